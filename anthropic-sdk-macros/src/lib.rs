@@ -0,0 +1,362 @@
+//! Derive macros for the Anthropic Rust SDK.
+//!
+//! This crate is re-exported by `anthropic-sdk` when its `macros` feature is
+//! enabled; depend on that instead of using it directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Lit, LitStr, Meta, Type,
+};
+
+/// Derive `AnthropicTool` and `ToolSchema` for a struct, or `ToolSchema` for a
+/// fieldless enum.
+///
+/// The tool name defaults to the struct name in snake_case and the description
+/// to its doc comment. Both can be overridden with
+/// `#[tool(name = "...", description = "...")]`. Field doc comments become
+/// property descriptions, and `Option` or `#[serde(default)]` fields are not
+/// required. `#[serde(rename)]`, `#[serde(rename_all)]` and `#[serde(skip)]`
+/// are honored so the schema matches what the input deserializes from.
+#[proc_macro_derive(AnthropicTool, attributes(tool))]
+pub fn derive_anthropic_tool(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let container = ContainerAttrs::parse(&input.attrs)?;
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => expand_struct(input, &container, fields),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "AnthropicTool can only be derived for structs with named fields",
+            )),
+        },
+        Data::Enum(data) => expand_enum(input, &container, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "AnthropicTool cannot be derived for unions",
+        )),
+    }
+}
+
+fn expand_struct(
+    input: &DeriveInput,
+    container: &ContainerAttrs,
+    fields: &syn::FieldsNamed,
+) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut properties = Vec::new();
+    for field in &fields.named {
+        let attrs = FieldAttrs::parse(&field.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        let field_ident = field.ident.as_ref().expect("named field");
+        let name = match attrs.rename {
+            Some(name) => name,
+            None => {
+                let raw = field_ident.to_string();
+                let raw = raw.strip_prefix("r#").unwrap_or(&raw).to_string();
+                match &container.rename_all {
+                    Some(rule) => rename_field(&raw, rule),
+                    None => raw,
+                }
+            }
+        };
+
+        let ty = &field.ty;
+        let required = !(attrs.default || container.default || is_option(ty));
+        let describe = doc_string(&field.attrs).map(|description| {
+            quote! {
+                if let Some(object) = schema.as_object_mut() {
+                    object.insert("description".to_string(), #description.into());
+                }
+            }
+        });
+        let require = required.then(|| {
+            quote! { required.push(::anthropic_sdk::__private::serde_json::Value::from(#name)); }
+        });
+
+        properties.push(quote! {
+            {
+                #[allow(unused_mut)]
+                let mut schema = <#ty as ::anthropic_sdk::ToolSchema>::schema();
+                #describe
+                properties.insert(#name.to_string(), schema);
+                #require
+            }
+        });
+    }
+
+    let tool_name = container
+        .name
+        .clone()
+        .unwrap_or_else(|| to_snake_case(&ident.to_string()));
+    let description = match container
+        .description
+        .clone()
+        .or_else(|| doc_string(&input.attrs))
+    {
+        Some(description) => quote! { Some(#description) },
+        None => quote! { None },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::anthropic_sdk::ToolSchema for #ident #ty_generics #where_clause {
+            fn schema() -> ::anthropic_sdk::__private::serde_json::Value {
+                let mut properties = ::anthropic_sdk::__private::serde_json::Map::new();
+                #[allow(unused_mut)]
+                let mut required: Vec<::anthropic_sdk::__private::serde_json::Value> = Vec::new();
+                #(#properties)*
+
+                let mut schema = ::anthropic_sdk::__private::serde_json::Map::new();
+                schema.insert("type".to_string(), "object".into());
+                schema.insert("properties".to_string(), properties.into());
+                schema.insert("required".to_string(), required.into());
+                schema.into()
+            }
+        }
+
+        impl #impl_generics ::anthropic_sdk::AnthropicTool for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #tool_name;
+            const DESCRIPTION: Option<&'static str> = #description;
+        }
+    })
+}
+
+fn expand_enum(
+    input: &DeriveInput,
+    container: &ContainerAttrs,
+    data: &syn::DataEnum,
+) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut values = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "AnthropicTool can only be derived for enums with unit variants",
+            ));
+        }
+
+        let attrs = FieldAttrs::parse(&variant.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
+        values.push(match attrs.rename {
+            Some(name) => name,
+            None => match &container.rename_all {
+                Some(rule) => rename_variant(&variant.ident.to_string(), rule),
+                None => variant.ident.to_string(),
+            },
+        });
+    }
+
+    let describe = doc_string(&input.attrs).map(|description| {
+        quote! { "description": #description, }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::anthropic_sdk::ToolSchema for #ident #ty_generics #where_clause {
+            fn schema() -> ::anthropic_sdk::__private::serde_json::Value {
+                ::anthropic_sdk::__private::serde_json::json!({
+                    "type": "string",
+                    "enum": [#(#values),*],
+                    #describe
+                })
+            }
+        }
+    })
+}
+
+/// Attributes on the deriving type.
+#[derive(Default)]
+struct ContainerAttrs {
+    name: Option<String>,
+    description: Option<String>,
+    rename_all: Option<String>,
+    default: bool,
+}
+
+impl ContainerAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("tool") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("name") {
+                        container.name = Some(meta.value()?.parse::<LitStr>()?.value());
+                        Ok(())
+                    } else if meta.path.is_ident("description") {
+                        container.description = Some(meta.value()?.parse::<LitStr>()?.value());
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `name` or `description`"))
+                    }
+                })?;
+            } else if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename_all") && meta.input.peek(syn::Token![=]) {
+                        container.rename_all = Some(meta.value()?.parse::<LitStr>()?.value());
+                    } else if meta.path.is_ident("default") {
+                        container.default = true;
+                        skip_meta(&meta)?;
+                    } else {
+                        skip_meta(&meta)?;
+                    }
+                    Ok(())
+                })?;
+            }
+        }
+
+        Ok(container)
+    }
+}
+
+/// Attributes on a field or enum variant.
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    default: bool,
+    skip: bool,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut field = Self::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") && meta.input.peek(syn::Token![=]) {
+                    field.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    field.default = true;
+                    skip_meta(&meta)?;
+                } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_deserializing") {
+                    field.skip = true;
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(field)
+    }
+}
+
+/// Consume the value of a serde attribute we don't care about.
+fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+    Ok(())
+}
+
+/// Collect the doc comment of an item.
+fn doc_string(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// Whether a type is spelled as `Option<...>`.
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in name.char_indices() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
+}
+
+/// Apply a serde `rename_all` rule to a snake_case field name.
+fn rename_field(name: &str, rule: &str) -> String {
+    match rule {
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "PascalCase" | "camelCase" => {
+            let mut renamed = String::new();
+            let mut capitalize = rule == "PascalCase";
+            for ch in name.chars() {
+                if ch == '_' {
+                    capitalize = true;
+                } else if capitalize {
+                    renamed.push(ch.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    renamed.push(ch);
+                }
+            }
+            renamed
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_ascii_uppercase(),
+        _ => name.to_string(),
+    }
+}
+
+/// Apply a serde `rename_all` rule to a PascalCase variant name.
+fn rename_variant(name: &str, rule: &str) -> String {
+    match rule {
+        "lowercase" => name.to_ascii_lowercase(),
+        "UPPERCASE" => name.to_ascii_uppercase(),
+        "camelCase" => {
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "snake_case" => to_snake_case(name),
+        "SCREAMING_SNAKE_CASE" => to_snake_case(name).to_ascii_uppercase(),
+        "kebab-case" => to_snake_case(name).replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => to_snake_case(name).replace('_', "-").to_ascii_uppercase(),
+        _ => name.to_string(),
+    }
+}
@@ -61,8 +61,11 @@ async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
             MessageStreamEvent::Ping => {
                 // Keep-alive ping, ignore
             }
-            MessageStreamEvent::Error { error } => {
-                eprintln!("Stream error: {} - {}", error.error_type, error.message);
+            MessageStreamEvent::Error { .. } => {
+                // Error events are yielded as `Err` and handled by `?` above
+            }
+            _ => {
+                // Event types added to the API after this SDK
             }
         }
     }
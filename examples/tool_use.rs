@@ -86,27 +86,7 @@ async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
                         .messages(vec![
                             MessageParam::user("What's the weather like in San Francisco?"),
                             // Include the assistant's response with tool use
-                            MessageParam::assistant_with_blocks(
-                                message
-                                    .content
-                                    .iter()
-                                    .map(|block| match block {
-                                        anthropic_sdk::ContentBlock::Text { text, .. } => {
-                                            ContentBlockParam::text(text.clone())
-                                        }
-                                        anthropic_sdk::ContentBlock::ToolUse {
-                                            id,
-                                            name,
-                                            input,
-                                        } => ContentBlockParam::ToolUse {
-                                            id: id.clone(),
-                                            name: name.clone(),
-                                            input: input.clone(),
-                                        },
-                                        _ => ContentBlockParam::text(""),
-                                    })
-                                    .collect(),
-                            ),
+                            MessageParam::from(message.clone()),
                             // Include the tool result
                             MessageParam::user_with_blocks(vec![ContentBlockParam::tool_result(
                                 id, result,
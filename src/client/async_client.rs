@@ -1,31 +1,47 @@
 //! Async HTTP client for the Anthropic API.
 
-use std::time::Duration;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
-use reqwest::{Client, Response, StatusCode};
+use reqwest::multipart::Form;
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 
 use crate::error::{AnthropicError, ErrorResponse, Result};
-use crate::resources::{Completions, Messages, Models};
-use crate::streaming::MessageStream;
+use crate::resources::{Admin, Beta, CompletionCreateParams, Completions, Files, Messages, Models};
+use crate::streaming::{CompletionStream, MessageStream};
 use crate::types::MessageCreateParams;
 use crate::API_VERSION;
 
-use super::ClientConfig;
+use super::logging::Redacted;
+use super::platform::platform_headers;
+use super::rate_limit::{RateLimitInfo, RateLimiter};
+use super::scheduler::{Priority, RequestPermit, Scheduler};
+use super::{
+    ApiResponse, ClientConfig, Credentials, LogLevel, Middleware, Next, RequestOptions, RetryCause,
+    RetryEvent, Transport,
+};
 
 /// Async client for the Anthropic API.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct AsyncAnthropic {
     config: ClientConfig,
     http_client: Client,
+    transport: Arc<dyn Transport>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    rate_limiter: Arc<RateLimiter>,
+    scheduler: Option<Arc<Scheduler>>,
 }
 
 impl AsyncAnthropic {
     /// Create a new client with default configuration.
     ///
-    /// This will use the `ANTHROPIC_API_KEY` environment variable for authentication.
+    /// This will use the `ANTHROPIC_API_KEY` environment variable for
+    /// authentication; see [`ClientConfig::from_env`] for the other variables
+    /// read.
     pub fn new() -> Result<Self> {
-        Self::with_config(ClientConfig::default())
+        Self::with_config(ClientConfig::from_env()?)
     }
 
     /// Create a new client with an API key.
@@ -37,23 +53,110 @@ impl AsyncAnthropic {
     pub fn with_config(config: ClientConfig) -> Result<Self> {
         config.validate()?;
 
-        let http_client = Client::builder()
-            .timeout(config.timeout)
-            .default_headers(config.default_headers.clone())
-            .build()
-            .map_err(AnthropicError::Connection)?;
+        let http_client = match &config.http_client {
+            Some(http_client) => http_client.clone(),
+            None => Self::build_http_client(&config)?,
+        };
+        let transport = match &config.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(http_client.clone()),
+        };
+
+        let rate_limiter = Arc::new(RateLimiter::new(config.rate_limit.clone()));
+        let scheduler = config
+            .max_concurrent_requests
+            .map(|permits| Arc::new(Scheduler::new(permits)));
 
         Ok(Self {
             config,
             http_client,
+            transport,
+            middleware: Vec::new(),
+            rate_limiter,
+            scheduler,
         })
     }
 
+    /// Build the HTTP client for a configuration without one.
+    fn build_http_client(config: &ClientConfig) -> Result<Client> {
+        let mut builder = Client::builder();
+
+        if let Some(timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = config.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(keepalive) = config.tcp_keepalive {
+            builder = builder.tcp_keepalive(keepalive);
+        }
+        if let Some(timeout) = config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(max) = config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(interval) = config.http2_keep_alive_interval {
+            builder = builder.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = config.http2_keep_alive_timeout {
+            builder = builder.http2_keep_alive_timeout(timeout);
+        }
+        builder = builder.http2_adaptive_window(config.http2_adaptive_window);
+
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy).map_err(|e| AnthropicError::Config {
+                message: format!("Invalid proxy URL {}: {}", proxy, e),
+            })?;
+            builder = builder.proxy(proxy);
+        }
+
+        builder.build().map_err(AnthropicError::Connection)
+    }
+
+    /// Add a middleware that intercepts every request.
+    ///
+    /// Middleware runs in the order it was added, the first added seeing the
+    /// request first.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
     /// Get a reference to the client configuration.
     pub fn config(&self) -> &ClientConfig {
         &self.config
     }
 
+    /// Open a connection to the API ahead of the first request.
+    ///
+    /// Sends a `HEAD` request to the base URL, which resolves the host and
+    /// completes the TLS handshake, and leaves the connection in the pool so
+    /// the next request can skip that setup. Any HTTP response counts as
+    /// success; only a connection failure or timeout is an error.
+    pub async fn preconnect(&self) -> Result<()> {
+        let timeout = self.config.connect_timeout.unwrap_or(self.config.timeout);
+        let request = self
+            .http_client
+            .head(&self.config.base_url)
+            .timeout(timeout)
+            .build()
+            .map_err(AnthropicError::Connection)?;
+
+        let started_at = Instant::now();
+        let response = self.transport.execute(request).await?;
+        self.log(
+            LogLevel::Info,
+            format_args!(
+                "preconnected to {} in {:.2?} (status {})",
+                self.config.base_url,
+                started_at.elapsed(),
+                response.status()
+            ),
+        );
+        Ok(())
+    }
+
     /// Access the Messages API.
     pub fn messages(&self) -> Messages<'_> {
         Messages::new(self)
@@ -69,143 +172,524 @@ impl AsyncAnthropic {
         Models::new(self)
     }
 
+    /// Access the Files API.
+    pub fn files(&self) -> Files<'_> {
+        Files::new(self)
+    }
+
+    /// Access the Admin API.
+    ///
+    /// Requires an admin API key to be configured.
+    pub fn admin(&self) -> Admin<'_> {
+        Admin::new(self)
+    }
+
+    /// Access beta features.
+    ///
+    /// Requests made through the returned namespace send the given flags in
+    /// the `anthropic-beta` header. See [`betas`](crate::types::betas) for
+    /// known flag values.
+    pub fn beta<I, S>(&self, betas: I) -> Beta<'_>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Beta::new(self, betas.into_iter().map(Into::into).collect())
+    }
+
     /// Build the authentication headers.
-    fn build_auth_headers(&self) -> HeaderMap {
+    async fn build_auth_headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
 
+        if let Some(provider) = &self.config.credentials {
+            provider.credentials().await?.apply(&mut headers)?;
+            return Ok(headers);
+        }
+
         if let Some(api_key) = &self.config.api_key {
-            headers.insert(
-                "x-api-key",
-                HeaderValue::from_str(api_key).unwrap_or_else(|_| HeaderValue::from_static("")),
-            );
+            Credentials::ApiKey(api_key.clone()).apply(&mut headers)?;
         }
 
         if let Some(auth_token) = &self.config.auth_token {
-            let value = format!("Bearer {}", auth_token);
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&value).unwrap_or_else(|_| HeaderValue::from_static("")),
-            );
+            Credentials::BearerToken(auth_token.clone()).apply(&mut headers)?;
         }
 
+        Ok(headers)
+    }
+
+    /// Build the headers that authenticate a request with the admin API key.
+    pub(crate) fn admin_headers(&self) -> Result<HeaderMap> {
+        let admin_api_key =
+            self.config
+                .admin_api_key
+                .as_deref()
+                .ok_or_else(|| AnthropicError::Config {
+                    message: "Missing admin API key: set ANTHROPIC_ADMIN_KEY".into(),
+                })?;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-api-key",
+            HeaderValue::from_str(admin_api_key).map_err(|_| AnthropicError::Config {
+                message: "Admin API key is not a valid header value".into(),
+            })?,
+        );
+
+        Ok(headers)
+    }
+
+    /// Build the `anthropic-beta` header for a set of beta flags.
+    pub(crate) fn beta_headers(&self, betas: &[String]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if !betas.is_empty() {
+            if let Ok(value) = HeaderValue::from_str(&betas.join(",")) {
+                headers.insert("anthropic-beta", value);
+            }
+        }
         headers
     }
 
     /// Build the common request headers.
-    fn build_headers(&self) -> HeaderMap {
-        let mut headers = self.build_auth_headers();
-
+    ///
+    /// The configured default headers replace the SDK's own, such as
+    /// `anthropic-version`; only the credentials are set after them.
+    async fn build_headers(&self) -> Result<HeaderMap> {
+        let mut headers = HeaderMap::new();
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         headers.insert("anthropic-version", HeaderValue::from_static(API_VERSION));
-        headers.insert("x-stainless-lang", HeaderValue::from_static("rust"));
+        if self.config.platform_headers {
+            headers.extend(platform_headers().clone());
+        }
 
-        headers
+        if let Some(workspace_id) = &self.config.workspace_id {
+            let value =
+                HeaderValue::from_str(workspace_id).map_err(|_| AnthropicError::Config {
+                    message: format!("Invalid workspace ID {:?}", workspace_id),
+                })?;
+            headers.insert("anthropic-workspace-id", value);
+        }
+
+        headers.extend(self.config.default_headers.clone());
+        headers.extend(self.build_auth_headers().await?);
+        Ok(headers)
+    }
+
+    /// Make a POST request and return a stream.
+    pub(crate) async fn post_stream(
+        &self,
+        path: &str,
+        body: &MessageCreateParams,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<MessageStream> {
+        // Create a modified body with stream: true
+        let mut body = body.clone();
+        body.stream = Some(true);
+
+        let permit = self.acquire_permit(options.priority).await;
+        let started_at = Instant::now();
+        let response = self
+            .send_with_options(
+                Method::POST,
+                path,
+                Some(&body),
+                extra_headers,
+                options,
+                self.config.stream_timeout,
+            )
+            .await?;
+
+        Ok(MessageStream::new(
+            response,
+            started_at,
+            permit,
+            self.config.max_event_size,
+        ))
     }
 
-    /// Make a GET request.
-    pub(crate) async fn get<T>(&self, path: &str) -> Result<T>
+    /// Make a POST request to the legacy completions endpoint and return a
+    /// stream.
+    ///
+    /// Like [`post_stream`](Self::post_stream), the stream holds the
+    /// concurrency permit until dropped and enforces `max_event_size`.
+    pub(crate) async fn post_completion_stream(
+        &self,
+        body: &CompletionCreateParams,
+        options: &RequestOptions,
+    ) -> Result<CompletionStream> {
+        let mut body = body.clone();
+        body.stream = Some(true);
+
+        let permit = self.acquire_permit(options.priority).await;
+        let response = self
+            .send_with_options(
+                Method::POST,
+                "/complete",
+                Some(&body),
+                HeaderMap::new(),
+                options,
+                self.config.stream_timeout,
+            )
+            .await?;
+
+        Ok(CompletionStream::new(
+            response,
+            permit,
+            self.config.max_event_size,
+        ))
+    }
+
+    /// Make a request with per-request options and parse the JSON response.
+    pub(crate) async fn request_with_options<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
+        B: serde::Serialize + ?Sized,
     {
-        let url = format!("{}/v1{}", self.config.base_url, path);
-        let headers = self.build_headers();
-
+        let _permit = self.acquire_permit(options.priority).await;
         let response = self
-            .request_with_retry(|| self.http_client.get(&url).headers(headers.clone()).send())
+            .send_with_options(
+                method,
+                path,
+                body,
+                extra_headers,
+                options,
+                Some(self.config.timeout),
+            )
             .await?;
-
         self.handle_response(response).await
     }
 
-    /// Make a POST request.
-    pub(crate) async fn post<T, B>(&self, path: &str, body: &B) -> Result<T>
+    /// Make a request and parse the JSON response, keeping its status and
+    /// headers.
+    pub(crate) async fn request_with_response<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<ApiResponse<T>>
     where
         T: serde::de::DeserializeOwned,
-        B: serde::Serialize,
+        B: serde::Serialize + ?Sized,
     {
-        let url = format!("{}/v1{}", self.config.base_url, path);
-        let headers = self.build_headers();
-
+        let _permit = self.acquire_permit(options.priority).await;
         let response = self
-            .request_with_retry(|| {
-                self.http_client
-                    .post(&url)
-                    .headers(headers.clone())
-                    .json(body)
-                    .send()
-            })
+            .send_with_options(
+                method,
+                path,
+                body,
+                extra_headers,
+                options,
+                Some(self.config.timeout),
+            )
             .await?;
+        self.parse_response(response).await
+    }
 
-        self.handle_response(response).await
+    /// Make a request with retries and return the raw successful response.
+    ///
+    /// A concurrency permit is held only until the response headers arrive.
+    /// The caller reads the body, so the stream timeout applies unless the
+    /// options set one.
+    pub(crate) async fn send<B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<Response>
+    where
+        B: serde::Serialize + ?Sized,
+    {
+        let _permit = self.acquire_permit(options.priority).await;
+        self.send_with_options(
+            method,
+            path,
+            body,
+            extra_headers,
+            options,
+            self.config.stream_timeout,
+        )
+        .await
     }
 
-    /// Make a POST request and return a stream.
-    pub(crate) async fn post_stream(
+    /// Build the headers for one request.
+    ///
+    /// `extra_headers` replace the common headers, except that betas in
+    /// `anthropic-beta` are added to those of the configured default header.
+    /// The options' headers are merged last, over `extra_headers`, and then
+    /// the headers the options remove are dropped. Requests other than `GET`
+    /// carry an `Idempotency-Key` header, generated unless the options set
+    /// one, that stays the same across retries.
+    async fn request_headers(
+        &self,
+        method: &Method,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<HeaderMap> {
+        let mut headers = self.build_headers().await?;
+        // A request that brings its own API key, like the admin endpoints,
+        // must not also send the client's bearer token.
+        if extra_headers.contains_key("x-api-key") {
+            headers.remove(AUTHORIZATION);
+        }
+        merge_headers(&mut headers, extra_headers);
+        if method != Method::GET {
+            let key = match &options.idempotency_key {
+                Some(key) => key.clone(),
+                None => format!("stainless-rust-retry-{}", uuid::Uuid::new_v4()),
+            };
+            if let Ok(value) = HeaderValue::from_str(&key) {
+                headers.insert("idempotency-key", value);
+            }
+        }
+        headers.extend(options.extra_headers.clone());
+        for name in &options.remove_headers {
+            headers.remove(name);
+        }
+        Ok(headers)
+    }
+
+    /// Make a request with per-request options and return the raw successful
+    /// response.
+    ///
+    /// The headers are built by [`request_headers`](Self::request_headers).
+    /// The options' timeout, if set, replaces `default_timeout`.
+    pub(crate) async fn send_with_options<B>(
         &self,
+        method: Method,
         path: &str,
-        body: &MessageCreateParams,
-    ) -> Result<MessageStream> {
+        body: Option<&B>,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+        default_timeout: Option<Duration>,
+    ) -> Result<Response>
+    where
+        B: serde::Serialize + ?Sized,
+    {
         let url = format!("{}/v1{}", self.config.base_url, path);
-        let headers = self.build_headers();
+        let headers = self
+            .request_headers(&method, extra_headers, options)
+            .await?;
 
-        // Create a modified body with stream: true
-        let mut body = body.clone();
-        body.stream = Some(true);
+        let timeout = options.timeout.or(default_timeout);
+        let max_retries = options.max_retries.unwrap_or(self.config.max_retries);
 
         let response = self
-            .request_with_retry(|| {
-                self.http_client
-                    .post(&url)
-                    .headers(headers.clone())
-                    .json(&body)
-                    .send()
+            .request_with_retry(max_retries, options.deadline, || {
+                let mut request = self
+                    .http_client
+                    .request(method.clone(), &url)
+                    .headers(headers.clone());
+                if !options.extra_query.is_empty() {
+                    request = request.query(&options.extra_query);
+                }
+                if let Some(body) = body {
+                    request = request.json(body);
+                }
+                // Reading the body must also finish by the deadline
+                let timeout = match options.deadline {
+                    Some(deadline) => {
+                        let remaining = deadline.saturating_duration_since(Instant::now());
+                        Some(timeout.map_or(remaining, |timeout| timeout.min(remaining)))
+                    }
+                    None => timeout,
+                };
+                self.execute(request, timeout)
             })
             .await?;
 
-        // Check for errors before creating stream
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let request_id = response
-                .headers()
-                .get("request-id")
-                .and_then(|v| v.to_str().ok())
-                .map(String::from);
-
-            let body_text = response.text().await.unwrap_or_default();
-            let message =
-                if let Ok(error_response) = serde_json::from_str::<ErrorResponse>(&body_text) {
-                    error_response.error.message
-                } else {
-                    body_text
-                };
+        self.check_response(response).await
+    }
+
+    /// Make a multipart POST request and parse the JSON response.
+    ///
+    /// The form body may be streamed and therefore cannot be replayed, so this
+    /// request is never retried and the options' retry count is ignored. The
+    /// headers are built as for other requests, except that the form sets the
+    /// content type. The options' deadline shortens the timeout.
+    pub(crate) async fn post_multipart<T>(
+        &self,
+        path: &str,
+        form: Form,
+        extra_headers: HeaderMap,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = format!("{}/v1{}", self.config.base_url, path);
+        let mut headers = self
+            .request_headers(&Method::POST, extra_headers, options)
+            .await?;
+        // The multipart body sets its own content type with the boundary
+        headers.remove(CONTENT_TYPE);
 
-            return Err(AnthropicError::from_status(
-                status, message, request_id, None,
-            ));
+        let mut request = self.http_client.post(&url).headers(headers).multipart(form);
+        if !options.extra_query.is_empty() {
+            request = request.query(&options.extra_query);
         }
+        let timeout = options.timeout.unwrap_or(self.config.timeout);
+        let timeout = match options.deadline {
+            Some(deadline) => timeout.min(deadline.saturating_duration_since(Instant::now())),
+            None => timeout,
+        };
+        let _permit = self.acquire_permit(options.priority).await;
+        let response = self.execute(request, Some(timeout)).await?;
 
-        Ok(MessageStream::new(response))
+        self.handle_response(response).await
+    }
+
+    /// Wait for a slot under `max_concurrent_requests`, if it is set.
+    async fn acquire_permit(&self, priority: Priority) -> Option<RequestPermit> {
+        match &self.scheduler {
+            Some(scheduler) => Some(scheduler.acquire(priority).await),
+            None => None,
+        }
+    }
+
+    /// Send a request through the middleware chain.
+    async fn execute(
+        &self,
+        request: RequestBuilder,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let request = match timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+        let request = request.build().map_err(AnthropicError::Connection)?;
+
+        let method = request.method().clone();
+        let url = request.url().clone();
+        self.log(
+            LogLevel::Debug,
+            format_args!(
+                "sending {} {} with headers {}",
+                method,
+                url,
+                Redacted(request.headers())
+            ),
+        );
+
+        self.rate_limiter.acquire().await;
+        let started_at = Instant::now();
+        let response = match Next::new(self.transport.as_ref(), &self.middleware)
+            .run(request)
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                self.log(
+                    LogLevel::Info,
+                    format_args!("{} {} failed: {}", method, url, e),
+                );
+                return Err(e);
+            }
+        };
+        self.rate_limiter.update(response.headers());
+
+        self.log(
+            LogLevel::Info,
+            format_args!(
+                "{} {} -> {} (request-id {}, {:.2?})",
+                method,
+                url,
+                response.status(),
+                response
+                    .headers()
+                    .get("request-id")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or("none"),
+                started_at.elapsed()
+            ),
+        );
+
+        Ok(response)
+    }
+
+    /// Emit a diagnostic `tracing` event if `level` is enabled.
+    fn log(&self, level: LogLevel, message: fmt::Arguments<'_>) {
+        if self.config.log_level >= Some(level) {
+            match level {
+                LogLevel::Info => tracing::info!(target: "anthropic_sdk", "{}", message),
+                LogLevel::Debug => tracing::debug!(target: "anthropic_sdk", "{}", message),
+            }
+        }
     }
 
     /// Execute a request with retry logic.
-    async fn request_with_retry<F, Fut>(&self, request_fn: F) -> Result<Response>
+    async fn request_with_retry<F, Fut>(
+        &self,
+        max_retries: u32,
+        deadline: Option<Instant>,
+        request_fn: F,
+    ) -> Result<Response>
     where
         F: Fn() -> Fut,
-        Fut: std::future::Future<Output = std::result::Result<Response, reqwest::Error>>,
+        Fut: std::future::Future<Output = Result<Response>>,
     {
+        let started_at = Instant::now();
         let mut last_error = None;
         let mut attempts = 0;
-
-        while attempts <= self.config.max_retries {
-            match request_fn().await {
+        // Whether a retry after `delay` fits in the retry budget and before
+        // the deadline
+        let allows = |delay: Duration| {
+            self.config.retry.allows(started_at.elapsed(), delay)
+                && deadline.is_none_or(|deadline| Instant::now() + delay < deadline)
+        };
+        let exceeded = |attempts: u32| AnthropicError::DeadlineExceeded {
+            attempts: attempts + 1,
+            elapsed: started_at.elapsed(),
+        };
+
+        while attempts <= max_retries {
+            let result = match deadline {
+                Some(deadline) => {
+                    match tokio::time::timeout_at(deadline.into(), request_fn()).await {
+                        Ok(result) => result,
+                        Err(_) => return Err(exceeded(attempts)),
+                    }
+                }
+                None => request_fn().await,
+            };
+            match result {
                 Ok(response) => {
                     let status = response.status();
 
                     // Check if we should retry based on status
-                    if self.should_retry(status) && attempts < self.config.max_retries {
+                    if self.should_retry(status) && attempts < max_retries {
                         let retry_after = self.parse_retry_after(response.headers());
-                        let delay = self.calculate_delay(attempts, retry_after);
+                        let delay = self.config.retry.delay(attempts, retry_after);
+                        if !allows(delay) {
+                            return Ok(response);
+                        }
+                        self.log(
+                            LogLevel::Info,
+                            format_args!(
+                                "retrying in {:.2?} after status {} (retry {} of {})",
+                                delay,
+                                status,
+                                attempts + 1,
+                                max_retries
+                            ),
+                        );
+                        self.notify_retry(
+                            attempts + 1,
+                            max_retries,
+                            delay,
+                            RetryCause::Status(status),
+                        );
                         tokio::time::sleep(delay).await;
                         attempts += 1;
                         continue;
@@ -213,19 +697,33 @@ impl AsyncAnthropic {
 
                     return Ok(response);
                 }
+                Err(AnthropicError::Timeout)
+                    if deadline.is_some_and(|deadline| Instant::now() >= deadline) =>
+                {
+                    return Err(exceeded(attempts));
+                }
                 Err(e) => {
-                    last_error = Some(if e.is_timeout() {
-                        AnthropicError::Timeout
-                    } else {
-                        AnthropicError::Connection(e)
-                    });
-
-                    if attempts < self.config.max_retries {
-                        let delay = self.calculate_delay(attempts, None);
+                    let retryable =
+                        matches!(e, AnthropicError::Timeout | AnthropicError::Connection(_));
+
+                    let delay = self.config.retry.delay(attempts, None);
+                    if retryable && attempts < max_retries && allows(delay) {
+                        self.log(
+                            LogLevel::Info,
+                            format_args!(
+                                "retrying in {:.2?} after error: {} (retry {} of {})",
+                                delay,
+                                e,
+                                attempts + 1,
+                                max_retries
+                            ),
+                        );
+                        self.notify_retry(attempts + 1, max_retries, delay, RetryCause::Error(&e));
+                        last_error = Some(e);
                         tokio::time::sleep(delay).await;
                         attempts += 1;
                     } else {
-                        break;
+                        return Err(e);
                     }
                 }
             }
@@ -234,6 +732,18 @@ impl AsyncAnthropic {
         Err(last_error.unwrap_or(AnthropicError::Timeout))
     }
 
+    /// Tell the retry hook, if any, about a retry.
+    fn notify_retry(&self, attempt: u32, max_retries: u32, delay: Duration, cause: RetryCause<'_>) {
+        if let Some(hook) = &self.config.on_retry {
+            hook.on_retry(&RetryEvent {
+                attempt,
+                max_retries,
+                delay,
+                cause,
+            });
+        }
+    }
+
     /// Check if a status code should trigger a retry.
     fn should_retry(&self, status: StatusCode) -> bool {
         matches!(
@@ -261,74 +771,126 @@ impl AsyncAnthropic {
         None
     }
 
-    /// Calculate the delay for a retry attempt.
-    fn calculate_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
-        const INITIAL_DELAY: f64 = 0.5;
-        const MAX_DELAY: f64 = 8.0;
-
-        // Use retry-after if provided and reasonable (within 60 seconds)
-        if let Some(retry_after) = retry_after {
-            if retry_after <= Duration::from_secs(60) {
-                return retry_after;
-            }
-        }
-
-        // Exponential backoff with jitter
-        let base_delay = INITIAL_DELAY * 2.0_f64.powi(attempt as i32);
-        let delay = base_delay.min(MAX_DELAY);
-
-        // Add some jitter (±25%)
-        let jitter = 1.0 - 0.25 * rand_f64();
-        let final_delay = delay * jitter;
-
-        Duration::from_secs_f64(final_delay)
-    }
-
     /// Handle the response, parsing errors if needed.
     async fn handle_response<T>(&self, response: Response) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
+        let response = self.check_response(response).await?;
+        let body = self.read_body(response).await?;
+        serde_json::from_slice(&body).map_err(AnthropicError::Json)
+    }
+
+    /// Handle the response, keeping its status and headers.
+    async fn parse_response<T>(&self, response: Response) -> Result<ApiResponse<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self.check_response(response).await?;
         let status = response.status();
+        let headers = response.headers().clone();
+        let body = self.read_body(response).await?;
+        let data = serde_json::from_slice(&body).map_err(AnthropicError::Json)?;
+
+        Ok(ApiResponse {
+            data,
+            status,
+            headers,
+        })
+    }
+
+    /// Read a response body, failing if it is larger than
+    /// `max_response_size`.
+    async fn read_body(&self, mut response: Response) -> Result<Vec<u8>> {
+        let Some(limit) = self.config.max_response_size else {
+            let body = response.bytes().await.map_err(AnthropicError::Connection)?;
+            return Ok(body.into());
+        };
+        let too_large = || AnthropicError::InvalidResponse {
+            message: format!("Response body exceeds the limit of {} bytes", limit),
+        };
+
+        if response
+            .content_length()
+            .is_some_and(|length| length > limit as u64)
+        {
+            return Err(too_large());
+        }
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(AnthropicError::Connection)? {
+            if body.len() + chunk.len() > limit {
+                return Err(too_large());
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    /// Pass through a successful response, or convert an error response into an error.
+    async fn check_response(&self, response: Response) -> Result<Response> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
         let request_id = response
             .headers()
             .get("request-id")
             .and_then(|v| v.to_str().ok())
             .map(String::from);
-
-        if status.is_success() {
-            let body = response.text().await.map_err(AnthropicError::Connection)?;
-            serde_json::from_str(&body).map_err(AnthropicError::Json)
+        let retry_after = self.parse_retry_after(response.headers());
+        let rate_limit = RateLimitInfo::from_headers(response.headers());
+        let body = self.read_body(response).await.unwrap_or_default();
+        let body_text = String::from_utf8_lossy(&body).into_owned();
+
+        let message = if let Ok(error_response) = serde_json::from_str::<ErrorResponse>(&body_text)
+        {
+            error_response.error.message
         } else {
-            let retry_after = self.parse_retry_after(response.headers());
-            let body_text = response.text().await.unwrap_or_default();
-
-            let message =
-                if let Ok(error_response) = serde_json::from_str::<ErrorResponse>(&body_text) {
-                    error_response.error.message
-                } else {
-                    body_text
-                };
-
-            Err(AnthropicError::from_status(
-                status.as_u16(),
-                message,
-                request_id,
-                retry_after,
-            ))
+            body_text
+        };
+
+        let mut error =
+            AnthropicError::from_status(status.as_u16(), message, request_id, retry_after);
+        if let AnthropicError::RateLimited {
+            rate_limit: info, ..
+        } = &mut error
+        {
+            *info = rate_limit.map(Box::new);
         }
+        Err(error)
     }
 }
 
-/// Simple random number generator for jitter (0.0 to 1.0).
-fn rand_f64() -> f64 {
-    use std::collections::hash_map::RandomState;
-    use std::hash::{BuildHasher, Hasher};
-
-    let state = RandomState::new();
-    let mut hasher = state.build_hasher();
-    hasher.write_u64(std::time::Instant::now().elapsed().as_nanos() as u64);
-    let hash = hasher.finish();
+/// Add `extra` to `headers`, replacing values except `anthropic-beta`, whose
+/// comma-separated betas are merged with any configured ones.
+fn merge_headers(headers: &mut HeaderMap, mut extra: HeaderMap) {
+    if let (Some(configured), Some(requested)) = (
+        headers.get("anthropic-beta"),
+        extra.remove("anthropic-beta"),
+    ) {
+        let mut betas: Vec<&str> = Vec::new();
+        for value in [configured, &requested] {
+            for beta in value.to_str().unwrap_or_default().split(',') {
+                let beta = beta.trim();
+                if !beta.is_empty() && !betas.contains(&beta) {
+                    betas.push(beta);
+                }
+            }
+        }
+        let merged = HeaderValue::from_str(&betas.join(",")).unwrap_or(requested);
+        headers.insert("anthropic-beta", merged);
+    }
+    headers.extend(extra);
+}
 
-    (hash as f64) / (u64::MAX as f64)
+impl fmt::Debug for AsyncAnthropic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncAnthropic")
+            .field("config", &self.config)
+            .field("http_client", &self.http_client)
+            .field("transport", &self.transport)
+            .field("middleware", &self.middleware.len())
+            .finish()
+    }
 }
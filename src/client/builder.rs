@@ -0,0 +1,296 @@
+//! Validating builder for [`ClientConfig`].
+
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::header::{HeaderName, HeaderValue};
+
+use super::{
+    ClientConfig, CredentialsProvider, LogLevel, RateLimitConfig, RetryConfig, RetryHook, Transport,
+};
+
+/// Every problem found when building a [`ClientConfig`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    problems: Vec<String>,
+}
+
+impl ConfigError {
+    /// The problems found, one message each, in the order they were found.
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid client configuration: {}",
+            self.problems.join("; ")
+        )
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<ConfigError> for crate::AnthropicError {
+    fn from(error: ConfigError) -> Self {
+        Self::Config {
+            message: error.problems.join("; "),
+        }
+    }
+}
+
+/// Builds a [`ClientConfig`], checking it before it is used.
+///
+/// Unlike the setters on [`ClientConfig`], which skip a header that is not
+/// valid, the builder records every invalid value and
+/// [`build`](Self::build) reports them all at once, along with a malformed
+/// base URL or proxy URL, a zero timeout or concurrency limit, and
+/// credentials or a workspace ID that cannot be sent as a header.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::ClientConfig;
+/// use std::time::Duration;
+///
+/// let config = ClientConfig::builder()
+///     .api_key("your-api-key")
+///     .base_url("https://api.anthropic.com")
+///     .timeout(Duration::from_secs(60))
+///     .default_header("x-team", "search")
+///     .build()?;
+///
+/// let error = ClientConfig::builder()
+///     .base_url("not a url")
+///     .timeout(Duration::ZERO)
+///     .build()
+///     .unwrap_err();
+/// assert_eq!(error.problems().len(), 2);
+/// # Ok::<(), anthropic_sdk::ConfigError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientConfigBuilder {
+    config: ClientConfig,
+    problems: Vec<String>,
+}
+
+/// Forward setters that cannot fail to [`ClientConfig`].
+macro_rules! forward {
+    ($($(#[$doc:meta])* $name:ident($arg:ident: $ty:ty);)*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(mut self, $arg: $ty) -> Self {
+                self.config = self.config.$name($arg);
+                self
+            }
+        )*
+    };
+}
+
+impl ClientConfigBuilder {
+    /// Start from the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start from an existing configuration.
+    pub fn from_config(config: ClientConfig) -> Self {
+        Self {
+            config,
+            problems: Vec::new(),
+        }
+    }
+
+    /// Set the API key.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.config.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Set the bearer token.
+    pub fn auth_token(mut self, auth_token: impl Into<String>) -> Self {
+        self.config.auth_token = Some(auth_token.into());
+        self
+    }
+
+    /// Set the admin API key.
+    pub fn admin_api_key(mut self, admin_api_key: impl Into<String>) -> Self {
+        self.config.admin_api_key = Some(admin_api_key.into());
+        self
+    }
+
+    /// Get the credentials for each request from a provider.
+    pub fn credentials(mut self, credentials: impl CredentialsProvider + 'static) -> Self {
+        self.config = self.config.credentials(credentials);
+        self
+    }
+
+    /// Send requests through a custom transport.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.config = self.config.transport(transport);
+        self
+    }
+
+    /// Add a default header, recording a problem if the name or value is not
+    /// valid.
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        match (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+            (Ok(name), Ok(value)) => {
+                self.config.default_headers.insert(name, value);
+            }
+            (Err(_), _) => self
+                .problems
+                .push(format!("Invalid header name {:?}", name)),
+            (_, Err(_)) => self
+                .problems
+                .push(format!("Invalid value for header {:?}", name)),
+        }
+        self
+    }
+
+    /// Opt every request into the 1M token context window.
+    pub fn context_1m(mut self) -> Self {
+        self.config = self.config.context_1m();
+        self
+    }
+
+    forward! {
+        /// Set the base URL.
+        base_url(base_url: impl Into<String>);
+        /// Set the request timeout.
+        timeout(timeout: Duration);
+        /// Set the total time allowed for a streamed response, or `None` for no
+        /// limit.
+        stream_timeout(stream_timeout: Option<Duration>);
+        /// Enable or disable raising the default timeout for long generations.
+        scale_timeout(enabled: bool);
+        /// Set the time allowed to establish a connection.
+        connect_timeout(connect_timeout: Duration);
+        /// Set the longest wait for more of a response once it has started.
+        read_timeout(read_timeout: Duration);
+        /// Set the workspace that requests are attributed to.
+        workspace_id(workspace_id: impl Into<String>);
+        /// Set the maximum number of retries.
+        max_retries(max_retries: u32);
+        /// Set the delays between retries and the total time allowed for them.
+        retry(retry: RetryConfig);
+        /// Call a hook before each retry.
+        on_retry(hook: impl RetryHook + 'static);
+        /// Enable or disable the platform metadata headers.
+        platform_headers(enabled: bool);
+        /// Send requests with an existing HTTP client.
+        http_client(http_client: reqwest::Client);
+        /// Send all requests through a proxy.
+        proxy(proxy: impl Into<String>);
+        /// Set how long an idle pooled connection is kept open.
+        pool_idle_timeout(pool_idle_timeout: Duration);
+        /// Set the maximum number of idle connections kept per host.
+        pool_max_idle_per_host(pool_max_idle_per_host: usize);
+        /// Set the idle time before TCP keepalive probes are sent.
+        tcp_keepalive(tcp_keepalive: Duration);
+        /// Send HTTP/2 pings at this interval on open connections.
+        http2_keep_alive_interval(interval: Duration);
+        /// Set how long to wait for an HTTP/2 ping to be acknowledged.
+        http2_keep_alive_timeout(timeout: Duration);
+        /// Enable or disable adaptive HTTP/2 flow-control windows.
+        http2_adaptive_window(enabled: bool);
+        /// Fail when a JSON response body is larger than this many bytes.
+        max_response_size(max_response_size: usize);
+        /// Fail a message stream when an event is larger than this many bytes.
+        max_event_size(max_event_size: usize);
+        /// Limit the number of requests in flight at once.
+        max_concurrent_requests(max_concurrent_requests: usize);
+        /// Pace requests by the rate limit headers of earlier responses.
+        rate_limit(rate_limit: RateLimitConfig);
+        /// Log requests through `tracing` at this level.
+        log_level(log_level: LogLevel);
+    }
+
+    /// Check the configuration and return it, or every problem found.
+    pub fn build(self) -> Result<ClientConfig, ConfigError> {
+        let Self {
+            config,
+            mut problems,
+        } = self;
+
+        match reqwest::Url::parse(&config.base_url) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            _ => problems.push(format!("Invalid base URL {:?}", config.base_url)),
+        }
+        if config.timeout.is_zero() {
+            problems.push("timeout must be greater than zero".into());
+        }
+        if let Some(proxy) = &config.proxy {
+            if reqwest::Proxy::all(proxy).is_err() {
+                problems.push(format!("Invalid proxy URL {:?}", proxy));
+            }
+        }
+        if config.max_concurrent_requests == Some(0) {
+            problems.push("max_concurrent_requests must be at least 1".into());
+        }
+        for (name, secret) in [
+            ("API key", &config.api_key),
+            ("Auth token", &config.auth_token),
+            ("Admin API key", &config.admin_api_key),
+        ] {
+            if secret
+                .as_deref()
+                .is_some_and(|secret| HeaderValue::from_str(secret).is_err())
+            {
+                problems.push(format!("{} is not a valid header value", name));
+            }
+        }
+        if let Some(workspace_id) = &config.workspace_id {
+            if HeaderValue::from_str(workspace_id).is_err() {
+                problems.push(format!("Invalid workspace ID {:?}", workspace_id));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError { problems })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_reports_all_problems() {
+        let config = ClientConfigBuilder::new()
+            .api_key("sk-test")
+            .default_header("x-team", "search")
+            .build()
+            .unwrap();
+        assert_eq!(config.default_headers["x-team"], "search");
+
+        let error = ClientConfigBuilder::new()
+            .api_key("sk-ant\n")
+            .base_url("api.anthropic.com")
+            .timeout(Duration::ZERO)
+            .default_header("bad header", "value")
+            .default_header("x-team", "line\nbreak")
+            .max_concurrent_requests(0)
+            .workspace_id("wrkspc\n1")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            error.problems(),
+            [
+                "Invalid header name \"bad header\"",
+                "Invalid value for header \"x-team\"",
+                "Invalid base URL \"api.anthropic.com\"",
+                "timeout must be greater than zero",
+                "max_concurrent_requests must be at least 1",
+                "API key is not a valid header value",
+                "Invalid workspace ID \"wrkspc\\n1\"",
+            ]
+        );
+    }
+}
@@ -1,8 +1,14 @@
 //! Client configuration.
 
-use reqwest::header::HeaderMap;
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::sync::Arc;
 use std::time::Duration;
 
+use super::{
+    ClientConfigBuilder, CredentialsProvider, LogLevel, RateLimitConfig, RetryConfig, RetryHook,
+    Transport,
+};
+use crate::types::betas;
 use crate::{DEFAULT_BASE_URL, DEFAULT_MAX_RETRIES, DEFAULT_TIMEOUT_SECS};
 
 /// Configuration for the Anthropic client.
@@ -14,17 +20,127 @@ pub struct ClientConfig {
     /// Bearer token for authentication (Authorization header).
     pub auth_token: Option<String>,
 
+    /// Provider asked for the credentials of each request, replacing
+    /// `api_key` and `auth_token` when set.
+    pub credentials: Option<Arc<dyn CredentialsProvider>>,
+
+    /// Admin API key for the organization management endpoints.
+    pub admin_api_key: Option<String>,
+
+    /// Workspace that requests are attributed to (`anthropic-workspace-id` header).
+    pub workspace_id: Option<String>,
+
     /// Base URL for the API.
     pub base_url: String,
 
-    /// Request timeout.
+    /// Total time allowed for a request, from sending it to reading the
+    /// whole response. Applies to requests that are not streamed.
     pub timeout: Duration,
 
+    /// Total time allowed for a streamed response, including the stream
+    /// itself, or no limit when unset. Defaults to the same 10 minutes as
+    /// [`timeout`](Self::timeout); pair no limit with a
+    /// [`read_timeout`](Self::read_timeout) to still catch a stalled stream.
+    pub stream_timeout: Option<Duration>,
+
+    /// Raise the default timeout of a message request whose `max_tokens`
+    /// could take longer to generate than the timeout allows.
+    ///
+    /// Enabled by default. Setting [`timeout`](Self::timeout) or
+    /// [`stream_timeout`](Self::stream_timeout) with its setter, through the
+    /// builder or with `ANTHROPIC_TIMEOUT` disables it, so a timeout chosen
+    /// explicitly is never raised.
+    pub scale_timeout: bool,
+
+    /// Time allowed to establish a connection, unlimited when unset.
+    pub connect_timeout: Option<Duration>,
+
+    /// Longest wait for more of a response once it has started, unlimited
+    /// when unset. Unlike the overall timeouts, this does not cut off a long
+    /// stream that keeps producing data.
+    pub read_timeout: Option<Duration>,
+
     /// Maximum number of retries for failed requests.
     pub max_retries: u32,
 
-    /// Default headers to include in all requests.
+    /// Delays between retries and the total time allowed for them.
+    pub retry: RetryConfig,
+
+    /// Hook notified before each retry.
+    pub on_retry: Option<Arc<dyn RetryHook>>,
+
+    /// Default headers to include in all requests. They replace any header
+    /// of the same name the SDK sets, such as `user-agent`.
     pub default_headers: HeaderMap,
+
+    /// Send the SDK version, OS, architecture and compiler version in the
+    /// `user-agent` and `x-stainless-*` headers, as the official SDKs do.
+    /// Enabled by default.
+    pub platform_headers: bool,
+
+    /// HTTP client to send requests with.
+    ///
+    /// When unset, the client builds its own. The timeout and default headers
+    /// above are applied to each request either way.
+    pub http_client: Option<reqwest::Client>,
+
+    /// Transport that sends requests, replacing the HTTP client when set.
+    ///
+    /// Requests are still built with the HTTP client, so its connection
+    /// settings have no effect.
+    pub transport: Option<Arc<dyn Transport>>,
+
+    /// Proxy URL for all requests, such as `http://proxy.internal:8080`.
+    ///
+    /// `socks5://` URLs need the `socks` feature. When unset, the
+    /// `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY` and `NO_PROXY` environment
+    /// variables are honored. Ignored when [`http_client`](Self::http_client)
+    /// is set.
+    pub proxy: Option<String>,
+
+    /// How long an idle pooled connection is kept open.
+    ///
+    /// Like the other connection settings, ignored when
+    /// [`http_client`](Self::http_client) is set.
+    pub pool_idle_timeout: Option<Duration>,
+
+    /// Maximum number of idle connections kept per host.
+    pub pool_max_idle_per_host: Option<usize>,
+
+    /// Idle time before TCP keepalive probes are sent. When unset, reqwest's
+    /// default of 15 seconds applies.
+    pub tcp_keepalive: Option<Duration>,
+
+    /// Interval between HTTP/2 pings on an open connection, disabled when
+    /// unset. Keeps NATs and load balancers from dropping a connection while
+    /// a slow stream produces no data.
+    pub http2_keep_alive_interval: Option<Duration>,
+
+    /// How long to wait for an HTTP/2 ping to be acknowledged before closing
+    /// the connection. When unset, reqwest's default of 20 seconds applies.
+    pub http2_keep_alive_timeout: Option<Duration>,
+
+    /// Size HTTP/2 flow-control windows from the measured bandwidth-delay
+    /// product instead of using fixed windows.
+    pub http2_adaptive_window: bool,
+
+    /// Largest JSON response body to read, in bytes, unlimited when unset.
+    pub max_response_size: Option<usize>,
+
+    /// Largest event in a message stream, in bytes, unlimited when unset.
+    pub max_event_size: Option<usize>,
+
+    /// Maximum number of requests in flight at once, unlimited when unset.
+    ///
+    /// Shared by clones of the client. A message stream counts as in flight
+    /// until it is dropped.
+    pub max_concurrent_requests: Option<usize>,
+
+    /// Client-side rate limiting, disabled when unset.
+    pub rate_limit: Option<RateLimitConfig>,
+
+    /// Diagnostic logging through `tracing`, off when unset.
+    pub log_level: Option<LogLevel>,
 }
 
 impl Default for ClientConfig {
@@ -32,15 +148,99 @@ impl Default for ClientConfig {
         Self {
             api_key: std::env::var("ANTHROPIC_API_KEY").ok(),
             auth_token: std::env::var("ANTHROPIC_AUTH_TOKEN").ok(),
+            credentials: None,
+            admin_api_key: std::env::var("ANTHROPIC_ADMIN_KEY").ok(),
+            workspace_id: None,
             base_url: DEFAULT_BASE_URL.to_string(),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            stream_timeout: Some(Duration::from_secs(DEFAULT_TIMEOUT_SECS)),
+            scale_timeout: true,
+            connect_timeout: None,
+            read_timeout: None,
             max_retries: DEFAULT_MAX_RETRIES,
+            retry: RetryConfig::default(),
+            on_retry: None,
             default_headers: HeaderMap::new(),
+            platform_headers: true,
+            http_client: None,
+            transport: None,
+            proxy: None,
+            pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            tcp_keepalive: None,
+            http2_keep_alive_interval: None,
+            http2_keep_alive_timeout: None,
+            http2_adaptive_window: false,
+            max_response_size: None,
+            max_event_size: None,
+            max_concurrent_requests: None,
+            rate_limit: None,
+            log_level: LogLevel::from_env(),
         }
     }
 }
 
 impl ClientConfig {
+    /// Create a configuration from environment variables.
+    ///
+    /// Reads the credentials that [`Default`] reads, plus
+    /// `ANTHROPIC_WORKSPACE_ID`, `ANTHROPIC_BASE_URL`, `ANTHROPIC_TIMEOUT` (in
+    /// seconds) and `ANTHROPIC_MAX_RETRIES`. Unset or empty variables keep
+    /// their defaults. `ANTHROPIC_TIMEOUT` sets both
+    /// [`timeout`](Self::timeout) and [`stream_timeout`](Self::stream_timeout).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnthropicError::Config`](crate::AnthropicError::Config) if
+    /// the timeout or retry count cannot be parsed.
+    pub fn from_env() -> Result<Self, crate::AnthropicError> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Create a configuration from variables looked up by name.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, crate::AnthropicError> {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        let invalid = |name: &str, value: &str| crate::AnthropicError::Config {
+            message: format!("Invalid {}: {:?}", name, value),
+        };
+
+        let mut config = Self {
+            api_key: var("ANTHROPIC_API_KEY"),
+            auth_token: var("ANTHROPIC_AUTH_TOKEN"),
+            admin_api_key: var("ANTHROPIC_ADMIN_KEY"),
+            workspace_id: var("ANTHROPIC_WORKSPACE_ID"),
+            log_level: var("ANTHROPIC_LOG").and_then(|level| LogLevel::parse(&level)),
+            ..Default::default()
+        };
+
+        if let Some(base_url) = var("ANTHROPIC_BASE_URL") {
+            config.base_url = base_url.trim().trim_end_matches('/').to_string();
+        }
+        if let Some(timeout) = var("ANTHROPIC_TIMEOUT") {
+            config.timeout = timeout
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                .ok_or_else(|| invalid("ANTHROPIC_TIMEOUT", &timeout))?;
+            config.stream_timeout = Some(config.timeout);
+            config.scale_timeout = false;
+        }
+        if let Some(max_retries) = var("ANTHROPIC_MAX_RETRIES") {
+            config.max_retries = max_retries
+                .trim()
+                .parse()
+                .map_err(|_| invalid("ANTHROPIC_MAX_RETRIES", &max_retries))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Start a builder that checks the configuration when it is built.
+    pub fn builder() -> ClientConfigBuilder {
+        ClientConfigBuilder::new()
+    }
+
     /// Create a new configuration with an API key.
     pub fn with_api_key(api_key: impl Into<String>) -> Self {
         Self {
@@ -57,6 +257,27 @@ impl ClientConfig {
         }
     }
 
+    /// Create a new configuration that gets credentials from a provider.
+    pub fn with_credentials(credentials: impl CredentialsProvider + 'static) -> Self {
+        Self::default().credentials(credentials)
+    }
+
+    /// Create a new configuration with an admin API key.
+    pub fn with_admin_api_key(admin_api_key: impl Into<String>) -> Self {
+        Self {
+            admin_api_key: Some(admin_api_key.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Get the credentials for each request from a provider.
+    ///
+    /// The provider replaces `api_key` and `auth_token`.
+    pub fn credentials(mut self, credentials: impl CredentialsProvider + 'static) -> Self {
+        self.credentials = Some(Arc::new(credentials));
+        self
+    }
+
     /// Set the base URL.
     pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
         self.base_url = base_url.into();
@@ -64,8 +285,47 @@ impl ClientConfig {
     }
 
     /// Set the request timeout.
+    ///
+    /// The timeout is no longer raised for long generations; see
+    /// [`scale_timeout`](Self::scale_timeout).
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
+        self.scale_timeout = false;
+        self
+    }
+
+    /// Set the total time allowed for a streamed response, or `None` for no
+    /// limit.
+    ///
+    /// The timeout is no longer raised for long generations; see
+    /// [`scale_timeout`](Self::scale_timeout).
+    pub fn stream_timeout(mut self, stream_timeout: Option<Duration>) -> Self {
+        self.stream_timeout = stream_timeout;
+        self.scale_timeout = false;
+        self
+    }
+
+    /// Enable or disable raising the default timeout for long generations.
+    pub fn scale_timeout(mut self, enabled: bool) -> Self {
+        self.scale_timeout = enabled;
+        self
+    }
+
+    /// Set the time allowed to establish a connection.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Set the longest wait for more of a response once it has started.
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Set the workspace that requests are attributed to.
+    pub fn workspace_id(mut self, workspace_id: impl Into<String>) -> Self {
+        self.workspace_id = Some(workspace_id.into());
         self
     }
 
@@ -75,6 +335,19 @@ impl ClientConfig {
         self
     }
 
+    /// Set the delays between retries and the total time allowed for them.
+    pub fn retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Call a hook before each retry, with the attempt, its cause and the
+    /// delay before it.
+    pub fn on_retry(mut self, hook: impl RetryHook + 'static) -> Self {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
     /// Add a default header.
     pub fn default_header(
         mut self,
@@ -87,11 +360,147 @@ impl ClientConfig {
         self
     }
 
+    /// Allow prompts of up to 1M tokens on every request, on models that
+    /// support it, by opting into the [`CONTEXT_1M`](betas::CONTEXT_1M) beta.
+    ///
+    /// The flag is added to any default `anthropic-beta` header.
+    pub fn context_1m(self) -> Self {
+        self.default_beta(betas::CONTEXT_1M)
+    }
+
+    /// Add a beta flag to the default `anthropic-beta` header.
+    fn default_beta(mut self, beta: &str) -> Self {
+        let configured = self
+            .default_headers
+            .get("anthropic-beta")
+            .and_then(|value| value.to_str().ok());
+        let value = match configured {
+            Some(betas) if betas.split(',').any(|b| b.trim() == beta) => return self,
+            Some(betas) if !betas.trim().is_empty() => format!("{},{}", betas, beta),
+            _ => beta.to_string(),
+        };
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            self.default_headers.insert("anthropic-beta", value);
+        }
+        self
+    }
+
+    /// Enable or disable the platform metadata headers.
+    pub fn platform_headers(mut self, enabled: bool) -> Self {
+        self.platform_headers = enabled;
+        self
+    }
+
+    /// Send requests with an existing HTTP client.
+    ///
+    /// Use this to share a connection pool with the rest of an application, or
+    /// to configure proxies and TLS on the client directly.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Send requests through a custom transport.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Send all requests through a proxy.
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Set how long an idle pooled connection is kept open.
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections kept per host.
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Set the idle time before TCP keepalive probes are sent.
+    pub fn tcp_keepalive(mut self, tcp_keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(tcp_keepalive);
+        self
+    }
+
+    /// Send HTTP/2 pings at this interval on open connections.
+    pub fn http2_keep_alive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keep_alive_interval = Some(interval);
+        self
+    }
+
+    /// Set how long to wait for an HTTP/2 ping to be acknowledged.
+    pub fn http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http2_keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable or disable adaptive HTTP/2 flow-control windows.
+    pub fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Fail when a JSON response body is larger than `max_response_size`
+    /// bytes.
+    pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = Some(max_response_size);
+        self
+    }
+
+    /// Fail a message stream when an event is larger than `max_event_size`
+    /// bytes.
+    pub fn max_event_size(mut self, max_event_size: usize) -> Self {
+        self.max_event_size = Some(max_event_size);
+        self
+    }
+
+    /// Limit the number of requests in flight at once.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// Delay requests when the rate-limit budget reported by the API runs low.
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Log requests through `tracing` at the given level.
+    pub fn log_level(mut self, log_level: LogLevel) -> Self {
+        self.log_level = Some(log_level);
+        self
+    }
+
     /// Validate the configuration.
     pub fn validate(&self) -> Result<(), crate::AnthropicError> {
-        if self.api_key.is_none() && self.auth_token.is_none() {
+        if self.api_key.is_none()
+            && self.auth_token.is_none()
+            && self.credentials.is_none()
+            && self.admin_api_key.is_none()
+        {
             return Err(crate::AnthropicError::MissingApiKey);
         }
+        if self.max_concurrent_requests == Some(0) {
+            return Err(crate::AnthropicError::Config {
+                message: "max_concurrent_requests must be at least 1".into(),
+            });
+        }
+        if let Some(workspace_id) = &self.workspace_id {
+            if HeaderValue::from_str(workspace_id).is_err() {
+                return Err(crate::AnthropicError::Config {
+                    message: format!("Invalid workspace ID {:?}", workspace_id),
+                });
+            }
+        }
         Ok(())
     }
 
@@ -104,4 +513,64 @@ impl ClientConfig {
     pub fn auth_token(&self) -> Option<&str> {
         self.auth_token.as_deref()
     }
+
+    /// Get the admin API key.
+    pub fn admin_api_key(&self) -> Option<&str> {
+        self.admin_api_key.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_vars(vars: &[(&str, &str)]) -> Result<ClientConfig, crate::AnthropicError> {
+        ClientConfig::from_vars(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let config = from_vars(&[
+            ("ANTHROPIC_API_KEY", "sk-test"),
+            ("ANTHROPIC_BASE_URL", "https://gateway.internal/anthropic/"),
+            ("ANTHROPIC_TIMEOUT", "30.5"),
+            ("ANTHROPIC_MAX_RETRIES", "5"),
+            ("ANTHROPIC_AUTH_TOKEN", ""),
+        ])
+        .unwrap();
+
+        assert_eq!(config.api_key(), Some("sk-test"));
+        assert_eq!(config.auth_token(), None);
+        assert_eq!(config.base_url, "https://gateway.internal/anthropic");
+        assert_eq!(config.timeout, Duration::from_millis(30_500));
+        assert_eq!(config.stream_timeout, Some(Duration::from_millis(30_500)));
+        assert_eq!(config.max_retries, 5);
+
+        let config = from_vars(&[]).unwrap();
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.max_retries, DEFAULT_MAX_RETRIES);
+
+        assert!(from_vars(&[("ANTHROPIC_TIMEOUT", "soon")]).is_err());
+        assert!(from_vars(&[("ANTHROPIC_TIMEOUT", "-1")]).is_err());
+        assert!(from_vars(&[("ANTHROPIC_MAX_RETRIES", "-1")]).is_err());
+    }
+
+    #[test]
+    fn test_context_1m() {
+        let config = ClientConfig::default().context_1m();
+        assert_eq!(config.default_headers["anthropic-beta"], betas::CONTEXT_1M);
+
+        let config = ClientConfig::default()
+            .default_header("anthropic-beta", "some-beta")
+            .context_1m()
+            .context_1m();
+        assert_eq!(
+            config.default_headers["anthropic-beta"],
+            format!("some-beta,{}", betas::CONTEXT_1M)
+        );
+    }
 }
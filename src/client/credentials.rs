@@ -0,0 +1,95 @@
+//! Credentials that can be fetched or rotated at runtime.
+
+use std::fmt;
+use std::future::Future;
+
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+
+use crate::error::{AnthropicError, Result};
+
+/// A credential to authenticate a request with.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// An API key, sent in the `x-api-key` header.
+    ApiKey(String),
+    /// A bearer token, sent in the `Authorization` header.
+    BearerToken(String),
+}
+
+impl Credentials {
+    /// Add the authentication header for this credential.
+    pub(crate) fn apply(&self, headers: &mut HeaderMap) -> Result<()> {
+        let (name, value) = match self {
+            Self::ApiKey(key) => ("x-api-key", HeaderValue::from_str(key)),
+            Self::BearerToken(token) => (
+                AUTHORIZATION.as_str(),
+                HeaderValue::from_str(&format!("Bearer {}", token)),
+            ),
+        };
+        let mut value = value.map_err(|_| AnthropicError::Config {
+            message: "Credentials are not a valid header value".into(),
+        })?;
+        value.set_sensitive(true);
+        headers.insert(name, value);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiKey(_) => f.write_str("ApiKey(<redacted>)"),
+            Self::BearerToken(_) => f.write_str("BearerToken(<redacted>)"),
+        }
+    }
+}
+
+/// Supplies the credentials for each request.
+///
+/// Set one with [`ClientConfig::credentials`](super::ClientConfig::credentials)
+/// to use keys fetched from a secret store or rotated while the client runs.
+/// The provider is asked once per request, so it should cache what it fetches.
+/// [`Credentials`] itself is a provider that always returns the same value,
+/// and so are async closures returning [`Result<Credentials>`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::{AsyncAnthropic, ClientConfig, Credentials};
+///
+/// # async fn fetch_key_from_vault() -> anthropic_sdk::Result<String> { unimplemented!() }
+/// # fn main() -> anthropic_sdk::Result<()> {
+/// let config = ClientConfig::default().credentials(|| async {
+///     Ok(Credentials::ApiKey(fetch_key_from_vault().await?))
+/// });
+/// let client = AsyncAnthropic::with_config(config)?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait CredentialsProvider: Send + Sync {
+    /// Get the credentials to send with the next request.
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials>>;
+}
+
+impl CredentialsProvider for Credentials {
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials>> {
+        Box::pin(std::future::ready(Ok(self.clone())))
+    }
+}
+
+impl<F, Fut> CredentialsProvider for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Credentials>> + Send + 'static,
+{
+    fn credentials(&self) -> BoxFuture<'_, Result<Credentials>> {
+        Box::pin(self())
+    }
+}
+
+impl fmt::Debug for dyn CredentialsProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CredentialsProvider")
+    }
+}
@@ -0,0 +1,89 @@
+//! Diagnostic logging of requests through `tracing`, controlled by
+//! `ANTHROPIC_LOG`.
+
+use std::fmt;
+
+use reqwest::header::{HeaderMap, AUTHORIZATION};
+
+/// How much the client logs.
+///
+/// Records are emitted as [`tracing`] events from the `anthropic_sdk` target,
+/// so an application's subscriber decides where they go. Read from the
+/// `ANTHROPIC_LOG` environment variable (`info` or `debug`) by default; see
+/// [`ClientConfig::log_level`](super::ClientConfig::log_level). Credentials
+/// are never logged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Log each response's method, URL, status, request ID and duration, and
+    /// each retry.
+    Info,
+    /// Also log each request as it is sent, with its headers.
+    Debug,
+}
+
+impl LogLevel {
+    /// Read the level from `ANTHROPIC_LOG`, ignoring unknown values.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("ANTHROPIC_LOG")
+            .ok()
+            .and_then(|value| Self::parse(&value))
+    }
+
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+        })
+    }
+}
+
+/// Formats headers for the log, hiding credentials.
+pub(crate) struct Redacted<'a>(pub(crate) &'a HeaderMap);
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for (name, value) in self.0 {
+            if name == AUTHORIZATION || name == "x-api-key" {
+                map.entry(&name.as_str(), &"<redacted>");
+            } else {
+                map.entry(&name.as_str(), &value.to_str().unwrap_or("<binary>"));
+            }
+        }
+        map.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_parse_and_redact() {
+        assert_eq!(LogLevel::parse("debug"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::parse(" INFO "), Some(LogLevel::Info));
+        assert_eq!(LogLevel::parse("trace"), None);
+        assert!(Some(LogLevel::Debug) >= Some(LogLevel::Info));
+        assert!(None < Some(LogLevel::Info));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_static("sk-ant-secret"));
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer secret"));
+        headers.insert("anthropic-version", HeaderValue::from_static("2023-06-01"));
+
+        let logged = Redacted(&headers).to_string();
+        assert!(!logged.contains("secret"));
+        assert!(logged.contains("\"anthropic-version\": \"2023-06-01\""));
+    }
+}
@@ -0,0 +1,94 @@
+//! Request middleware.
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use reqwest::{Request, Response};
+
+use super::Transport;
+use crate::error::Result;
+
+/// Intercepts every HTTP request the client sends.
+///
+/// A middleware receives the request and the rest of the chain. It can change
+/// the request before passing it on with [`Next::run`], inspect or replace the
+/// response, or answer without calling `next` at all. Middleware runs for
+/// JSON, streaming and upload requests, and again for each retry.
+///
+/// Closures with the matching signature implement this trait.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::{Middleware, Next};
+/// use anthropic_sdk::AsyncAnthropic;
+/// use futures::future::BoxFuture;
+/// use reqwest::{Request, Response};
+///
+/// struct Logger;
+///
+/// impl Middleware for Logger {
+///     fn handle<'a>(
+///         &'a self,
+///         request: Request,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, anthropic_sdk::Result<Response>> {
+///         Box::pin(async move {
+///             let url = request.url().clone();
+///             let response = next.run(request).await?;
+///             println!("{} -> {}", url, response.status());
+///             Ok(response)
+///         })
+///     }
+/// }
+///
+/// # fn main() -> anthropic_sdk::Result<()> {
+/// let client = AsyncAnthropic::new()?.with_middleware(Logger);
+/// # Ok(())
+/// # }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handle a request, usually by passing it on to `next`.
+    fn handle<'a>(&'a self, request: Request, next: Next<'a>) -> BoxFuture<'a, Result<Response>>;
+}
+
+impl<F> Middleware for F
+where
+    F: for<'a> Fn(Request, Next<'a>) -> BoxFuture<'a, Result<Response>> + Send + Sync,
+{
+    fn handle<'a>(&'a self, request: Request, next: Next<'a>) -> BoxFuture<'a, Result<Response>> {
+        self(request, next)
+    }
+}
+
+/// The rest of the middleware chain, ending with the [`Transport`].
+pub struct Next<'a> {
+    transport: &'a dyn Transport,
+    middleware: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(transport: &'a dyn Transport, middleware: &'a [Arc<dyn Middleware>]) -> Self {
+        Self {
+            transport,
+            middleware,
+        }
+    }
+
+    /// Pass the request to the next middleware, or send it.
+    pub fn run(self, request: Request) -> BoxFuture<'a, Result<Response>> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Next::new(self.transport, rest)),
+            None => self.transport.execute(request),
+        }
+    }
+}
+
+impl fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &self.middleware.len())
+            .finish()
+    }
+}
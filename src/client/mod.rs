@@ -1,9 +1,34 @@
 //! HTTP client implementations for the Anthropic API.
 
 mod async_client;
+mod builder;
 mod config;
+mod credentials;
+mod logging;
+mod middleware;
+mod options;
+mod platform;
+mod rate_limit;
+mod response;
+mod retry;
+mod scheduler;
 mod sync_client;
+mod transport;
 
 pub use async_client::AsyncAnthropic;
+pub use builder::{ClientConfigBuilder, ConfigError};
 pub use config::ClientConfig;
+pub use credentials::{Credentials, CredentialsProvider};
+pub use logging::LogLevel;
+pub use middleware::{Middleware, Next};
+pub use options::RequestOptions;
+pub use rate_limit::{RateLimitBudget, RateLimitConfig, RateLimitInfo};
+pub use response::ApiResponse;
+pub use retry::{RetryCause, RetryConfig, RetryEvent, RetryHook};
+pub use scheduler::Priority;
+pub(crate) use scheduler::RequestPermit;
+pub(crate) use sync_client::block_on;
 pub use sync_client::Anthropic;
+#[cfg(test)]
+pub(crate) use transport::Replay;
+pub use transport::Transport;
@@ -0,0 +1,173 @@
+//! Per-request options.
+
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::error::{AnthropicError, Result};
+
+use super::Priority;
+
+/// Options that override the client configuration for a single request.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::RequestOptions;
+/// use std::time::Duration;
+///
+/// # fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(30))
+///     .max_retries(0)
+///     .header("x-trace-id", "abc123")?
+///     .header("anthropic-version", "2023-06-01")?
+///     .remove_header("anthropic-beta")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Timeout for each attempt, replacing the client timeout.
+    pub timeout: Option<Duration>,
+
+    /// Maximum number of retries, replacing the client setting.
+    pub max_retries: Option<u32>,
+
+    /// Headers to add, replacing any header of the same name, including the
+    /// client's default headers and those the SDK sets.
+    pub extra_headers: HeaderMap,
+
+    /// Headers to leave out of the request, even if the client or the SDK
+    /// would set them.
+    pub remove_headers: Vec<HeaderName>,
+
+    /// Query parameters to append to the URL.
+    pub extra_query: Vec<(String, String)>,
+
+    /// Key sent in the `Idempotency-Key` header, replacing the one generated
+    /// for each request that is not a `GET`.
+    ///
+    /// The key is the same on every retry, so gateways that support it can
+    /// tell a retry from a new request. Reuse a key to make a request
+    /// idempotent across separate calls too.
+    pub idempotency_key: Option<String>,
+
+    /// Priority for a slot when the client is at its concurrency limit.
+    pub priority: Priority,
+
+    /// Time by which the request, with all its retries and the waits between
+    /// them, must finish.
+    ///
+    /// When it passes, the request fails with
+    /// [`AnthropicError::DeadlineExceeded`](crate::AnthropicError::DeadlineExceeded),
+    /// and no retry is started that could not begin before it. A streamed
+    /// response must also be read by then.
+    pub deadline: Option<Instant>,
+}
+
+impl RequestOptions {
+    /// Create options that keep the client defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout for each attempt.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of retries.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Add a header.
+    ///
+    /// Fails with [`AnthropicError::Config`] if the name or value cannot be
+    /// sent as a header.
+    pub fn header(mut self, name: &str, value: &str) -> Result<Self> {
+        let value = HeaderValue::try_from(value).map_err(|_| AnthropicError::Config {
+            message: format!("Invalid value for header {:?}", name),
+        })?;
+        self.extra_headers.insert(header_name(name)?, value);
+        Ok(self)
+    }
+
+    /// Leave a header out of the request.
+    ///
+    /// Fails with [`AnthropicError::Config`] if the name is not a valid header
+    /// name.
+    pub fn remove_header(mut self, name: &str) -> Result<Self> {
+        self.remove_headers.push(header_name(name)?);
+        Ok(self)
+    }
+
+    /// Add a query parameter.
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.extra_query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the priority for a slot under the concurrency limit.
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the time by which the request must finish, retries included.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Set the deadline to `timeout` from now.
+    pub fn deadline_in(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Set the idempotency key.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+}
+
+/// Parse a header name given to the options.
+fn header_name(name: &str) -> Result<HeaderName> {
+    HeaderName::try_from(name).map_err(|_| AnthropicError::Config {
+        message: format!("Invalid header name {:?}", name),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_headers_are_errors() {
+        let error = RequestOptions::new()
+            .header("bad header", "value")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Configuration error: Invalid header name \"bad header\""
+        );
+        let error = RequestOptions::new()
+            .header("x-team", "line\nbreak")
+            .unwrap_err();
+        assert!(matches!(error, AnthropicError::Config { .. }));
+        assert!(RequestOptions::new().remove_header("bad header").is_err());
+
+        let options = RequestOptions::new()
+            .header("x-team", "search")
+            .unwrap()
+            .remove_header("anthropic-beta")
+            .unwrap();
+        assert_eq!(options.extra_headers["x-team"], "search");
+        assert_eq!(options.remove_headers, ["anthropic-beta"]);
+    }
+}
@@ -0,0 +1,80 @@
+//! Platform metadata sent with each request.
+
+use std::sync::OnceLock;
+
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+
+/// The SDK, OS, architecture and compiler versions, in the headers the
+/// official SDKs send.
+pub(crate) fn platform_headers() -> &'static HeaderMap {
+    static HEADERS: OnceLock<HeaderMap> = OnceLock::new();
+    HEADERS.get_or_init(|| {
+        let version = env!("CARGO_PKG_VERSION");
+        let mut headers = HeaderMap::new();
+        let mut insert = |name: &'static str, value: String| {
+            if let Ok(value) = HeaderValue::from_str(&value) {
+                headers.insert(name, value);
+            }
+        };
+        insert(USER_AGENT.as_str(), format!("Anthropic/Rust {}", version));
+        insert("x-stainless-lang", "rust".into());
+        insert("x-stainless-package-version", version.into());
+        insert("x-stainless-os", os_name(std::env::consts::OS));
+        insert("x-stainless-arch", arch_name(std::env::consts::ARCH));
+        insert("x-stainless-runtime", "rustc".into());
+        insert(
+            "x-stainless-runtime-version",
+            env!("ANTHROPIC_SDK_RUSTC_VERSION").into(),
+        );
+        headers
+    })
+}
+
+/// The name the official SDKs use for an operating system.
+fn os_name(os: &str) -> String {
+    match os {
+        "linux" => "Linux".into(),
+        "macos" => "MacOS".into(),
+        "windows" => "Windows".into(),
+        "freebsd" => "FreeBSD".into(),
+        "openbsd" => "OpenBSD".into(),
+        "android" => "Android".into(),
+        "ios" => "iOS".into(),
+        other => format!("Other:{}", other),
+    }
+}
+
+/// The name the official SDKs use for a CPU architecture.
+fn arch_name(arch: &str) -> String {
+    match arch {
+        "x86_64" => "x64".into(),
+        "aarch64" => "arm64".into(),
+        "x86" => "x32".into(),
+        "arm" => "arm".into(),
+        other => format!("other:{}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_headers() {
+        assert_eq!(os_name("macos"), "MacOS");
+        assert_eq!(os_name("haiku"), "Other:haiku");
+        assert_eq!(arch_name("aarch64"), "arm64");
+        assert_eq!(arch_name("riscv64"), "other:riscv64");
+
+        let headers = platform_headers();
+        assert_eq!(headers["x-stainless-lang"], "rust");
+        assert_eq!(
+            headers["x-stainless-package-version"],
+            env!("CARGO_PKG_VERSION")
+        );
+        assert!(headers[USER_AGENT]
+            .to_str()
+            .unwrap()
+            .starts_with("Anthropic/Rust "));
+    }
+}
@@ -0,0 +1,408 @@
+//! Client-side rate limiting from the `anthropic-ratelimit-*` headers.
+
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+/// Settings for delaying requests when the rate-limit budget runs low.
+///
+/// The API reports the remaining budget in `anthropic-ratelimit-*` headers on
+/// every response. With rate limiting enabled, a request that would exceed
+/// that budget waits until the budget resets instead of failing with a 429.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{ClientConfig, RateLimitConfig};
+/// use std::time::Duration;
+///
+/// let config = ClientConfig::with_api_key("your-api-key").rate_limit(
+///     RateLimitConfig::new()
+///         .token_reserve(2_000)
+///         .max_delay(Duration::from_secs(30)),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Wait while at most this many requests remain.
+    pub request_reserve: u64,
+
+    /// Wait while at most this many tokens remain.
+    pub token_reserve: u64,
+
+    /// The longest a request waits for the budget to reset.
+    pub max_delay: Duration,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            request_reserve: 0,
+            token_reserve: 0,
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RateLimitConfig {
+    /// Create the default settings, which wait only when a budget is exhausted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of requests to keep in reserve.
+    pub fn request_reserve(mut self, request_reserve: u64) -> Self {
+        self.request_reserve = request_reserve;
+        self
+    }
+
+    /// Set the number of tokens to keep in reserve.
+    pub fn token_reserve(mut self, token_reserve: u64) -> Self {
+        self.token_reserve = token_reserve;
+        self
+    }
+
+    /// Set the longest a request waits for the budget to reset.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+/// The rate-limit state reported in a response's `anthropic-ratelimit-*`
+/// headers.
+///
+/// Each budget is present only if the response reported it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests per minute.
+    pub requests: Option<RateLimitBudget>,
+
+    /// Tokens per minute, where input and output tokens share one limit.
+    pub tokens: Option<RateLimitBudget>,
+
+    /// Input tokens per minute.
+    pub input_tokens: Option<RateLimitBudget>,
+
+    /// Output tokens per minute.
+    pub output_tokens: Option<RateLimitBudget>,
+}
+
+/// One rate limit and how much of it remains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitBudget {
+    /// The maximum allowed in the current window, if reported.
+    pub limit: Option<u64>,
+
+    /// How much is left in the current window.
+    pub remaining: u64,
+
+    /// When the budget is fully replenished, if reported.
+    pub reset: Option<SystemTime>,
+}
+
+impl RateLimitInfo {
+    /// Parse the rate-limit headers, returning `None` if there are none.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let budget = |name: &str| {
+            let header = |suffix: &str| {
+                headers
+                    .get(format!("anthropic-ratelimit-{}-{}", name, suffix))
+                    .and_then(|value| value.to_str().ok())
+            };
+            Some(RateLimitBudget {
+                limit: header("limit").and_then(|v| v.parse().ok()),
+                remaining: header("remaining")?.parse().ok()?,
+                reset: header("reset").and_then(parse_rfc3339),
+            })
+        };
+
+        let info = Self {
+            requests: budget("requests"),
+            tokens: budget("tokens"),
+            input_tokens: budget("input-tokens"),
+            output_tokens: budget("output-tokens"),
+        };
+        (info != Self::default()).then_some(info)
+    }
+
+    /// The earliest time a budget that has run out is replenished.
+    pub fn exhausted_until(&self) -> Option<SystemTime> {
+        self.budgets()
+            .filter(|budget| budget.remaining == 0)
+            .filter_map(|budget| budget.reset)
+            .min()
+    }
+
+    fn budgets(&self) -> impl Iterator<Item = &RateLimitBudget> {
+        [
+            &self.requests,
+            &self.tokens,
+            &self.input_tokens,
+            &self.output_tokens,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+/// Tracks the rate-limit budget reported by the API, shared by clones of a
+/// client.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    config: Option<RateLimitConfig>,
+    state: Mutex<RateLimitInfo>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: Option<RateLimitConfig>) -> Self {
+        Self {
+            config,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Wait until the budget allows another request, if rate limiting is
+    /// enabled.
+    pub(crate) async fn acquire(&self) {
+        let Some(config) = &self.config else {
+            return;
+        };
+        let delay = reserve(&mut self.state.lock().unwrap(), config, SystemTime::now());
+        if let Some(delay) = delay {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Record the budget reported in a response.
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let Some(info) = RateLimitInfo::from_headers(headers) else {
+            return;
+        };
+        let mut guard = self.state.lock().unwrap();
+        let state = &mut *guard;
+        for (budget, reported) in [
+            (&mut state.requests, info.requests),
+            (&mut state.tokens, info.tokens),
+            (&mut state.input_tokens, info.input_tokens),
+            (&mut state.output_tokens, info.output_tokens),
+        ] {
+            if reported.is_some() {
+                *budget = reported;
+            }
+        }
+    }
+}
+
+/// Compute how long to wait before sending, reserving a request from the
+/// budget if none is needed.
+fn reserve(
+    state: &mut RateLimitInfo,
+    config: &RateLimitConfig,
+    now: SystemTime,
+) -> Option<Duration> {
+    let delay = [
+        (&mut state.requests, config.request_reserve),
+        (&mut state.tokens, config.token_reserve),
+        (&mut state.input_tokens, config.token_reserve),
+        (&mut state.output_tokens, config.token_reserve),
+    ]
+    .into_iter()
+    .filter_map(|(budget, reserve)| delay(budget, reserve, now))
+    .max();
+
+    match delay {
+        Some(delay) => Some(delay.min(config.max_delay)),
+        None => {
+            // Count this request so concurrent callers see the smaller budget.
+            if let Some(budget) = &mut state.requests {
+                budget.remaining = budget.remaining.saturating_sub(1);
+            }
+            None
+        }
+    }
+}
+
+/// How long until `budget` resets, if it is at or below `reserve`.
+fn delay(budget: &mut Option<RateLimitBudget>, reserve: u64, now: SystemTime) -> Option<Duration> {
+    let remaining = budget.as_ref()?.remaining;
+    match budget
+        .as_ref()?
+        .reset
+        .map(|reset| reset.duration_since(now))
+    {
+        Some(Ok(delay)) if remaining <= reserve => Some(delay),
+        Some(Ok(_)) => None,
+        // The budget has reset since it was reported, so it is unknown.
+        _ => {
+            *budget = None;
+            None
+        }
+    }
+}
+
+/// Parse an RFC 3339 timestamp such as `2025-01-01T12:00:30Z`.
+///
+/// Returns `None` for anything malformed or out of range, since the value
+/// comes from a response header.
+pub(crate) fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+    let (date, time) = value.split_once(['T', 't', ' '])?;
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+
+    let offset_at = time.find(['Z', 'z', '+', '-'])?;
+    let (clock, offset) = time.split_at(offset_at);
+    let offset_secs = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let (hours, minutes) = (hours.parse::<i64>().ok()?, minutes.parse::<i64>().ok()?);
+            if !(0..=23).contains(&hours) || !(0..=59).contains(&minutes) {
+                return None;
+            }
+            let secs = hours * 3600 + minutes * 60;
+            if offset.starts_with('-') {
+                -secs
+            } else {
+                secs
+            }
+        }
+    };
+
+    let mut clock = clock.splitn(3, ':');
+    let hours = clock.next()?.parse::<i64>().ok()?;
+    let minutes = clock.next()?.parse::<i64>().ok()?;
+    let seconds = clock.next()?;
+    let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+    let seconds = seconds.parse::<i64>().ok()?;
+    let nanos = parse_nanos(fraction)?;
+    if !(0..=9999).contains(&year)
+        || !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..=23).contains(&hours)
+        || !(0..=59).contains(&minutes)
+        || !(0..=60).contains(&seconds)
+    {
+        return None;
+    }
+
+    // Days since the epoch, from Howard Hinnant's `days_from_civil`.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let year_of_era = y - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+
+    let secs = days * 86_400 + hours * 3600 + minutes * 60 + seconds - offset_secs;
+    let secs = u64::try_from(secs).ok()?;
+    UNIX_EPOCH.checked_add(Duration::new(secs, nanos))
+}
+
+/// Parse the digits after the decimal point of a seconds field as
+/// nanoseconds, ignoring digits beyond the ninth.
+fn parse_nanos(fraction: &str) -> Option<u32> {
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits = &fraction[..fraction.len().min(9)];
+    let nanos = digits.parse::<u32>().unwrap_or(0);
+    Some(nanos * 10u32.pow(9 - digits.len() as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let at = |secs: u64| Some(UNIX_EPOCH + Duration::from_secs(secs));
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), at(0));
+        assert_eq!(parse_rfc3339("2025-01-01T12:00:30Z"), at(1_735_732_830));
+        assert_eq!(
+            parse_rfc3339("2025-01-01T13:00:30+01:00"),
+            at(1_735_732_830)
+        );
+        assert_eq!(
+            parse_rfc3339("2024-02-29T00:00:00.5Z"),
+            Some(UNIX_EPOCH + Duration::from_millis(1_709_164_800_500))
+        );
+        assert_eq!(parse_rfc3339("not a date"), None);
+
+        for malformed in [
+            "2025-01-01T00:00:infZ",
+            "2025-01-01T00:00:NaNZ",
+            "2025-01-01T00:00:1e400Z",
+            "2025-01-01T00:00:00.-5Z",
+            "2025-01-01T24:00:00Z",
+            "2025-01-01T00:00:00+99:00",
+            "9223372036854775807-01-01T00:00:00Z",
+            "1969-12-31T23:59:59Z",
+        ] {
+            assert_eq!(parse_rfc3339(malformed), None, "{}", malformed);
+        }
+    }
+
+    fn headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in [
+            ("anthropic-ratelimit-requests-limit", "50"),
+            ("anthropic-ratelimit-requests-remaining", "1"),
+            ("anthropic-ratelimit-requests-reset", "2025-01-01T12:00:30Z"),
+            ("anthropic-ratelimit-tokens-remaining", "5000"),
+            ("anthropic-ratelimit-tokens-reset", "2025-01-01T12:00:10Z"),
+        ] {
+            headers.insert(name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_from_headers() {
+        let info = RateLimitInfo::from_headers(&headers()).unwrap();
+        assert_eq!(
+            info.requests,
+            Some(RateLimitBudget {
+                limit: Some(50),
+                remaining: 1,
+                reset: parse_rfc3339("2025-01-01T12:00:30Z"),
+            })
+        );
+        assert_eq!(info.tokens.unwrap().limit, None);
+        assert_eq!(info.input_tokens, None);
+        assert_eq!(info.exhausted_until(), None);
+
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let limiter = RateLimiter::default();
+        limiter.update(&headers());
+        let mut state = limiter.state.into_inner().unwrap();
+        let now = parse_rfc3339("2025-01-01T12:00:00Z").unwrap();
+
+        let config = RateLimitConfig::new();
+        assert_eq!(reserve(&mut state, &config, now), None);
+        assert_eq!(
+            reserve(&mut state, &config, now),
+            Some(Duration::from_secs(30))
+        );
+
+        let config = RateLimitConfig::new()
+            .token_reserve(5000)
+            .max_delay(Duration::from_secs(20));
+        let mut state = RateLimitInfo::from_headers(&headers()).unwrap();
+        assert_eq!(
+            reserve(&mut state, &config, now),
+            Some(Duration::from_secs(10))
+        );
+
+        // Once the reset time passes the budget is forgotten.
+        let later = now + Duration::from_secs(60);
+        assert_eq!(reserve(&mut state, &config, later), None);
+        assert_eq!(state.requests, None);
+    }
+}
@@ -0,0 +1,75 @@
+//! Successful responses with their HTTP metadata.
+
+use std::ops::Deref;
+
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+use super::RateLimitInfo;
+
+/// A parsed response body together with the status and headers it came with.
+///
+/// Returned by `create_with_response` and `count_tokens_with_response` on
+/// messages and by `create_with_response` and `retrieve_with_response` on
+/// batches, for when the request ID or other headers of a successful call are
+/// needed. Other endpoints return only the body. Dereferences to the body.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+///     let client = AsyncAnthropic::new()?;
+///
+///     let response = client.messages().create_with_response(
+///         MessageCreateParams::builder()
+///             .model("claude-sonnet-4-5-20250929")
+///             .max_tokens(1024)
+///             .messages(vec![MessageParam::user("Hello, Claude!")])
+///             .build()
+///     ).await?;
+///
+///     println!("{:?}: {}", response.request_id(), response.text());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The parsed response body.
+    pub data: T,
+
+    /// The HTTP status code.
+    pub status: StatusCode,
+
+    /// The response headers.
+    pub headers: HeaderMap,
+}
+
+impl<T> ApiResponse<T> {
+    /// Get the ID the API assigned to the request, from the `request-id` header.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("request-id")
+            .and_then(|value| value.to_str().ok())
+    }
+
+    /// Get the rate-limit state reported in the headers.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        RateLimitInfo::from_headers(&self.headers)
+    }
+
+    /// Discard the metadata and return the body.
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Deref for ApiResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
@@ -0,0 +1,210 @@
+//! Retry timing and notifications.
+
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::error::AnthropicError;
+
+/// How long to wait between retries, and for how long to keep retrying.
+///
+/// Retries back off exponentially from `initial_delay`, doubling up to
+/// `max_delay`, with up to 25% jitter. A `retry-after` header sent by the API
+/// is followed instead when it is no longer than `max_retry_after`. The number
+/// of retries is set by [`ClientConfig::max_retries`](super::ClientConfig::max_retries).
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{ClientConfig, RetryConfig};
+/// use std::time::Duration;
+///
+/// let config = ClientConfig::with_api_key("your-api-key")
+///     .max_retries(5)
+///     .retry(RetryConfig::new().max_elapsed(Duration::from_secs(20)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    /// Delay before the first retry.
+    pub initial_delay: Duration,
+
+    /// Longest delay between retries when backing off.
+    pub max_delay: Duration,
+
+    /// Longest `retry-after` header value to follow; longer values fall back
+    /// to backing off.
+    pub max_retry_after: Duration,
+
+    /// Total time after which no further retry is started, unlimited when
+    /// unset. A retry is skipped if waiting for it would exceed this.
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            max_retry_after: Duration::from_secs(60),
+            max_elapsed: None,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Create the default retry timing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// Set the longest delay between retries when backing off.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the longest `retry-after` header value to follow.
+    pub fn max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    /// Stop retrying once this much time has passed since the first attempt.
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Calculate the delay before retry number `attempt + 1`.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            if retry_after <= self.max_retry_after {
+                return retry_after;
+            }
+        }
+
+        // Exponential backoff with jitter
+        let base_delay = self.initial_delay.as_secs_f64() * 2.0_f64.powi(attempt as i32);
+        let delay = base_delay.min(self.max_delay.as_secs_f64());
+
+        // Add some jitter (±25%)
+        let jitter = 1.0 - 0.25 * rand_f64();
+        Duration::from_secs_f64(delay * jitter)
+    }
+
+    /// Whether a retry after `delay` still fits in the budget, given the time
+    /// already spent.
+    pub(crate) fn allows(&self, elapsed: Duration, delay: Duration) -> bool {
+        self.max_elapsed
+            .is_none_or(|max_elapsed| elapsed + delay <= max_elapsed)
+    }
+}
+
+/// A retry about to be made, passed to a [`RetryHook`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RetryEvent<'a> {
+    /// The number of this retry, starting at 1.
+    pub attempt: u32,
+    /// The most retries the request will make.
+    pub max_retries: u32,
+    /// How long the client waits before retrying.
+    pub delay: Duration,
+    /// Why the previous attempt failed.
+    pub cause: RetryCause<'a>,
+}
+
+/// Why a request is being retried.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RetryCause<'a> {
+    /// The API answered with a retryable status, such as 429 or 529.
+    Status(StatusCode),
+    /// The request timed out or the connection failed.
+    Error(&'a AnthropicError),
+}
+
+/// Notified before each retry.
+///
+/// Set one with [`ClientConfig::on_retry`](super::ClientConfig::on_retry) to
+/// show that a request is being retried. Closures taking a [`RetryEvent`] are
+/// hooks. The hook runs on the request's task, so it should return quickly.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{ClientConfig, RetryCause, RetryEvent};
+///
+/// let config = ClientConfig::with_api_key("your-api-key").on_retry(|event: &RetryEvent<'_>| {
+///     if let RetryCause::Status(status) = event.cause {
+///         eprintln!("{}, retrying in {:?}", status, event.delay);
+///     }
+/// });
+/// ```
+pub trait RetryHook: Send + Sync {
+    /// Called before waiting for a retry.
+    fn on_retry(&self, event: &RetryEvent<'_>);
+}
+
+impl<F> RetryHook for F
+where
+    F: Fn(&RetryEvent<'_>) + Send + Sync,
+{
+    fn on_retry(&self, event: &RetryEvent<'_>) {
+        self(event)
+    }
+}
+
+impl fmt::Debug for dyn RetryHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RetryHook")
+    }
+}
+
+/// Simple random number generator for jitter (0.0 to 1.0).
+fn rand_f64() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let state = RandomState::new();
+    let mut hasher = state.build_hasher();
+    hasher.write_u64(std::time::Instant::now().elapsed().as_nanos() as u64);
+    let hash = hasher.finish();
+
+    (hash as f64) / (u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let retry = RetryConfig::new()
+            .initial_delay(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(3))
+            .max_retry_after(Duration::from_secs(10));
+
+        for (attempt, max) in [(0, 1.0), (1, 2.0), (2, 3.0), (5, 3.0)] {
+            let delay = retry.delay(attempt, None).as_secs_f64();
+            assert!(delay > max * 0.75 - 1e-9 && delay <= max, "{}", delay);
+        }
+        assert_eq!(
+            retry.delay(0, Some(Duration::from_secs(7))),
+            Duration::from_secs(7)
+        );
+        assert!(retry.delay(0, Some(Duration::from_secs(11))) <= Duration::from_secs(1));
+
+        assert!(retry.allows(Duration::from_secs(100), Duration::from_secs(1)));
+        let retry = retry.max_elapsed(Duration::from_secs(5));
+        assert!(retry.allows(Duration::from_secs(3), Duration::from_secs(2)));
+        assert!(!retry.allows(Duration::from_secs(4), Duration::from_secs(2)));
+    }
+}
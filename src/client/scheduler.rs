@@ -0,0 +1,156 @@
+//! Priority-aware limit on requests in flight.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::oneshot;
+
+/// How urgently a request should get a slot when the client is at its
+/// concurrency limit.
+///
+/// Waiting [`Interactive`](Self::Interactive) requests are always let through
+/// before [`Background`](Self::Background) ones. Without
+/// [`max_concurrent_requests`](super::ClientConfig::max_concurrent_requests)
+/// there is no limit and priorities have no effect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// A request someone is waiting on, such as a chat reply.
+    #[default]
+    Interactive,
+    /// A request that can wait, such as batch enrichment.
+    Background,
+}
+
+/// Hands out a fixed number of slots, serving interactive waiters first.
+#[derive(Debug)]
+pub(crate) struct Scheduler {
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    available: usize,
+    interactive: VecDeque<oneshot::Sender<RequestPermit>>,
+    background: VecDeque<oneshot::Sender<RequestPermit>>,
+}
+
+/// A slot under the concurrency limit, returned to the scheduler on drop.
+#[derive(Debug)]
+pub(crate) struct RequestPermit {
+    scheduler: Option<Arc<Scheduler>>,
+}
+
+impl Scheduler {
+    pub(crate) fn new(permits: usize) -> Self {
+        Self {
+            state: Mutex::new(State {
+                available: permits,
+                interactive: VecDeque::new(),
+                background: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// Wait for a slot.
+    pub(crate) async fn acquire(self: &Arc<Self>, priority: Priority) -> RequestPermit {
+        let receiver = {
+            let mut state = self.state.lock().unwrap();
+            // Released slots go straight to waiters, so a free slot means
+            // nobody is queued.
+            if state.available > 0 {
+                state.available -= 1;
+                return RequestPermit {
+                    scheduler: Some(self.clone()),
+                };
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            match priority {
+                Priority::Interactive => state.interactive.push_back(sender),
+                Priority::Background => state.background.push_back(sender),
+            }
+            receiver
+        };
+
+        // If this future is dropped after a permit was sent, the permit is
+        // dropped with the channel and the slot released again.
+        receiver
+            .await
+            .expect("scheduler dropped while a request was waiting")
+    }
+
+    /// Pass a returned slot to the next waiter, or mark it free.
+    fn release(self: &Arc<Self>) {
+        loop {
+            let waiter = {
+                let mut state = self.state.lock().unwrap();
+                match state
+                    .interactive
+                    .pop_front()
+                    .or_else(|| state.background.pop_front())
+                {
+                    Some(waiter) => waiter,
+                    None => {
+                        state.available += 1;
+                        return;
+                    }
+                }
+            };
+
+            let permit = RequestPermit {
+                scheduler: Some(self.clone()),
+            };
+            match waiter.send(permit) {
+                Ok(()) => return,
+                // The waiter gave up; try the next one without releasing again.
+                Err(mut permit) => {
+                    permit.scheduler = None;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        if let Some(scheduler) = self.scheduler.take() {
+            scheduler.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_interactive_first() {
+        let scheduler = Arc::new(Scheduler::new(1));
+        let held = scheduler.acquire(Priority::Background).await;
+
+        let background = tokio::spawn({
+            let scheduler = scheduler.clone();
+            async move { scheduler.acquire(Priority::Background).await }
+        });
+        tokio::task::yield_now().await;
+        let interactive = tokio::spawn({
+            let scheduler = scheduler.clone();
+            async move { scheduler.acquire(Priority::Interactive).await }
+        });
+        tokio::task::yield_now().await;
+
+        // A waiter that gives up does not lose the slot.
+        let mut abandoned = Box::pin(scheduler.acquire(Priority::Interactive));
+        assert!((&mut abandoned).now_or_never().is_none());
+        drop(abandoned);
+
+        drop(held);
+        let permit = interactive.await.unwrap();
+        assert!(!background.is_finished());
+
+        drop(permit);
+        drop(background.await.unwrap());
+        assert_eq!(scheduler.state.lock().unwrap().available, 1);
+    }
+}
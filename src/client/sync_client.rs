@@ -1,26 +1,44 @@
 //! Blocking (synchronous) client for the Anthropic API.
 
-use tokio::runtime::Runtime;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
 
 use crate::error::Result;
-use crate::resources::{BlockingCompletions, BlockingMessages, BlockingModels};
+use crate::resources::{
+    BlockingAdmin, BlockingBeta, BlockingCompletions, BlockingFiles, BlockingMessages,
+    BlockingModels,
+};
 
 use super::{AsyncAnthropic, ClientConfig};
 
 /// Blocking (synchronous) client for the Anthropic API.
 ///
 /// This is a wrapper around [`AsyncAnthropic`] that blocks on async operations.
+/// All blocking clients share one tokio runtime, started with the first.
+///
+/// Cloning is cheap: clones share the connection pool and the runtime, so one
+/// client can be stored in application state and used from many threads.
+///
+/// Calls may also be made from inside a multi-threaded tokio runtime, such as
+/// from a `spawn_blocking` task or a synchronous library called from async
+/// code. A current-thread runtime cannot be blocked, so calling from one
+/// panics.
+#[derive(Clone)]
 pub struct Anthropic {
     inner: AsyncAnthropic,
-    runtime: Runtime,
+    runtime: Arc<Runtime>,
 }
 
 impl Anthropic {
     /// Create a new blocking client with default configuration.
     ///
-    /// This will use the `ANTHROPIC_API_KEY` environment variable for authentication.
+    /// This will use the `ANTHROPIC_API_KEY` environment variable for
+    /// authentication; see [`ClientConfig::from_env`] for the other variables
+    /// read.
     pub fn new() -> Result<Self> {
-        Self::with_config(ClientConfig::default())
+        Self::with_config(ClientConfig::from_env()?)
     }
 
     /// Create a new blocking client with an API key.
@@ -32,11 +50,10 @@ impl Anthropic {
     pub fn with_config(config: ClientConfig) -> Result<Self> {
         let inner = AsyncAnthropic::with_config(config)?;
 
-        let runtime = Runtime::new().map_err(|e| crate::AnthropicError::Config {
-            message: format!("Failed to create tokio runtime: {}", e),
-        })?;
-
-        Ok(Self { inner, runtime })
+        Ok(Self {
+            inner,
+            runtime: global_runtime()?,
+        })
     }
 
     /// Get a reference to the client configuration.
@@ -44,6 +61,13 @@ impl Anthropic {
         self.inner.config()
     }
 
+    /// Open a connection to the API ahead of the first request.
+    ///
+    /// See [`AsyncAnthropic::preconnect`].
+    pub fn preconnect(&self) -> Result<()> {
+        self.block_on(self.inner.preconnect())
+    }
+
     /// Get a reference to the inner async client.
     pub fn inner(&self) -> &AsyncAnthropic {
         &self.inner
@@ -54,6 +78,12 @@ impl Anthropic {
         &self.runtime
     }
 
+    /// Get a shared handle to the tokio runtime, for blocking streams that
+    /// outlive the borrow of the client.
+    pub(crate) fn shared_runtime(&self) -> Arc<Runtime> {
+        self.runtime.clone()
+    }
+
     /// Access the Messages API.
     pub fn messages(&self) -> BlockingMessages<'_> {
         BlockingMessages::new(self)
@@ -69,11 +99,88 @@ impl Anthropic {
         BlockingModels::new(self)
     }
 
+    /// Access the Files API.
+    pub fn files(&self) -> BlockingFiles<'_> {
+        BlockingFiles::new(self)
+    }
+
+    /// Access the Admin API.
+    ///
+    /// Requires an admin API key to be configured.
+    pub fn admin(&self) -> BlockingAdmin<'_> {
+        BlockingAdmin::new(self)
+    }
+
+    /// Access beta features.
+    ///
+    /// Requests made through the returned namespace send the given flags in
+    /// the `anthropic-beta` header.
+    pub fn beta<I, S>(&self, betas: I) -> BlockingBeta<'_>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        BlockingBeta::new(self, betas.into_iter().map(Into::into).collect())
+    }
+
     /// Block on an async operation.
     pub(crate) fn block_on<F, T>(&self, future: F) -> T
     where
-        F: std::future::Future<Output = T>,
+        F: Future<Output = T>,
     {
-        self.runtime.block_on(future)
+        block_on(&self.runtime, future)
+    }
+}
+
+/// The runtime shared by all blocking clients, started on first use.
+///
+/// The runtime is built while holding the lock, so threads racing to create
+/// the first client never build one that would be dropped, which panics
+/// inside an async context. A failure is returned and the next call tries
+/// again.
+fn global_runtime() -> Result<Arc<Runtime>> {
+    static RUNTIME: Mutex<Option<Arc<Runtime>>> = Mutex::new(None);
+
+    let mut slot = RUNTIME.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(runtime) = &*slot {
+        return Ok(runtime.clone());
+    }
+    let runtime = Runtime::new().map_err(|e| crate::AnthropicError::Config {
+        message: format!("Failed to create tokio runtime: {}", e),
+    })?;
+    Ok(slot.insert(Arc::new(runtime)).clone())
+}
+
+/// Block on a future with `runtime`, even when called from inside a
+/// multi-threaded runtime.
+pub(crate) fn block_on<F: Future>(runtime: &Runtime, future: F) -> F::Output {
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| runtime.block_on(future))
+        }
+        _ => runtime.block_on(future),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_on_inside_runtime() {
+        let runtime = global_runtime().unwrap();
+        assert!(Arc::ptr_eq(&runtime, &global_runtime().unwrap()));
+        assert_eq!(block_on(&runtime, async { 1 + 1 }), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_concurrent_first_use_shares_one_runtime() {
+        let threads: Vec<_> = (0..8)
+            .map(|_| tokio::spawn(async { global_runtime().unwrap() }))
+            .collect();
+        let first = global_runtime().unwrap();
+        for thread in threads {
+            assert!(Arc::ptr_eq(&first, &thread.await.unwrap()));
+        }
     }
 }
@@ -0,0 +1,333 @@
+//! The HTTP layer requests are sent through.
+
+use futures::future::BoxFuture;
+use reqwest::{Request, Response};
+
+use crate::error::{AnthropicError, Result};
+
+/// Sends a built request and returns the response.
+///
+/// The client sends every request through a transport, after its
+/// [`Middleware`](super::Middleware). The default transport is a
+/// [`reqwest::Client`]; set another with
+/// [`ClientConfig::transport`](super::ClientConfig::transport) to use a Unix
+/// socket, an in-process test server or a different HTTP stack. Build the
+/// response with `reqwest::Response::from(http::Response<_>)`.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{ClientConfig, Transport};
+/// use futures::future::BoxFuture;
+/// use reqwest::{Request, Response};
+///
+/// /// Answers every request with the same JSON body.
+/// struct Canned(&'static str);
+///
+/// impl Transport for Canned {
+///     fn execute(&self, _request: Request) -> BoxFuture<'_, anthropic_sdk::Result<Response>> {
+///         let response = http::Response::builder()
+///             .status(200)
+///             .header("content-type", "application/json")
+///             .body(self.0)
+///             .unwrap();
+///         Box::pin(async move { Ok(Response::from(response)) })
+///     }
+/// }
+///
+/// let config = ClientConfig::with_api_key("test").transport(Canned("{}"));
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send a request.
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+impl Transport for reqwest::Client {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move {
+            reqwest::Client::execute(self, request).await.map_err(|e| {
+                if e.is_timeout() {
+                    AnthropicError::Timeout
+                } else {
+                    AnthropicError::Connection(e)
+                }
+            })
+        })
+    }
+}
+
+impl std::fmt::Debug for dyn Transport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Transport")
+    }
+}
+
+/// Replays responses in order, recording the requests.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct Replay {
+    pub(crate) responses: std::sync::Mutex<Vec<(u16, &'static str)>>,
+    pub(crate) requests: std::sync::Mutex<Vec<Request>>,
+}
+
+#[cfg(test)]
+impl Replay {
+    /// Create a transport answering with `responses`, as status and body.
+    pub(crate) fn new(responses: Vec<(u16, &'static str)>) -> std::sync::Arc<Self> {
+        std::sync::Arc::new(Self {
+            responses: std::sync::Mutex::new(responses),
+            requests: Default::default(),
+        })
+    }
+
+    /// The JSON body of each request sent.
+    pub(crate) fn bodies(&self) -> Vec<serde_json::Value> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| {
+                let body = request.body().and_then(|body| body.as_bytes());
+                serde_json::from_slice(body.unwrap_or_default()).unwrap()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+impl Transport for std::sync::Arc<Replay> {
+    fn execute(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        let (status, body) = self.responses.lock().unwrap().remove(0);
+        self.requests.lock().unwrap().push(request);
+        let response = http::Response::builder()
+            .status(status)
+            .header("retry-after-ms", "0")
+            .header("request-id", "req_1")
+            .body(body)
+            .unwrap();
+        Box::pin(async move { Ok(Response::from(response)) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::{AsyncAnthropic, ClientConfig, MessageCreateParams, MessageParam, RequestOptions};
+
+    const MESSAGE: &str = r#"{"id":"msg_1","type":"message","role":"assistant","model":"claude-sonnet-4-5",
+        "content":[{"type":"text","text":"Hi"}],"stop_reason":"end_turn",
+        "usage":{"input_tokens":1,"output_tokens":1}}"#;
+
+    fn params() -> MessageCreateParams {
+        MessageCreateParams::builder()
+            .model("claude-sonnet-4-5")
+            .max_tokens(16)
+            .messages(vec![MessageParam::user("Hello")])
+            .build()
+    }
+
+    #[tokio::test]
+    async fn test_retry_through_transport() {
+        let replay = Replay::new(vec![
+            (
+                529,
+                r#"{"type":"error","error":{"type":"overloaded_error","message":"busy"}}"#,
+            ),
+            (200, MESSAGE),
+        ]);
+
+        let retries = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .max_retries(1)
+                .transport(replay.clone())
+                .on_retry({
+                    let retries = retries.clone();
+                    move |event: &crate::RetryEvent<'_>| {
+                        if let crate::RetryCause::Status(status) = event.cause {
+                            retries
+                                .lock()
+                                .unwrap()
+                                .push((event.attempt, status.as_u16()));
+                        }
+                    }
+                }),
+        )
+        .unwrap();
+        let message = client
+            .messages()
+            .create_with_response(params())
+            .await
+            .unwrap();
+        assert_eq!(message.text(), "Hi");
+        assert_eq!(message.request_id(), Some("req_1"));
+        assert_eq!(*retries.lock().unwrap(), [(1, 529)]);
+
+        let requests = replay.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url().path(), "/v1/messages");
+        assert_eq!(requests[0].headers()["x-api-key"], "sk-test");
+        let key = |request: &Request| request.headers()["idempotency-key"].clone();
+        assert_eq!(key(&requests[0]), key(&requests[1]));
+    }
+
+    #[tokio::test]
+    async fn test_request_header_overrides() {
+        let replay = Replay::new(vec![(200, MESSAGE)]);
+
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .default_header("anthropic-beta", "some-beta")
+                .default_header("anthropic-version", "2099-01-01")
+                .transport(replay.clone()),
+        )
+        .unwrap();
+        client
+            .messages()
+            .create_with_options(
+                params(),
+                RequestOptions::new()
+                    .header("x-trace-id", "abc123")
+                    .unwrap()
+                    .remove_header("anthropic-beta")
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let headers = replay.requests.lock().unwrap()[0].headers().clone();
+        assert_eq!(headers["anthropic-version"], "2099-01-01");
+        assert_eq!(headers["x-trace-id"], "abc123");
+        assert!(!headers.contains_key("anthropic-beta"));
+    }
+
+    #[tokio::test]
+    async fn test_beta_header_merges_with_default() {
+        let replay = Replay::new(vec![(200, MESSAGE), (200, MESSAGE)]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .default_header("anthropic-beta", "some-beta, context-1m-2025-08-07")
+                .transport(replay.clone()),
+        )
+        .unwrap();
+        let mut params = params();
+        params.betas = Some(vec![
+            "context-1m-2025-08-07".into(),
+            "output-128k-2025-02-19".into(),
+        ]);
+        client.messages().create(params).await.unwrap();
+        client.messages().create(self::params()).await.unwrap();
+
+        let requests = replay.requests.lock().unwrap();
+        assert_eq!(
+            requests[0].headers()["anthropic-beta"],
+            "some-beta,context-1m-2025-08-07,output-128k-2025-02-19"
+        );
+        assert_eq!(
+            requests[1].headers()["anthropic-beta"],
+            "some-beta, context-1m-2025-08-07"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_workspace_header() {
+        let replay = Replay::new(vec![(200, MESSAGE)]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .workspace_id("wrkspc_1")
+                .transport(replay.clone()),
+        )
+        .unwrap();
+        client.messages().create(params()).await.unwrap();
+        let headers = replay.requests.lock().unwrap()[0].headers().clone();
+        assert_eq!(headers["anthropic-workspace-id"], "wrkspc_1");
+
+        let error =
+            AsyncAnthropic::with_config(ClientConfig::with_api_key("sk-test").workspace_id("a\nb"))
+                .unwrap_err();
+        assert!(matches!(error, AnthropicError::Config { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_admin_requests_drop_bearer_token() {
+        let replay = Replay::new(vec![(
+            200,
+            r#"{"id":"wrkspc_1","type":"workspace","name":"Default","created_at":"2025-01-01T00:00:00Z"}"#,
+        )]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig {
+                auth_token: Some("token".into()),
+                ..ClientConfig::with_admin_api_key("sk-admin")
+            }
+            .transport(replay.clone()),
+        )
+        .unwrap();
+        client
+            .admin()
+            .workspaces()
+            .retrieve("wrkspc_1")
+            .await
+            .unwrap();
+        let headers = replay.requests.lock().unwrap()[0].headers().clone();
+        assert_eq!(headers["x-api-key"], "sk-admin");
+        assert!(!headers.contains_key(reqwest::header::AUTHORIZATION));
+    }
+
+    /// Never answers.
+    struct Hang;
+
+    impl Transport for Hang {
+        fn execute(&self, _request: Request) -> BoxFuture<'_, Result<Response>> {
+            Box::pin(futures::future::pending())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deadline() {
+        let client =
+            AsyncAnthropic::with_config(ClientConfig::with_api_key("sk-test").transport(Hang))
+                .unwrap();
+        let error = client
+            .messages()
+            .create_with_options(
+                params(),
+                RequestOptions::new().deadline_in(std::time::Duration::from_millis(20)),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AnthropicError::DeadlineExceeded { attempts: 1, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_completion_stream_holds_permit() {
+        let replay = Replay::new(vec![(200, ""), (200, MESSAGE)]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .max_concurrent_requests(1)
+                .transport(replay.clone()),
+        )
+        .unwrap();
+        let stream = client
+            .completions()
+            .create_stream(crate::CompletionCreateParams::new(
+                "claude-2.1",
+                "\n\nHuman: Hi\n\nAssistant:",
+                16,
+            ))
+            .await
+            .unwrap();
+
+        let wait = std::time::Duration::from_millis(20);
+        let blocked = tokio::time::timeout(wait, client.messages().create(params())).await;
+        assert!(blocked.is_err());
+
+        drop(stream);
+        client.messages().create(params()).await.unwrap();
+        assert_eq!(replay.bodies()[0]["stream"], true);
+    }
+}
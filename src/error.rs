@@ -2,6 +2,8 @@
 
 use std::time::Duration;
 
+use crate::client::RateLimitInfo;
+
 /// The main error type for the Anthropic SDK.
 #[derive(Debug, thiserror::Error)]
 pub enum AnthropicError {
@@ -53,6 +55,7 @@ pub enum AnthropicError {
         message: String,
         request_id: Option<String>,
         retry_after: Option<Duration>,
+        rate_limit: Option<Box<RateLimitInfo>>,
     },
 
     /// Internal server error (HTTP 5xx)
@@ -85,6 +88,11 @@ pub enum AnthropicError {
     #[error("Request timed out")]
     Timeout,
 
+    /// The request's deadline passed before it succeeded, counting every
+    /// attempt and the waits between them
+    #[error("Request timed out at its deadline after {attempts} attempt(s) in {elapsed:.2?}")]
+    DeadlineExceeded { attempts: u32, elapsed: Duration },
+
     /// Invalid response from API
     #[error("Invalid response: {message}")]
     InvalidResponse { message: String },
@@ -93,6 +101,14 @@ pub enum AnthropicError {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// Reading or writing a local file failed
+    #[error("{message}: {source}")]
+    Io {
+        message: String,
+        #[source]
+        source: std::io::Error,
+    },
+
     /// Configuration error
     #[error("Configuration error: {message}")]
     Config { message: String },
@@ -104,6 +120,28 @@ pub enum AnthropicError {
     /// Stream error
     #[error("Stream error: {message}")]
     Stream { message: String },
+
+    /// Tool execution error, raised by the tool runner's `Abort` policy
+    #[error("Tool {name} failed: {message}")]
+    Tool { name: String, message: String },
+
+    /// Tool input that does not match the expected type
+    #[error("Invalid input for tool {name}: {message} (at `{path}`, input: {input})")]
+    ToolInput {
+        name: String,
+        path: String,
+        message: String,
+        input: String,
+    },
+
+    /// Error talking to an MCP server, only returned with the `mcp` feature
+    #[error("MCP error: {message}")]
+    Mcp { message: String },
+
+    /// The tool runner reached its iteration limit while the model still
+    /// requested tool use
+    #[error("Tool runner exceeded {max_iterations} iterations")]
+    MaxIterationsExceeded { max_iterations: u32 },
 }
 
 impl AnthropicError {
@@ -147,6 +185,7 @@ impl AnthropicError {
                 message,
                 request_id,
                 retry_after,
+                rate_limit: None,
             },
             529 => Self::Overloaded {
                 message,
@@ -163,6 +202,29 @@ impl AnthropicError {
         }
     }
 
+    /// Create an error from an API error type, such as `overloaded_error`.
+    ///
+    /// Used for errors that arrive in the body of a successful response, like
+    /// `error` events in a stream. Unknown types become [`Self::Stream`].
+    pub fn from_error_type(error_type: &str, message: String, request_id: Option<String>) -> Self {
+        let status = match error_type {
+            "invalid_request_error" => 400,
+            "authentication_error" => 401,
+            "permission_error" => 403,
+            "not_found_error" => 404,
+            "request_too_large" => 413,
+            "rate_limit_error" => 429,
+            "api_error" => 500,
+            "overloaded_error" => 529,
+            _ => {
+                return Self::Stream {
+                    message: format!("{}: {}", error_type, message),
+                }
+            }
+        };
+        Self::from_status(status, message, request_id, None)
+    }
+
     /// Get the request ID if available.
     pub fn request_id(&self) -> Option<&str> {
         match self {
@@ -199,10 +261,18 @@ impl AnthropicError {
             _ => None,
         }
     }
+
+    /// Get the rate-limit state reported with a [`Self::RateLimited`] error.
+    pub fn rate_limit(&self) -> Option<&RateLimitInfo> {
+        match self {
+            Self::RateLimited { rate_limit, .. } => rate_limit.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 /// API error response structure from Anthropic API.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ErrorResponse {
     #[serde(rename = "type")]
     pub error_type: String,
@@ -210,7 +280,7 @@ pub struct ErrorResponse {
 }
 
 /// Error object within the API response.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ErrorObject {
     #[serde(rename = "type")]
     pub error_type: String,
@@ -79,26 +79,56 @@ pub mod client;
 pub mod error;
 pub mod resources;
 pub mod streaming;
+pub mod tools;
 pub mod types;
 
 // Re-export main types for convenience
-pub use client::{Anthropic, AsyncAnthropic, ClientConfig};
+pub use client::{
+    Anthropic, ApiResponse, AsyncAnthropic, ClientConfig, ClientConfigBuilder, ConfigError,
+    Credentials, CredentialsProvider, LogLevel, Middleware, Next, Priority, RateLimitBudget,
+    RateLimitConfig, RateLimitInfo, RequestOptions, RetryCause, RetryConfig, RetryEvent, RetryHook,
+    Transport,
+};
 pub use error::{AnthropicError, Result};
 pub use types::{
-    ContentBlock, ContentBlockParam, Message, MessageContent, MessageCreateParams,
-    MessageCreateParamsBuilder, MessageParam, Model, ModelList, Role, StopReason, Tool, ToolChoice,
-    ToolInputSchema, ToolResultBlockParam, ToolUseBlock, Usage,
+    AnthropicTool, BatchCreateParams, BatchIndividualResponse, BatchRequest, BatchRequestCounts,
+    BatchResult, ContentBlock, ContentBlockParam, FileMetadata, KnownModel, Message, MessageBatch,
+    MessageContent, MessageCreateParams, MessageCreateParamsBuilder, MessageParam, Model,
+    ModelList, OutputFormat, Role, StopReason, Tool, ToolChoice, ToolInputSchema,
+    ToolResultBlockParam, ToolSchema, ToolUnion, ToolUseBlock, Usage,
 };
 
+// Re-export derive macros
+#[cfg(feature = "macros")]
+pub use anthropic_sdk_macros::AnthropicTool;
+
 // Re-export streaming types
 pub use streaming::{
-    BlockingMessageStream, ContentBlockDelta, MessageDelta, MessageDeltaUsage, MessageStream,
-    MessageStreamEvent, StreamState,
+    BatchResultsStream, BlockingBatchResultsStream, BlockingCompletionStream,
+    BlockingMessageStream, CompletionStream, CompletionStreamEvent, ContentBlockDelta,
+    LocalMessageStream, MessageDelta, MessageDeltaUsage, MessageStream, MessageStreamEvent,
+    PartialJson, StreamMetrics, StreamState,
 };
 
 // Re-export resource types
-pub use resources::{BlockingCompletions, BlockingMessages, BlockingModels};
-pub use resources::{Completions, Messages, Models};
+pub use resources::{
+    Admin, Batches, Beta, Completion, CompletionCreateParams, Completions, Files, Messages, Models,
+    PollConfig,
+};
+pub use resources::{
+    BlockingAdmin, BlockingBatches, BlockingBeta, BlockingCompletions, BlockingFiles,
+    BlockingMessages, BlockingModelIter, BlockingModels,
+};
+#[cfg(feature = "beta")]
+pub use resources::{BlockingPromptTools, PromptTools};
+
+// Lets derive macro output refer to `::anthropic_sdk` from within this crate.
+extern crate self as anthropic_sdk;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
 
 /// Default API version header value
 pub const API_VERSION: &str = "2023-06-01";
@@ -0,0 +1,219 @@
+//! Organization API keys resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{ApiKey, ApiKeyList, ApiKeyUpdateParams, ListApiKeysParams};
+
+/// Organization API keys resource (async).
+pub struct ApiKeys<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> ApiKeys<'a> {
+    /// Create a new ApiKeys resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// List API keys in the organization.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, ClientConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::with_config(ClientConfig::with_admin_api_key("sk-ant-admin..."))?;
+    ///
+    ///     let keys = client.admin().api_keys().list(Default::default()).await?;
+    ///     for key in keys.data {
+    ///         println!("{}: {:?}", key.name, key.status);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list(&self, params: ListApiKeysParams) -> Result<ApiKeyList> {
+        self.list_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// List API keys in the organization, overriding client settings for this
+    /// request.
+    pub async fn list_with_options(
+        &self,
+        params: ListApiKeysParams,
+        options: RequestOptions,
+    ) -> Result<ApiKeyList> {
+        let mut path = "/organizations/api_keys".to_string();
+        let mut query_parts = Vec::new();
+
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(before_id) = params.before_id {
+            query_parts.push(format!("before_id={}", before_id));
+        }
+        if let Some(after_id) = params.after_id {
+            query_parts.push(format!("after_id={}", after_id));
+        }
+        if let Some(status) = params.status {
+            query_parts.push(format!("status={}", status.as_str()));
+        }
+        if let Some(workspace_id) = params.workspace_id {
+            query_parts.push(format!("workspace_id={}", workspace_id));
+        }
+        if let Some(created_by_user_id) = params.created_by_user_id {
+            query_parts.push(format!("created_by_user_id={}", created_by_user_id));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
+        }
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Retrieve an API key.
+    pub async fn retrieve(&self, api_key_id: &str) -> Result<ApiKey> {
+        self.retrieve_with_options(api_key_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve an API key, overriding client settings for this request.
+    pub async fn retrieve_with_options(
+        &self,
+        api_key_id: &str,
+        options: RequestOptions,
+    ) -> Result<ApiKey> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/organizations/api_keys/{}", api_key_id),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Update an API key's name or status.
+    pub async fn update(&self, api_key_id: &str, params: ApiKeyUpdateParams) -> Result<ApiKey> {
+        self.update_with_options(api_key_id, params, RequestOptions::default())
+            .await
+    }
+
+    /// Update an API key's name or status, overriding client settings for this
+    /// request.
+    pub async fn update_with_options(
+        &self,
+        api_key_id: &str,
+        params: ApiKeyUpdateParams,
+        options: RequestOptions,
+    ) -> Result<ApiKey> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                &format!("/organizations/api_keys/{}", api_key_id),
+                Some(&params),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+}
+
+/// Organization API keys resource (blocking).
+pub struct BlockingApiKeys<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingApiKeys<'a> {
+    /// Create a new blocking ApiKeys resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// List API keys in the organization.
+    pub fn list(&self, params: ListApiKeysParams) -> Result<ApiKeyList> {
+        self.client
+            .block_on(self.client.inner().admin().api_keys().list(params))
+    }
+
+    /// List API keys in the organization, overriding client settings for this
+    /// request.
+    pub fn list_with_options(
+        &self,
+        params: ListApiKeysParams,
+        options: RequestOptions,
+    ) -> Result<ApiKeyList> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .api_keys()
+                .list_with_options(params, options),
+        )
+    }
+
+    /// Retrieve an API key.
+    pub fn retrieve(&self, api_key_id: &str) -> Result<ApiKey> {
+        self.client
+            .block_on(self.client.inner().admin().api_keys().retrieve(api_key_id))
+    }
+
+    /// Retrieve an API key, overriding client settings for this request.
+    pub fn retrieve_with_options(
+        &self,
+        api_key_id: &str,
+        options: RequestOptions,
+    ) -> Result<ApiKey> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .api_keys()
+                .retrieve_with_options(api_key_id, options),
+        )
+    }
+
+    /// Update an API key's name or status.
+    pub fn update(&self, api_key_id: &str, params: ApiKeyUpdateParams) -> Result<ApiKey> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .api_keys()
+                .update(api_key_id, params),
+        )
+    }
+
+    /// Update an API key's name or status, overriding client settings for this
+    /// request.
+    pub fn update_with_options(
+        &self,
+        api_key_id: &str,
+        params: ApiKeyUpdateParams,
+        options: RequestOptions,
+    ) -> Result<ApiKey> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .api_keys()
+                .update_with_options(api_key_id, params, options),
+        )
+    }
+}
@@ -0,0 +1,250 @@
+//! Organization invites resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{DeletedInvite, Invite, InviteCreateParams, InviteList, ListInvitesParams};
+
+/// Organization invites resource (async).
+pub struct Invites<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> Invites<'a> {
+    /// Create a new Invites resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// Invite a user to the organization.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    /// use anthropic_sdk::types::{InviteCreateParams, OrganizationRole};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let invite = client
+    ///         .admin()
+    ///         .invites()
+    ///         .create(InviteCreateParams::new("user@example.com", OrganizationRole::Developer))
+    ///         .await?;
+    ///     println!("{} expires at {}", invite.id, invite.expires_at);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create(&self, params: InviteCreateParams) -> Result<Invite> {
+        self.create_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Invite a user to the organization, overriding client settings for this
+    /// request.
+    pub async fn create_with_options(
+        &self,
+        params: InviteCreateParams,
+        options: RequestOptions,
+    ) -> Result<Invite> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/organizations/invites",
+                Some(&params),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// List invites in the organization.
+    pub async fn list(&self, params: ListInvitesParams) -> Result<InviteList> {
+        self.list_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// List invites in the organization, overriding client settings for this
+    /// request.
+    pub async fn list_with_options(
+        &self,
+        params: ListInvitesParams,
+        options: RequestOptions,
+    ) -> Result<InviteList> {
+        let mut path = "/organizations/invites".to_string();
+        let mut query_parts = Vec::new();
+
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(before_id) = params.before_id {
+            query_parts.push(format!("before_id={}", before_id));
+        }
+        if let Some(after_id) = params.after_id {
+            query_parts.push(format!("after_id={}", after_id));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
+        }
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Retrieve an invite.
+    pub async fn retrieve(&self, invite_id: &str) -> Result<Invite> {
+        self.retrieve_with_options(invite_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve an invite, overriding client settings for this request.
+    pub async fn retrieve_with_options(
+        &self,
+        invite_id: &str,
+        options: RequestOptions,
+    ) -> Result<Invite> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/organizations/invites/{}", invite_id),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Delete a pending invite.
+    pub async fn delete(&self, invite_id: &str) -> Result<DeletedInvite> {
+        self.delete_with_options(invite_id, RequestOptions::default())
+            .await
+    }
+
+    /// Delete a pending invite, overriding client settings for this request.
+    pub async fn delete_with_options(
+        &self,
+        invite_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedInvite> {
+        self.client
+            .request_with_options(
+                Method::DELETE,
+                &format!("/organizations/invites/{}", invite_id),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+}
+
+/// Organization invites resource (blocking).
+pub struct BlockingInvites<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingInvites<'a> {
+    /// Create a new blocking Invites resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// Invite a user to the organization.
+    pub fn create(&self, params: InviteCreateParams) -> Result<Invite> {
+        self.client
+            .block_on(self.client.inner().admin().invites().create(params))
+    }
+
+    /// Invite a user to the organization, overriding client settings for this
+    /// request.
+    pub fn create_with_options(
+        &self,
+        params: InviteCreateParams,
+        options: RequestOptions,
+    ) -> Result<Invite> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .invites()
+                .create_with_options(params, options),
+        )
+    }
+
+    /// List invites in the organization.
+    pub fn list(&self, params: ListInvitesParams) -> Result<InviteList> {
+        self.client
+            .block_on(self.client.inner().admin().invites().list(params))
+    }
+
+    /// List invites in the organization, overriding client settings for this
+    /// request.
+    pub fn list_with_options(
+        &self,
+        params: ListInvitesParams,
+        options: RequestOptions,
+    ) -> Result<InviteList> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .invites()
+                .list_with_options(params, options),
+        )
+    }
+
+    /// Retrieve an invite.
+    pub fn retrieve(&self, invite_id: &str) -> Result<Invite> {
+        self.client
+            .block_on(self.client.inner().admin().invites().retrieve(invite_id))
+    }
+
+    /// Retrieve an invite, overriding client settings for this request.
+    pub fn retrieve_with_options(
+        &self,
+        invite_id: &str,
+        options: RequestOptions,
+    ) -> Result<Invite> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .invites()
+                .retrieve_with_options(invite_id, options),
+        )
+    }
+
+    /// Delete a pending invite.
+    pub fn delete(&self, invite_id: &str) -> Result<DeletedInvite> {
+        self.client
+            .block_on(self.client.inner().admin().invites().delete(invite_id))
+    }
+
+    /// Delete a pending invite, overriding client settings for this request.
+    pub fn delete_with_options(
+        &self,
+        invite_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedInvite> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .invites()
+                .delete_with_options(invite_id, options),
+        )
+    }
+}
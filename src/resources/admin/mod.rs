@@ -0,0 +1,92 @@
+//! Admin API resources.
+//!
+//! These endpoints manage the organization and require an admin API key
+//! (`ANTHROPIC_ADMIN_KEY` or [`ClientConfig::with_admin_api_key`](crate::ClientConfig::with_admin_api_key)).
+
+mod api_keys;
+mod invites;
+mod reports;
+mod workspace_members;
+mod workspaces;
+
+pub use api_keys::{ApiKeys, BlockingApiKeys};
+pub use invites::{BlockingInvites, Invites};
+pub use reports::{BlockingReports, Reports};
+pub use workspace_members::{BlockingWorkspaceMembers, WorkspaceMembers};
+pub use workspaces::{BlockingWorkspaces, Workspaces};
+
+use crate::client::{Anthropic, AsyncAnthropic};
+
+/// Admin API namespace (async).
+pub struct Admin<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> Admin<'a> {
+    /// Create a new Admin namespace.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// Access the organization API keys.
+    pub fn api_keys(&self) -> ApiKeys<'a> {
+        ApiKeys::new(self.client)
+    }
+
+    /// Access the workspaces.
+    pub fn workspaces(&self) -> Workspaces<'a> {
+        Workspaces::new(self.client)
+    }
+
+    /// Access the workspace members.
+    pub fn workspace_members(&self) -> WorkspaceMembers<'a> {
+        WorkspaceMembers::new(self.client)
+    }
+
+    /// Access the organization invites.
+    pub fn invites(&self) -> Invites<'a> {
+        Invites::new(self.client)
+    }
+
+    /// Access the usage and cost reports.
+    pub fn reports(&self) -> Reports<'a> {
+        Reports::new(self.client)
+    }
+}
+
+/// Admin API namespace (blocking).
+pub struct BlockingAdmin<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingAdmin<'a> {
+    /// Create a new blocking Admin namespace.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// Access the organization API keys.
+    pub fn api_keys(&self) -> BlockingApiKeys<'a> {
+        BlockingApiKeys::new(self.client)
+    }
+
+    /// Access the workspaces.
+    pub fn workspaces(&self) -> BlockingWorkspaces<'a> {
+        BlockingWorkspaces::new(self.client)
+    }
+
+    /// Access the workspace members.
+    pub fn workspace_members(&self) -> BlockingWorkspaceMembers<'a> {
+        BlockingWorkspaceMembers::new(self.client)
+    }
+
+    /// Access the organization invites.
+    pub fn invites(&self) -> BlockingInvites<'a> {
+        BlockingInvites::new(self.client)
+    }
+
+    /// Access the usage and cost reports.
+    pub fn reports(&self) -> BlockingReports<'a> {
+        BlockingReports::new(self.client)
+    }
+}
@@ -0,0 +1,219 @@
+//! Usage and cost reports resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{CostReport, CostReportParams, UsageReport, UsageReportParams};
+
+/// Usage and cost reports resource (async).
+pub struct Reports<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> Reports<'a> {
+    /// Create a new Reports resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// Get a time-bucketed report of Messages API token usage.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    /// use anthropic_sdk::types::{BucketWidth, UsageGroupBy, UsageReportParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let report = client
+    ///         .admin()
+    ///         .reports()
+    ///         .usage(
+    ///             UsageReportParams::new("2025-01-01T00:00:00Z")
+    ///                 .bucket_width(BucketWidth::Day)
+    ///                 .group_by(UsageGroupBy::Model),
+    ///         )
+    ///         .await?;
+    ///
+    ///     for bucket in report.data {
+    ///         for result in bucket.results {
+    ///             println!("{} {:?}: {}", bucket.starting_at, result.model, result.output_tokens);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn usage(&self, params: UsageReportParams) -> Result<UsageReport> {
+        self.usage_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Get a time-bucketed report of Messages API token usage, overriding
+    /// client settings for this request.
+    pub async fn usage_with_options(
+        &self,
+        params: UsageReportParams,
+        options: RequestOptions,
+    ) -> Result<UsageReport> {
+        let mut query_parts = vec![format!("starting_at={}", encode(&params.starting_at))];
+
+        if let Some(ending_at) = params.ending_at {
+            query_parts.push(format!("ending_at={}", encode(&ending_at)));
+        }
+        if let Some(bucket_width) = params.bucket_width {
+            query_parts.push(format!("bucket_width={}", bucket_width.as_str()));
+        }
+        for group_by in params.group_by {
+            query_parts.push(format!("group_by[]={}", group_by.as_str()));
+        }
+        for model in params.models {
+            query_parts.push(format!("models[]={}", encode(&model)));
+        }
+        for workspace_id in params.workspace_ids {
+            query_parts.push(format!("workspace_ids[]={}", encode(&workspace_id)));
+        }
+        for api_key_id in params.api_key_ids {
+            query_parts.push(format!("api_key_ids[]={}", encode(&api_key_id)));
+        }
+        for service_tier in params.service_tiers {
+            query_parts.push(format!("service_tiers[]={}", service_tier.as_str()));
+        }
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(page) = params.page {
+            query_parts.push(format!("page={}", encode(&page)));
+        }
+
+        let path = format!(
+            "/organizations/usage_report/messages?{}",
+            query_parts.join("&")
+        );
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Get a time-bucketed report of costs.
+    pub async fn cost(&self, params: CostReportParams) -> Result<CostReport> {
+        self.cost_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Get a time-bucketed report of costs, overriding client settings for this
+    /// request.
+    pub async fn cost_with_options(
+        &self,
+        params: CostReportParams,
+        options: RequestOptions,
+    ) -> Result<CostReport> {
+        let mut query_parts = vec![format!("starting_at={}", encode(&params.starting_at))];
+
+        if let Some(ending_at) = params.ending_at {
+            query_parts.push(format!("ending_at={}", encode(&ending_at)));
+        }
+        for group_by in params.group_by {
+            query_parts.push(format!("group_by[]={}", group_by.as_str()));
+        }
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(page) = params.page {
+            query_parts.push(format!("page={}", encode(&page)));
+        }
+
+        let path = format!("/organizations/cost_report?{}", query_parts.join("&"));
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+}
+
+/// Percent-encode a query parameter value.
+fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Usage and cost reports resource (blocking).
+pub struct BlockingReports<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingReports<'a> {
+    /// Create a new blocking Reports resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// Get a time-bucketed report of Messages API token usage.
+    pub fn usage(&self, params: UsageReportParams) -> Result<UsageReport> {
+        self.client
+            .block_on(self.client.inner().admin().reports().usage(params))
+    }
+
+    /// Get a time-bucketed report of Messages API token usage, overriding
+    /// client settings for this request.
+    pub fn usage_with_options(
+        &self,
+        params: UsageReportParams,
+        options: RequestOptions,
+    ) -> Result<UsageReport> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .reports()
+                .usage_with_options(params, options),
+        )
+    }
+
+    /// Get a time-bucketed report of costs.
+    pub fn cost(&self, params: CostReportParams) -> Result<CostReport> {
+        self.client
+            .block_on(self.client.inner().admin().reports().cost(params))
+    }
+
+    /// Get a time-bucketed report of costs, overriding client settings for this
+    /// request.
+    pub fn cost_with_options(
+        &self,
+        params: CostReportParams,
+        options: RequestOptions,
+    ) -> Result<CostReport> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .reports()
+                .cost_with_options(params, options),
+        )
+    }
+}
@@ -0,0 +1,384 @@
+//! Workspace members resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{
+    DeletedWorkspaceMember, ListWorkspaceMembersParams, WorkspaceMember, WorkspaceMemberAddParams,
+    WorkspaceMemberList, WorkspaceRole,
+};
+
+/// Workspace members resource (async).
+pub struct WorkspaceMembers<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> WorkspaceMembers<'a> {
+    /// Create a new WorkspaceMembers resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// Add a user to a workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    /// use anthropic_sdk::types::{WorkspaceMemberAddParams, WorkspaceRole};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let member = client
+    ///         .admin()
+    ///         .workspace_members()
+    ///         .add(
+    ///             "wrkspc_123",
+    ///             WorkspaceMemberAddParams::new("user_123", WorkspaceRole::WorkspaceDeveloper),
+    ///         )
+    ///         .await?;
+    ///     println!("{:?}", member.workspace_role);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn add(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceMemberAddParams,
+    ) -> Result<WorkspaceMember> {
+        self.add_with_options(workspace_id, params, RequestOptions::default())
+            .await
+    }
+
+    /// Add a user to a workspace, overriding client settings for this request.
+    pub async fn add_with_options(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceMemberAddParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                &format!("/organizations/workspaces/{}/members", workspace_id),
+                Some(&params),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// List the members of a workspace.
+    pub async fn list(
+        &self,
+        workspace_id: &str,
+        params: ListWorkspaceMembersParams,
+    ) -> Result<WorkspaceMemberList> {
+        self.list_with_options(workspace_id, params, RequestOptions::default())
+            .await
+    }
+
+    /// List the members of a workspace, overriding client settings for this
+    /// request.
+    pub async fn list_with_options(
+        &self,
+        workspace_id: &str,
+        params: ListWorkspaceMembersParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMemberList> {
+        let mut path = format!("/organizations/workspaces/{}/members", workspace_id);
+        let mut query_parts = Vec::new();
+
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(before_id) = params.before_id {
+            query_parts.push(format!("before_id={}", before_id));
+        }
+        if let Some(after_id) = params.after_id {
+            query_parts.push(format!("after_id={}", after_id));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
+        }
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Retrieve a workspace member.
+    pub async fn retrieve(&self, workspace_id: &str, user_id: &str) -> Result<WorkspaceMember> {
+        self.retrieve_with_options(workspace_id, user_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve a workspace member, overriding client settings for this
+    /// request.
+    pub async fn retrieve_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!(
+                    "/organizations/workspaces/{}/members/{}",
+                    workspace_id, user_id
+                ),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Change a member's role within a workspace.
+    pub async fn update_role(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        workspace_role: WorkspaceRole,
+    ) -> Result<WorkspaceMember> {
+        self.update_role_with_options(
+            workspace_id,
+            user_id,
+            workspace_role,
+            RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Change a member's role within a workspace, overriding client settings
+    /// for this request.
+    pub async fn update_role_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        workspace_role: WorkspaceRole,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                &format!(
+                    "/organizations/workspaces/{}/members/{}",
+                    workspace_id, user_id
+                ),
+                Some(&serde_json::json!({ "workspace_role": workspace_role })),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Remove a member from a workspace.
+    pub async fn remove(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+    ) -> Result<DeletedWorkspaceMember> {
+        self.remove_with_options(workspace_id, user_id, RequestOptions::default())
+            .await
+    }
+
+    /// Remove a member from a workspace, overriding client settings for this
+    /// request.
+    pub async fn remove_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedWorkspaceMember> {
+        self.client
+            .request_with_options(
+                Method::DELETE,
+                &format!(
+                    "/organizations/workspaces/{}/members/{}",
+                    workspace_id, user_id
+                ),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+}
+
+/// Workspace members resource (blocking).
+pub struct BlockingWorkspaceMembers<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingWorkspaceMembers<'a> {
+    /// Create a new blocking WorkspaceMembers resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// Add a user to a workspace.
+    pub fn add(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceMemberAddParams,
+    ) -> Result<WorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .add(workspace_id, params),
+        )
+    }
+
+    /// Add a user to a workspace, overriding client settings for this request.
+    pub fn add_with_options(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceMemberAddParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .add_with_options(workspace_id, params, options),
+        )
+    }
+
+    /// List the members of a workspace.
+    pub fn list(
+        &self,
+        workspace_id: &str,
+        params: ListWorkspaceMembersParams,
+    ) -> Result<WorkspaceMemberList> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .list(workspace_id, params),
+        )
+    }
+
+    /// List the members of a workspace, overriding client settings for this
+    /// request.
+    pub fn list_with_options(
+        &self,
+        workspace_id: &str,
+        params: ListWorkspaceMembersParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMemberList> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .list_with_options(workspace_id, params, options),
+        )
+    }
+
+    /// Retrieve a workspace member.
+    pub fn retrieve(&self, workspace_id: &str, user_id: &str) -> Result<WorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .retrieve(workspace_id, user_id),
+        )
+    }
+
+    /// Retrieve a workspace member, overriding client settings for this
+    /// request.
+    pub fn retrieve_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .retrieve_with_options(workspace_id, user_id, options),
+        )
+    }
+
+    /// Change a member's role within a workspace.
+    pub fn update_role(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        workspace_role: WorkspaceRole,
+    ) -> Result<WorkspaceMember> {
+        self.client
+            .block_on(self.client.inner().admin().workspace_members().update_role(
+                workspace_id,
+                user_id,
+                workspace_role,
+            ))
+    }
+
+    /// Change a member's role within a workspace, overriding client settings
+    /// for this request.
+    pub fn update_role_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        workspace_role: WorkspaceRole,
+        options: RequestOptions,
+    ) -> Result<WorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .update_role_with_options(workspace_id, user_id, workspace_role, options),
+        )
+    }
+
+    /// Remove a member from a workspace.
+    pub fn remove(&self, workspace_id: &str, user_id: &str) -> Result<DeletedWorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .remove(workspace_id, user_id),
+        )
+    }
+
+    /// Remove a member from a workspace, overriding client settings for this
+    /// request.
+    pub fn remove_with_options(
+        &self,
+        workspace_id: &str,
+        user_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedWorkspaceMember> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspace_members()
+                .remove_with_options(workspace_id, user_id, options),
+        )
+    }
+}
@@ -0,0 +1,315 @@
+//! Workspaces resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{ListWorkspacesParams, Workspace, WorkspaceList, WorkspaceParams};
+
+/// Workspaces resource (async).
+pub struct Workspaces<'a> {
+    client: &'a AsyncAnthropic,
+}
+
+impl<'a> Workspaces<'a> {
+    /// Create a new Workspaces resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self { client }
+    }
+
+    /// Create a workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    /// use anthropic_sdk::types::WorkspaceParams;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let workspace = client
+    ///         .admin()
+    ///         .workspaces()
+    ///         .create(WorkspaceParams::new("Research"))
+    ///         .await?;
+    ///     println!("{}", workspace.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create(&self, params: WorkspaceParams) -> Result<Workspace> {
+        self.create_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a workspace, overriding client settings for this request.
+    pub async fn create_with_options(
+        &self,
+        params: WorkspaceParams,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/organizations/workspaces",
+                Some(&params),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// List workspaces in the organization.
+    pub async fn list(&self, params: ListWorkspacesParams) -> Result<WorkspaceList> {
+        self.list_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// List workspaces in the organization, overriding client settings for this
+    /// request.
+    pub async fn list_with_options(
+        &self,
+        params: ListWorkspacesParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceList> {
+        let mut path = "/organizations/workspaces".to_string();
+        let mut query_parts = Vec::new();
+
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(before_id) = params.before_id {
+            query_parts.push(format!("before_id={}", before_id));
+        }
+        if let Some(after_id) = params.after_id {
+            query_parts.push(format!("after_id={}", after_id));
+        }
+        if let Some(include_archived) = params.include_archived {
+            query_parts.push(format!("include_archived={}", include_archived));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
+        }
+
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Retrieve a workspace.
+    pub async fn retrieve(&self, workspace_id: &str) -> Result<Workspace> {
+        self.retrieve_with_options(workspace_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve a workspace, overriding client settings for this request.
+    pub async fn retrieve_with_options(
+        &self,
+        workspace_id: &str,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/organizations/workspaces/{}", workspace_id),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Update a workspace.
+    pub async fn update(&self, workspace_id: &str, params: WorkspaceParams) -> Result<Workspace> {
+        self.update_with_options(workspace_id, params, RequestOptions::default())
+            .await
+    }
+
+    /// Update a workspace, overriding client settings for this request.
+    pub async fn update_with_options(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceParams,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                &format!("/organizations/workspaces/{}", workspace_id),
+                Some(&params),
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+
+    /// Archive a workspace.
+    ///
+    /// Archived workspaces can no longer be used, and their API keys are
+    /// deactivated.
+    pub async fn archive(&self, workspace_id: &str) -> Result<Workspace> {
+        self.archive_with_options(workspace_id, RequestOptions::default())
+            .await
+    }
+
+    /// Archive a workspace, overriding client settings for this request.
+    pub async fn archive_with_options(
+        &self,
+        workspace_id: &str,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                &format!("/organizations/workspaces/{}/archive", workspace_id),
+                None::<&()>,
+                self.client.admin_headers()?,
+                &options,
+            )
+            .await
+    }
+}
+
+/// Workspaces resource (blocking).
+pub struct BlockingWorkspaces<'a> {
+    client: &'a Anthropic,
+}
+
+impl<'a> BlockingWorkspaces<'a> {
+    /// Create a new blocking Workspaces resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self { client }
+    }
+
+    /// Create a workspace.
+    pub fn create(&self, params: WorkspaceParams) -> Result<Workspace> {
+        self.client
+            .block_on(self.client.inner().admin().workspaces().create(params))
+    }
+
+    /// Create a workspace, overriding client settings for this request.
+    pub fn create_with_options(
+        &self,
+        params: WorkspaceParams,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .create_with_options(params, options),
+        )
+    }
+
+    /// List workspaces in the organization.
+    pub fn list(&self, params: ListWorkspacesParams) -> Result<WorkspaceList> {
+        self.client
+            .block_on(self.client.inner().admin().workspaces().list(params))
+    }
+
+    /// List workspaces in the organization, overriding client settings for this
+    /// request.
+    pub fn list_with_options(
+        &self,
+        params: ListWorkspacesParams,
+        options: RequestOptions,
+    ) -> Result<WorkspaceList> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .list_with_options(params, options),
+        )
+    }
+
+    /// Retrieve a workspace.
+    pub fn retrieve(&self, workspace_id: &str) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .retrieve(workspace_id),
+        )
+    }
+
+    /// Retrieve a workspace, overriding client settings for this request.
+    pub fn retrieve_with_options(
+        &self,
+        workspace_id: &str,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .retrieve_with_options(workspace_id, options),
+        )
+    }
+
+    /// Update a workspace.
+    pub fn update(&self, workspace_id: &str, params: WorkspaceParams) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .update(workspace_id, params),
+        )
+    }
+
+    /// Update a workspace, overriding client settings for this request.
+    pub fn update_with_options(
+        &self,
+        workspace_id: &str,
+        params: WorkspaceParams,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .update_with_options(workspace_id, params, options),
+        )
+    }
+
+    /// Archive a workspace.
+    pub fn archive(&self, workspace_id: &str) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .archive(workspace_id),
+        )
+    }
+
+    /// Archive a workspace, overriding client settings for this request.
+    pub fn archive_with_options(
+        &self,
+        workspace_id: &str,
+        options: RequestOptions,
+    ) -> Result<Workspace> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .admin()
+                .workspaces()
+                .archive_with_options(workspace_id, options),
+        )
+    }
+}
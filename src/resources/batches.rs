@@ -0,0 +1,434 @@
+//! Message Batches API resource.
+
+use std::time::{Duration, Instant};
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, ApiResponse, AsyncAnthropic, RequestOptions};
+use crate::error::{AnthropicError, Result};
+use crate::streaming::{BatchResultsStream, BlockingBatchResultsStream};
+use crate::types::{BatchCreateParams, MessageBatch};
+
+/// Polling configuration for [`Batches::wait_for_completion`].
+#[derive(Debug, Clone)]
+pub struct PollConfig {
+    /// Delay before the second status check.
+    pub interval: Duration,
+
+    /// Upper bound on the delay between status checks.
+    pub max_interval: Duration,
+
+    /// Factor applied to the delay after each check.
+    pub backoff: f64,
+
+    /// Give up after this much time has elapsed.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            backoff: 1.5,
+            timeout: None,
+        }
+    }
+}
+
+impl PollConfig {
+    /// Set the initial polling interval.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the maximum polling interval.
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Set the backoff multiplier (1.0 polls at a fixed interval).
+    pub fn backoff(mut self, backoff: f64) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Set the overall timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Calculate the delay that follows the given one.
+    fn next_interval(&self, current: Duration) -> Duration {
+        current
+            .mul_f64(self.backoff.max(1.0))
+            .min(self.max_interval)
+    }
+}
+
+/// Message Batches API resource (async).
+pub struct Batches<'a> {
+    client: &'a AsyncAnthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> Batches<'a> {
+    /// Create a new Batches resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a AsyncAnthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// Create a message batch.
+    ///
+    /// Batches are processed asynchronously at a reduced price. Results become
+    /// available once the batch's `processing_status` is `ended`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, BatchCreateParams, MessageCreateParams, MessageParam};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let batch = client.messages().batches().create(
+    ///         BatchCreateParams::new(Vec::new()).request(
+    ///             "request-1",
+    ///             MessageCreateParams::builder()
+    ///                 .model("claude-sonnet-4-5-20250929")
+    ///                 .max_tokens(1024)
+    ///                 .messages(vec![MessageParam::user("Hello, Claude!")])
+    ///                 .build(),
+    ///         ),
+    ///     ).await?;
+    ///
+    ///     println!("{}: {:?}", batch.id, batch.processing_status);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create(&self, params: BatchCreateParams) -> Result<MessageBatch> {
+        self.create_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a message batch, overriding client settings for this request.
+    pub async fn create_with_options(
+        &self,
+        params: BatchCreateParams,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/messages/batches",
+                Some(&params),
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await
+    }
+
+    /// Create a message batch, returning the response status and headers
+    /// with it.
+    pub async fn create_with_response(
+        &self,
+        params: BatchCreateParams,
+    ) -> Result<ApiResponse<MessageBatch>> {
+        self.client
+            .request_with_response(
+                Method::POST,
+                "/messages/batches",
+                Some(&params),
+                self.client.beta_headers(&self.betas),
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// Retrieve a message batch.
+    pub async fn retrieve(&self, batch_id: &str) -> Result<MessageBatch> {
+        self.retrieve_with_options(batch_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve a message batch, overriding client settings for this request.
+    pub async fn retrieve_with_options(
+        &self,
+        batch_id: &str,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/messages/batches/{}", batch_id),
+                None::<&()>,
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await
+    }
+
+    /// Retrieve a message batch, returning the response status and headers
+    /// with it.
+    pub async fn retrieve_with_response(
+        &self,
+        batch_id: &str,
+    ) -> Result<ApiResponse<MessageBatch>> {
+        self.client
+            .request_with_response(
+                Method::GET,
+                &format!("/messages/batches/{}", batch_id),
+                None::<&()>,
+                self.client.beta_headers(&self.betas),
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// Stream the results of a message batch.
+    ///
+    /// Results are decoded lazily from the `.jsonl` results file, one per line.
+    /// Results are not guaranteed to be in the same order as the requests; use
+    /// `custom_id` to match them up.
+    pub async fn results(&self, batch_id: &str) -> Result<BatchResultsStream> {
+        self.results_with_options(batch_id, RequestOptions::default())
+            .await
+    }
+
+    /// Stream the results of a message batch, overriding client settings for
+    /// this request.
+    pub async fn results_with_options(
+        &self,
+        batch_id: &str,
+        options: RequestOptions,
+    ) -> Result<BatchResultsStream> {
+        let response = self
+            .client
+            .send(
+                Method::GET,
+                &format!("/messages/batches/{}/results", batch_id),
+                None::<&()>,
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await?;
+
+        Ok(BatchResultsStream::new(response))
+    }
+
+    /// Poll a message batch until it has finished processing.
+    ///
+    /// Resolves with the ended batch, or fails with [`AnthropicError::Timeout`]
+    /// if `config.timeout` elapses first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, PollConfig};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let batch = client
+    ///         .messages()
+    ///         .batches()
+    ///         .wait_for_completion("msgbatch_123", PollConfig::default())
+    ///         .await?;
+    ///
+    ///     println!("{:?}", batch.request_counts);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_for_completion(
+        &self,
+        batch_id: &str,
+        config: PollConfig,
+    ) -> Result<MessageBatch> {
+        self.wait_for_completion_with_options(batch_id, config, RequestOptions::default())
+            .await
+    }
+
+    /// Poll a message batch until it has finished processing, overriding
+    /// client settings for each status check.
+    pub async fn wait_for_completion_with_options(
+        &self,
+        batch_id: &str,
+        config: PollConfig,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        let started = Instant::now();
+        let mut interval = config.interval;
+
+        loop {
+            let batch = self
+                .retrieve_with_options(batch_id, options.clone())
+                .await?;
+            if batch.is_ended() {
+                return Ok(batch);
+            }
+
+            let mut delay = interval;
+            if let Some(timeout) = config.timeout {
+                let remaining = timeout.saturating_sub(started.elapsed());
+                if remaining.is_zero() {
+                    return Err(AnthropicError::Timeout);
+                }
+                delay = delay.min(remaining);
+            }
+
+            tokio::time::sleep(delay).await;
+            interval = config.next_interval(interval);
+        }
+    }
+}
+
+/// Message Batches API resource (blocking).
+pub struct BlockingBatches<'a> {
+    client: &'a Anthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> BlockingBatches<'a> {
+    /// Create a new blocking Batches resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The async resource this wraps.
+    fn inner(&self) -> Batches<'a> {
+        Batches::with_betas(self.client.inner(), self.betas.clone())
+    }
+
+    /// Create a message batch.
+    pub fn create(&self, params: BatchCreateParams) -> Result<MessageBatch> {
+        self.client.block_on(self.inner().create(params))
+    }
+
+    /// Create a message batch, overriding client settings for this request.
+    pub fn create_with_options(
+        &self,
+        params: BatchCreateParams,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        self.client
+            .block_on(self.inner().create_with_options(params, options))
+    }
+
+    /// Create a message batch, returning the response status and headers
+    /// with it.
+    pub fn create_with_response(
+        &self,
+        params: BatchCreateParams,
+    ) -> Result<ApiResponse<MessageBatch>> {
+        self.client
+            .block_on(self.inner().create_with_response(params))
+    }
+
+    /// Retrieve a message batch.
+    pub fn retrieve(&self, batch_id: &str) -> Result<MessageBatch> {
+        self.client.block_on(self.inner().retrieve(batch_id))
+    }
+
+    /// Retrieve a message batch, overriding client settings for this request.
+    pub fn retrieve_with_options(
+        &self,
+        batch_id: &str,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        self.client
+            .block_on(self.inner().retrieve_with_options(batch_id, options))
+    }
+
+    /// Retrieve a message batch, returning the response status and headers
+    /// with it.
+    pub fn retrieve_with_response(&self, batch_id: &str) -> Result<ApiResponse<MessageBatch>> {
+        self.client
+            .block_on(self.inner().retrieve_with_response(batch_id))
+    }
+
+    /// Stream the results of a message batch.
+    ///
+    /// Returns a blocking iterator over the individual results.
+    pub fn results(&self, batch_id: &str) -> Result<BlockingBatchResultsStream> {
+        self.results_with_options(batch_id, RequestOptions::default())
+    }
+
+    /// Stream the results of a message batch, overriding client settings for
+    /// this request.
+    pub fn results_with_options(
+        &self,
+        batch_id: &str,
+        options: RequestOptions,
+    ) -> Result<BlockingBatchResultsStream> {
+        let stream = self
+            .client
+            .block_on(self.inner().results_with_options(batch_id, options))?;
+
+        Ok(BlockingBatchResultsStream::new(
+            stream,
+            self.client.shared_runtime(),
+        ))
+    }
+
+    /// Poll a message batch until it has finished processing.
+    pub fn wait_for_completion(&self, batch_id: &str, config: PollConfig) -> Result<MessageBatch> {
+        self.client
+            .block_on(self.inner().wait_for_completion(batch_id, config))
+    }
+    /// Poll a message batch until it has finished processing, overriding
+    /// client settings for each status check.
+    pub fn wait_for_completion_with_options(
+        &self,
+        batch_id: &str,
+        config: PollConfig,
+        options: RequestOptions,
+    ) -> Result<MessageBatch> {
+        self.client.block_on(
+            self.inner()
+                .wait_for_completion_with_options(batch_id, config, options),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::Replay;
+    use crate::{AsyncAnthropic, ClientConfig, PollConfig, RequestOptions};
+
+    const IN_PROGRESS: &str = r#"{"id":"msgbatch_1","type":"message_batch","processing_status":"in_progress","request_counts":{"processing":1,"succeeded":0,"errored":0,"canceled":0,"expired":0},"created_at":"2025-01-01T00:00:00Z","expires_at":"2025-01-02T00:00:00Z"}"#;
+    const ENDED: &str = r#"{"id":"msgbatch_1","type":"message_batch","processing_status":"ended","request_counts":{"processing":0,"succeeded":1,"errored":0,"canceled":0,"expired":0},"created_at":"2025-01-01T00:00:00Z","expires_at":"2025-01-02T00:00:00Z"}"#;
+
+    #[tokio::test]
+    async fn test_wait_for_completion_applies_options_to_each_check() {
+        let replay = Replay::new(vec![(200, IN_PROGRESS), (200, ENDED)]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test").transport(replay.clone()),
+        )
+        .unwrap();
+        let batch = client
+            .messages()
+            .batches()
+            .wait_for_completion_with_options(
+                "msgbatch_1",
+                PollConfig::default().interval(Duration::from_millis(1)),
+                RequestOptions::new().header("x-trace", "abc").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(batch.is_ended());
+
+        let requests = replay.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        for request in requests.iter() {
+            assert_eq!(request.url().path(), "/v1/messages/batches/msgbatch_1");
+            assert_eq!(request.headers()["x-trace"], "abc");
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Beta API namespace.
+
+use super::{BlockingFiles, BlockingMessages, BlockingModels, Files, Messages, Models};
+#[cfg(feature = "beta")]
+use super::{BlockingPromptTools, PromptTools};
+use crate::client::{Anthropic, AsyncAnthropic};
+
+/// Beta API namespace (async).
+///
+/// Every request made through this namespace carries the configured flags in
+/// the `anthropic-beta` header.
+pub struct Beta<'a> {
+    client: &'a AsyncAnthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> Beta<'a> {
+    /// Create a new Beta namespace.
+    pub(crate) fn new(client: &'a AsyncAnthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The beta flags sent with each request.
+    pub fn betas(&self) -> &[String] {
+        &self.betas
+    }
+
+    /// Access the Messages API.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::types::betas;
+    /// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let message = client.beta([betas::INTERLEAVED_THINKING]).messages().create(
+    ///         MessageCreateParams::builder()
+    ///             .model("claude-sonnet-4-5-20250929")
+    ///             .max_tokens(1024)
+    ///             .messages(vec![MessageParam::user("Hello, Claude!")])
+    ///             .build()
+    ///     ).await?;
+    ///
+    ///     println!("{}", message.text());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn messages(&self) -> Messages<'a> {
+        Messages::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the Files API.
+    pub fn files(&self) -> Files<'a> {
+        Files::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the Models API.
+    pub fn models(&self) -> Models<'a> {
+        Models::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the experimental prompt tools.
+    #[cfg(feature = "beta")]
+    pub fn prompt_tools(&self) -> PromptTools<'a> {
+        PromptTools::with_betas(self.client, self.betas.clone())
+    }
+}
+
+/// Beta API namespace (blocking).
+pub struct BlockingBeta<'a> {
+    client: &'a Anthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> BlockingBeta<'a> {
+    /// Create a new blocking Beta namespace.
+    pub(crate) fn new(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The beta flags sent with each request.
+    pub fn betas(&self) -> &[String] {
+        &self.betas
+    }
+
+    /// Access the Messages API.
+    pub fn messages(&self) -> BlockingMessages<'a> {
+        BlockingMessages::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the Files API.
+    pub fn files(&self) -> BlockingFiles<'a> {
+        BlockingFiles::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the Models API.
+    pub fn models(&self) -> BlockingModels<'a> {
+        BlockingModels::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Access the experimental prompt tools.
+    #[cfg(feature = "beta")]
+    pub fn prompt_tools(&self) -> BlockingPromptTools<'a> {
+        BlockingPromptTools::with_betas(self.client, self.betas.clone())
+    }
+}
@@ -1,9 +1,12 @@
 //! Completions API resource (legacy).
 
+use reqwest::header::HeaderMap;
+use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Anthropic, AsyncAnthropic};
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
 use crate::error::Result;
+use crate::streaming::{BlockingCompletionStream, CompletionStream};
 
 /// Legacy completion response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,7 +104,65 @@ impl<'a> Completions<'a> {
     ///
     /// Note: This is the legacy completions API. For new projects, use the Messages API instead.
     pub async fn create(&self, params: CompletionCreateParams) -> Result<Completion> {
-        self.client.post("/complete", &params).await
+        self.create_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a completion, overriding client settings for this request.
+    pub async fn create_with_options(
+        &self,
+        params: CompletionCreateParams,
+        options: RequestOptions,
+    ) -> Result<Completion> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/complete",
+                Some(&params),
+                HeaderMap::new(),
+                &options,
+            )
+            .await
+    }
+
+    /// Create a streaming completion (legacy API).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, CompletionCreateParams, CompletionStreamEvent};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let mut stream = client.completions().create_stream(
+    ///         CompletionCreateParams::new("claude-2.1", "\n\nHuman: Hello\n\nAssistant:", 256)
+    ///     ).await?;
+    ///
+    ///     while let Some(event) = stream.next().await {
+    ///         if let CompletionStreamEvent::Completion(chunk) = event? {
+    ///             print!("{}", chunk.completion);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_stream(&self, params: CompletionCreateParams) -> Result<CompletionStream> {
+        self.create_stream_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a streaming completion, overriding client settings for this
+    /// request.
+    pub async fn create_stream_with_options(
+        &self,
+        params: CompletionCreateParams,
+        options: RequestOptions,
+    ) -> Result<CompletionStream> {
+        self.client.post_completion_stream(&params, &options).await
     }
 }
 
@@ -123,4 +184,46 @@ impl<'a> BlockingCompletions<'a> {
         self.client
             .block_on(self.client.inner().completions().create(params))
     }
+
+    /// Create a completion, overriding client settings for this request.
+    pub fn create_with_options(
+        &self,
+        params: CompletionCreateParams,
+        options: RequestOptions,
+    ) -> Result<Completion> {
+        self.client.block_on(
+            self.client
+                .inner()
+                .completions()
+                .create_with_options(params, options),
+        )
+    }
+
+    /// Create a streaming completion (legacy API).
+    pub fn create_stream(
+        &self,
+        params: CompletionCreateParams,
+    ) -> Result<BlockingCompletionStream> {
+        self.create_stream_with_options(params, RequestOptions::default())
+    }
+
+    /// Create a streaming completion, overriding client settings for this
+    /// request.
+    pub fn create_stream_with_options(
+        &self,
+        params: CompletionCreateParams,
+        options: RequestOptions,
+    ) -> Result<BlockingCompletionStream> {
+        let stream = self.client.block_on(
+            self.client
+                .inner()
+                .completions()
+                .create_stream_with_options(params, options),
+        )?;
+
+        Ok(BlockingCompletionStream::new(
+            stream,
+            self.client.shared_runtime(),
+        ))
+    }
 }
@@ -0,0 +1,667 @@
+//! Files API resource.
+
+use std::path::Path;
+
+use bytes::Bytes;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use reqwest::header::HeaderMap;
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, Method};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::{AnthropicError, Result};
+use crate::types::{betas, DeletedFile, FileList, FileMetadata, ListFilesParams};
+
+/// Files API resource (async).
+pub struct Files<'a> {
+    client: &'a AsyncAnthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> Files<'a> {
+    /// Create a new Files resource.
+    pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new Files resource that sends the given beta flags.
+    ///
+    /// The Files API beta flag is always sent in addition to these.
+    pub(crate) fn with_betas(client: &'a AsyncAnthropic, mut betas: Vec<String>) -> Self {
+        if !betas.iter().any(|beta| beta == betas::FILES_API) {
+            betas.push(betas::FILES_API.to_string());
+        }
+        Self { client, betas }
+    }
+
+    /// Headers sent with every Files API request.
+    fn headers(&self) -> HeaderMap {
+        self.client.beta_headers(&self.betas)
+    }
+
+    /// Upload a file from an async reader.
+    ///
+    /// The reader is streamed into the multipart request body, so the file is
+    /// never fully loaded into memory. Because the body cannot be replayed,
+    /// uploads are not retried.
+    pub async fn upload<R>(
+        &self,
+        reader: R,
+        filename: impl Into<String>,
+        mime_type: &str,
+    ) -> Result<FileMetadata>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.upload_with_options(reader, filename, mime_type, RequestOptions::default())
+            .await
+    }
+
+    /// Upload a file from an async reader, overriding client settings for
+    /// this request.
+    ///
+    /// The options' timeout covers the whole upload.
+    pub async fn upload_with_options<R>(
+        &self,
+        reader: R,
+        filename: impl Into<String>,
+        mime_type: &str,
+        options: RequestOptions,
+    ) -> Result<FileMetadata>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        let body = Body::wrap_stream(ReaderStream::new(reader));
+        self.upload_part(Part::stream(body), filename.into(), mime_type, &options)
+            .await
+    }
+
+    /// Upload a file from disk.
+    ///
+    /// The MIME type is inferred from the file extension.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let file = client.files().upload_path("report.pdf").await?;
+    ///     println!("{}: {} bytes", file.id, file.size_bytes);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_path(&self, path: impl AsRef<Path>) -> Result<FileMetadata> {
+        self.upload_path_with_options(path, RequestOptions::default())
+            .await
+    }
+
+    /// Upload a file from disk, overriding client settings for this request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, RequestOptions};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let file = client
+    ///         .files()
+    ///         .upload_path_with_options(
+    ///             "video-transcript.pdf",
+    ///             RequestOptions::new().timeout(Duration::from_secs(1800)),
+    ///         )
+    ///         .await?;
+    ///     println!("{}", file.id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn upload_path_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: RequestOptions,
+    ) -> Result<FileMetadata> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| AnthropicError::Config {
+                message: format!("Invalid file path: {}", path.display()),
+            })?;
+
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| io_error(path, e))?;
+        let length = file.metadata().await.map_err(|e| io_error(path, e))?.len();
+
+        let body = Body::wrap_stream(ReaderStream::new(file));
+        self.upload_part(
+            Part::stream_with_length(body, length),
+            filename,
+            mime_type_for(path),
+            &options,
+        )
+        .await
+    }
+
+    /// Send a single-part multipart upload.
+    async fn upload_part(
+        &self,
+        part: Part,
+        filename: String,
+        mime_type: &str,
+        options: &RequestOptions,
+    ) -> Result<FileMetadata> {
+        let part =
+            part.file_name(filename)
+                .mime_str(mime_type)
+                .map_err(|_| AnthropicError::Config {
+                    message: format!("Invalid MIME type {:?}", mime_type),
+                })?;
+        let form = Form::new().part("file", part);
+
+        self.client
+            .post_multipart("/files", form, self.headers(), options)
+            .await
+    }
+
+    /// List uploaded files.
+    pub async fn list(&self, params: ListFilesParams) -> Result<FileList> {
+        self.list_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// List uploaded files, overriding client settings for this request.
+    pub async fn list_with_options(
+        &self,
+        params: ListFilesParams,
+        options: RequestOptions,
+    ) -> Result<FileList> {
+        let mut path = "/files".to_string();
+        let mut query_parts = Vec::new();
+
+        if let Some(limit) = params.limit {
+            query_parts.push(format!("limit={}", limit));
+        }
+        if let Some(before_id) = params.before_id {
+            query_parts.push(format!("before_id={}", before_id));
+        }
+        if let Some(after_id) = params.after_id {
+            query_parts.push(format!("after_id={}", after_id));
+        }
+
+        if !query_parts.is_empty() {
+            path.push('?');
+            path.push_str(&query_parts.join("&"));
+        }
+
+        self.client
+            .request_with_options(Method::GET, &path, None::<&()>, self.headers(), &options)
+            .await
+    }
+
+    /// Retrieve the metadata of a file.
+    pub async fn retrieve_metadata(&self, file_id: &str) -> Result<FileMetadata> {
+        self.retrieve_metadata_with_options(file_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve the metadata of a file, overriding client settings for this
+    /// request.
+    pub async fn retrieve_metadata_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<FileMetadata> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/files/{}", file_id),
+                None::<&()>,
+                self.headers(),
+                &options,
+            )
+            .await
+    }
+
+    /// Delete a file.
+    pub async fn delete(&self, file_id: &str) -> Result<DeletedFile> {
+        self.delete_with_options(file_id, RequestOptions::default())
+            .await
+    }
+
+    /// Delete a file, overriding client settings for this request.
+    pub async fn delete_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedFile> {
+        self.client
+            .request_with_options(
+                Method::DELETE,
+                &format!("/files/{}", file_id),
+                None::<&()>,
+                self.headers(),
+                &options,
+            )
+            .await
+    }
+
+    /// Download the contents of a file into memory.
+    ///
+    /// For large files prefer [`download_stream`](Self::download_stream) or
+    /// [`download_to`](Self::download_to).
+    pub async fn download(&self, file_id: &str) -> Result<Bytes> {
+        self.download_with_options(file_id, RequestOptions::default())
+            .await
+    }
+
+    /// Download the contents of a file into memory, overriding client
+    /// settings for this request.
+    ///
+    /// The options' timeout covers the whole download.
+    pub async fn download_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<Bytes> {
+        let response = self.download_response(file_id, &options).await?;
+        response.bytes().await.map_err(AnthropicError::Connection)
+    }
+
+    /// Download the contents of a file as a stream of byte chunks.
+    pub async fn download_stream(
+        &self,
+        file_id: &str,
+    ) -> Result<BoxStream<'static, Result<Bytes>>> {
+        self.download_stream_with_options(file_id, RequestOptions::default())
+            .await
+    }
+
+    /// Download the contents of a file as a stream of byte chunks,
+    /// overriding client settings for this request.
+    pub async fn download_stream_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<BoxStream<'static, Result<Bytes>>> {
+        let response = self.download_response(file_id, &options).await?;
+        Ok(response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(AnthropicError::Connection))
+            .boxed())
+    }
+
+    /// Download the contents of a file directly into a writer.
+    ///
+    /// Chunks are written as they arrive, so the file is never fully buffered.
+    /// Returns the number of bytes written.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let mut output = tokio::fs::File::create("output.csv").await.unwrap();
+    ///     let written = client.files().download_to("file_123", &mut output).await?;
+    ///     println!("wrote {} bytes", written);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn download_to<W>(&self, file_id: &str, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.download_to_with_options(file_id, writer, RequestOptions::default())
+            .await
+    }
+
+    /// Download the contents of a file directly into a writer, overriding
+    /// client settings for this request.
+    pub async fn download_to_with_options<W>(
+        &self,
+        file_id: &str,
+        writer: &mut W,
+        options: RequestOptions,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut stream = self.download_stream_with_options(file_id, options).await?;
+        let mut written = 0u64;
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await.map_err(write_error)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(write_error)?;
+
+        Ok(written)
+    }
+
+    /// Request the contents of a file.
+    async fn download_response(
+        &self,
+        file_id: &str,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response> {
+        self.client
+            .send(
+                Method::GET,
+                &format!("/files/{}/content", file_id),
+                None::<&()>,
+                self.headers(),
+                options,
+            )
+            .await
+    }
+}
+
+/// Convert a writer error into an SDK error.
+fn write_error(error: std::io::Error) -> AnthropicError {
+    AnthropicError::Io {
+        message: "Failed to write file contents".into(),
+        source: error,
+    }
+}
+
+/// Convert a filesystem error into an SDK error.
+fn io_error(path: &Path, error: std::io::Error) -> AnthropicError {
+    AnthropicError::Io {
+        message: format!("Failed to read {}", path.display()),
+        source: error,
+    }
+}
+
+/// Infer a MIME type from a file extension.
+fn mime_type_for(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("txt") => "text/plain",
+        Some("md") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("json") => "application/json",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("png") => "image/png",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Files API resource (blocking).
+pub struct BlockingFiles<'a> {
+    client: &'a Anthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> BlockingFiles<'a> {
+    /// Create a new blocking Files resource.
+    pub(crate) fn new(client: &'a Anthropic) -> Self {
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new blocking Files resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The async resource this wraps.
+    fn inner(&self) -> Files<'a> {
+        Files::with_betas(self.client.inner(), self.betas.clone())
+    }
+
+    /// Upload a file from an async reader.
+    pub fn upload<R>(
+        &self,
+        reader: R,
+        filename: impl Into<String>,
+        mime_type: &str,
+    ) -> Result<FileMetadata>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.client
+            .block_on(self.inner().upload(reader, filename, mime_type))
+    }
+
+    /// Upload a file from an async reader, overriding client settings for
+    /// this request.
+    pub fn upload_with_options<R>(
+        &self,
+        reader: R,
+        filename: impl Into<String>,
+        mime_type: &str,
+        options: RequestOptions,
+    ) -> Result<FileMetadata>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        self.client.block_on(
+            self.inner()
+                .upload_with_options(reader, filename, mime_type, options),
+        )
+    }
+
+    /// Upload a file from disk.
+    pub fn upload_path(&self, path: impl AsRef<Path>) -> Result<FileMetadata> {
+        self.client.block_on(self.inner().upload_path(path))
+    }
+
+    /// Upload a file from disk, overriding client settings for this request.
+    pub fn upload_path_with_options(
+        &self,
+        path: impl AsRef<Path>,
+        options: RequestOptions,
+    ) -> Result<FileMetadata> {
+        self.client
+            .block_on(self.inner().upload_path_with_options(path, options))
+    }
+
+    /// List uploaded files.
+    pub fn list(&self, params: ListFilesParams) -> Result<FileList> {
+        self.client.block_on(self.inner().list(params))
+    }
+
+    /// List uploaded files, overriding client settings for this request.
+    pub fn list_with_options(
+        &self,
+        params: ListFilesParams,
+        options: RequestOptions,
+    ) -> Result<FileList> {
+        self.client
+            .block_on(self.inner().list_with_options(params, options))
+    }
+
+    /// Retrieve the metadata of a file.
+    pub fn retrieve_metadata(&self, file_id: &str) -> Result<FileMetadata> {
+        self.client
+            .block_on(self.inner().retrieve_metadata(file_id))
+    }
+
+    /// Retrieve the metadata of a file, overriding client settings for this
+    /// request.
+    pub fn retrieve_metadata_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<FileMetadata> {
+        self.client.block_on(
+            self.inner()
+                .retrieve_metadata_with_options(file_id, options),
+        )
+    }
+
+    /// Delete a file.
+    pub fn delete(&self, file_id: &str) -> Result<DeletedFile> {
+        self.client.block_on(self.inner().delete(file_id))
+    }
+
+    /// Delete a file, overriding client settings for this request.
+    pub fn delete_with_options(
+        &self,
+        file_id: &str,
+        options: RequestOptions,
+    ) -> Result<DeletedFile> {
+        self.client
+            .block_on(self.inner().delete_with_options(file_id, options))
+    }
+
+    /// Download the contents of a file into memory.
+    pub fn download(&self, file_id: &str) -> Result<Bytes> {
+        self.client.block_on(self.inner().download(file_id))
+    }
+
+    /// Download the contents of a file into memory, overriding client
+    /// settings for this request.
+    pub fn download_with_options(&self, file_id: &str, options: RequestOptions) -> Result<Bytes> {
+        self.client
+            .block_on(self.inner().download_with_options(file_id, options))
+    }
+
+    /// Download the contents of a file directly into a writer.
+    ///
+    /// Returns the number of bytes written.
+    pub fn download_to<W>(&self, file_id: &str, writer: &mut W) -> Result<u64>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        self.download_to_with_options(file_id, writer, RequestOptions::default())
+    }
+
+    /// Download the contents of a file directly into a writer, overriding
+    /// client settings for this request.
+    pub fn download_to_with_options<W>(
+        &self,
+        file_id: &str,
+        writer: &mut W,
+        options: RequestOptions,
+    ) -> Result<u64>
+    where
+        W: std::io::Write + ?Sized,
+    {
+        self.client.block_on(async {
+            let mut stream = self
+                .inner()
+                .download_stream_with_options(file_id, options)
+                .await?;
+            let mut written = 0u64;
+
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk?;
+                writer.write_all(&chunk).map_err(write_error)?;
+                written += chunk.len() as u64;
+            }
+            writer.flush().map_err(write_error)?;
+
+            Ok(written)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Replay;
+    use crate::{AnthropicError, AsyncAnthropic, ClientConfig, RequestOptions};
+
+    #[tokio::test]
+    async fn test_upload_missing_path() {
+        let client = AsyncAnthropic::with_api_key("sk-test").unwrap();
+        let error = client
+            .files()
+            .upload_path("does/not/exist.pdf")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AnthropicError::Io { ref source, .. } if source.kind() == std::io::ErrorKind::NotFound
+        ));
+        assert!(error
+            .to_string()
+            .starts_with("Failed to read does/not/exist.pdf: "));
+    }
+
+    #[tokio::test]
+    async fn test_download_with_options() {
+        let replay = Replay::new(vec![(200, "contents")]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test").transport(replay.clone()),
+        )
+        .unwrap();
+        let bytes = client
+            .files()
+            .download_with_options(
+                "file_1",
+                RequestOptions::new().header("x-trace", "abc").unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(&bytes[..], b"contents");
+
+        let requests = replay.requests.lock().unwrap();
+        assert_eq!(requests[0].url().path(), "/v1/files/file_1/content");
+        assert_eq!(requests[0].headers()["x-trace"], "abc");
+    }
+
+    #[tokio::test]
+    async fn test_upload_headers_match_json_requests() {
+        let replay = Replay::new(vec![(
+            200,
+            r#"{"id":"file_1","type":"file","filename":"a.txt","mime_type":"text/plain","size_bytes":5,"created_at":"2025-01-01T00:00:00Z"}"#,
+        )]);
+        let client = AsyncAnthropic::with_config(
+            ClientConfig::with_api_key("sk-test")
+                .default_header("anthropic-beta", "some-beta")
+                .transport(replay.clone()),
+        )
+        .unwrap();
+        let file = client
+            .files()
+            .upload_with_options(
+                &b"hello"[..],
+                "a.txt",
+                "text/plain",
+                RequestOptions::new()
+                    .header("x-trace", "abc")
+                    .unwrap()
+                    .remove_header("x-stainless-lang")
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(file.id, "file_1");
+
+        let requests = replay.requests.lock().unwrap();
+        let headers = requests[0].headers();
+        assert_eq!(headers["anthropic-beta"], "some-beta,files-api-2025-04-14");
+        assert_eq!(headers["x-trace"], "abc");
+        assert!(headers.contains_key("idempotency-key"));
+        assert!(!headers.contains_key("x-stainless-lang"));
+        assert!(headers["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("multipart/form-data"));
+    }
+}
@@ -1,19 +1,33 @@
 //! Messages API resource.
 
-use crate::client::{Anthropic, AsyncAnthropic};
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+use super::{Batches, BlockingBatches};
+use crate::client::{Anthropic, ApiResponse, AsyncAnthropic, RequestOptions};
 use crate::error::Result;
 use crate::streaming::{BlockingMessageStream, MessageStream};
+use crate::tools::{BlockingToolRunner, ToolRegistry, ToolRunResult, ToolRunner};
 use crate::types::{CountTokensParams, Message, MessageCreateParams, TokenCount};
+use crate::DEFAULT_TIMEOUT_SECS;
 
 /// Messages API resource (async).
 pub struct Messages<'a> {
     client: &'a AsyncAnthropic,
+    betas: Vec<String>,
 }
 
 impl<'a> Messages<'a> {
     /// Create a new Messages resource.
     pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
-        Self { client }
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new Messages resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a AsyncAnthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
     }
 
     /// Create a message.
@@ -40,7 +54,67 @@ impl<'a> Messages<'a> {
     /// }
     /// ```
     pub async fn create(&self, params: MessageCreateParams) -> Result<Message> {
-        self.client.post("/messages", &params).await
+        self.create_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a message, overriding client settings for this request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam, RequestOptions};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let message = client.messages().create_with_options(
+    ///         MessageCreateParams::builder()
+    ///             .model("claude-sonnet-4-5-20250929")
+    ///             .max_tokens(64)
+    ///             .messages(vec![MessageParam::user("Hello, Claude!")])
+    ///             .build(),
+    ///         RequestOptions::new().timeout(Duration::from_secs(10)).max_retries(0),
+    ///     ).await?;
+    ///
+    ///     println!("{}", message.text());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_with_options(
+        &self,
+        params: MessageCreateParams,
+        options: RequestOptions,
+    ) -> Result<Message> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/messages",
+                Some(&params),
+                self.headers(&params),
+                &self.options(&params, options, false),
+            )
+            .await
+    }
+
+    /// Create a message, returning the response status and headers with it.
+    ///
+    /// See [`ApiResponse`] for an example.
+    pub async fn create_with_response(
+        &self,
+        params: MessageCreateParams,
+    ) -> Result<ApiResponse<Message>> {
+        self.client
+            .request_with_response(
+                Method::POST,
+                "/messages",
+                Some(&params),
+                self.headers(&params),
+                &self.options(&params, RequestOptions::default(), false),
+            )
+            .await
     }
 
     /// Create a message with streaming.
@@ -80,26 +154,211 @@ impl<'a> Messages<'a> {
     /// }
     /// ```
     pub async fn create_stream(&self, params: MessageCreateParams) -> Result<MessageStream> {
-        self.client.post_stream("/messages", &params).await
+        self.create_stream_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Create a message with streaming, overriding client settings for this
+    /// request.
+    ///
+    /// The timeout covers the whole stream. Without one, a stream with a
+    /// large `max_tokens` is given longer than the client's default.
+    pub async fn create_stream_with_options(
+        &self,
+        params: MessageCreateParams,
+        options: RequestOptions,
+    ) -> Result<MessageStream> {
+        let options = self.options(&params, options, true);
+        self.client
+            .post_stream("/messages", &params, self.headers(&params), &options)
+            .await
     }
 
     /// Count the tokens in a message.
     ///
     /// This can be used to estimate costs before sending a request.
     pub async fn count_tokens(&self, params: CountTokensParams) -> Result<TokenCount> {
-        self.client.post("/messages/count_tokens", &params).await
+        self.count_tokens_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Count the tokens in a message, overriding client settings for this
+    /// request.
+    pub async fn count_tokens_with_options(
+        &self,
+        params: CountTokensParams,
+        options: RequestOptions,
+    ) -> Result<TokenCount> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/messages/count_tokens",
+                Some(&params),
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await
+    }
+
+    /// Count the tokens of the request `params` would send.
+    ///
+    /// The betas required by its tools are sent as they would be with the
+    /// request.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: anthropic_sdk::AsyncAnthropic, params: anthropic_sdk::MessageCreateParams) -> anthropic_sdk::Result<()> {
+    /// let count = client.messages().count_tokens_for(&params).await?;
+    /// if count.input_tokens < 100_000 {
+    ///     let message = client.messages().create(params).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count_tokens_for(&self, params: &MessageCreateParams) -> Result<TokenCount> {
+        self.client
+            .request_with_options(
+                Method::POST,
+                "/messages/count_tokens",
+                Some(&CountTokensParams::from(params)),
+                self.headers(params),
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// Count the tokens in a message, returning the response status and
+    /// headers with the count.
+    pub async fn count_tokens_with_response(
+        &self,
+        params: CountTokensParams,
+    ) -> Result<ApiResponse<TokenCount>> {
+        self.client
+            .request_with_response(
+                Method::POST,
+                "/messages/count_tokens",
+                Some(&params),
+                self.client.beta_headers(&self.betas),
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// Access the Message Batches API.
+    pub fn batches(&self) -> Batches<'a> {
+        Batches::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Beta headers for a request, including betas required by its tools.
+    fn headers(&self, params: &MessageCreateParams) -> HeaderMap {
+        let mut betas = self.betas.clone();
+        for beta in params.required_betas() {
+            if !betas.iter().any(|b| b == beta) {
+                betas.push(beta.to_string());
+            }
+        }
+        self.client.beta_headers(&betas)
+    }
+
+    /// Request options with the timeout raised for long generations.
+    ///
+    /// Output is generated at roughly 128K tokens an hour. When the options
+    /// set no timeout and the client's is the default, not set explicitly
+    /// (see [`ClientConfig::scale_timeout`](crate::ClientConfig::scale_timeout)),
+    /// a request whose `max_tokens` could take longer than that is given as
+    /// long as it needs.
+    fn options(
+        &self,
+        params: &MessageCreateParams,
+        mut options: RequestOptions,
+        stream: bool,
+    ) -> RequestOptions {
+        let config = self.client.config();
+        let default = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        let timeout = if stream {
+            config.stream_timeout
+        } else {
+            Some(config.timeout)
+        };
+        if options.timeout.is_none() && config.scale_timeout && timeout == Some(default) {
+            let expected = expected_duration(params.max_tokens);
+            if expected > default {
+                options.timeout = Some(expected);
+            }
+        }
+        options
+    }
+
+    /// Create a runner that executes the given tools in a tool use loop.
+    ///
+    /// Accepts a [`ToolRegistry`] or a `Vec` of [`FunctionTool`](crate::tools::FunctionTool)s.
+    pub fn tool_runner(&self, tools: impl Into<ToolRegistry>) -> ToolRunner<'a> {
+        ToolRunner::new(
+            Messages::with_betas(self.client, self.betas.clone()),
+            tools.into(),
+        )
+    }
+
+    /// Create a message, executing tool calls until the model finishes its turn.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::tools::FunctionTool;
+    /// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam, Tool, ToolInputSchema};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let time = FunctionTool::new(
+    ///         Tool::with_description("get_time", "Get the current time", ToolInputSchema::empty()),
+    ///         |_input| async { Ok("12:00".to_string()) },
+    ///     );
+    ///
+    ///     let result = client.messages().run_tools(
+    ///         MessageCreateParams::builder()
+    ///             .model("claude-sonnet-4-5-20250929")
+    ///             .max_tokens(1024)
+    ///             .messages(vec![MessageParam::user("What time is it?")])
+    ///             .build(),
+    ///         vec![time],
+    ///     ).await?;
+    ///
+    ///     println!("{}", result.message.text());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn run_tools(
+        &self,
+        params: MessageCreateParams,
+        tools: impl Into<ToolRegistry>,
+    ) -> Result<ToolRunResult> {
+        self.tool_runner(tools).run(params).await
     }
 }
 
 /// Messages API resource (blocking).
 pub struct BlockingMessages<'a> {
     client: &'a Anthropic,
+    betas: Vec<String>,
 }
 
 impl<'a> BlockingMessages<'a> {
     /// Create a new blocking Messages resource.
     pub(crate) fn new(client: &'a Anthropic) -> Self {
-        Self { client }
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new blocking Messages resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The async resource this wraps.
+    fn inner(&self) -> Messages<'a> {
+        Messages::with_betas(self.client.inner(), self.betas.clone())
     }
 
     /// Create a message.
@@ -125,31 +384,146 @@ impl<'a> BlockingMessages<'a> {
     /// }
     /// ```
     pub fn create(&self, params: MessageCreateParams) -> Result<Message> {
+        self.client.block_on(self.inner().create(params))
+    }
+
+    /// Create a message, overriding client settings for this request.
+    pub fn create_with_options(
+        &self,
+        params: MessageCreateParams,
+        options: RequestOptions,
+    ) -> Result<Message> {
         self.client
-            .block_on(self.client.inner().messages().create(params))
+            .block_on(self.inner().create_with_options(params, options))
+    }
+
+    /// Create a message, returning the response status and headers with it.
+    pub fn create_with_response(
+        &self,
+        params: MessageCreateParams,
+    ) -> Result<ApiResponse<Message>> {
+        self.client
+            .block_on(self.inner().create_with_response(params))
     }
 
     /// Create a message with streaming.
     ///
     /// Returns a blocking iterator over stream events.
     pub fn create_stream(&self, params: MessageCreateParams) -> Result<BlockingMessageStream> {
+        self.create_stream_with_options(params, RequestOptions::default())
+    }
+
+    /// Create a message with streaming, overriding client settings for this
+    /// request.
+    pub fn create_stream_with_options(
+        &self,
+        params: MessageCreateParams,
+        options: RequestOptions,
+    ) -> Result<BlockingMessageStream> {
         let stream = self
             .client
-            .block_on(self.client.inner().messages().create_stream(params))?;
-
-        // Create a new runtime handle for the blocking stream
-        let runtime = std::sync::Arc::new(tokio::runtime::Runtime::new().map_err(|e| {
-            crate::AnthropicError::Config {
-                message: format!("Failed to create runtime for stream: {}", e),
-            }
-        })?);
+            .block_on(self.inner().create_stream_with_options(params, options))?;
 
-        Ok(BlockingMessageStream::new(stream, runtime))
+        Ok(BlockingMessageStream::new(
+            stream,
+            self.client.shared_runtime(),
+        ))
     }
 
     /// Count the tokens in a message.
     pub fn count_tokens(&self, params: CountTokensParams) -> Result<TokenCount> {
+        self.client.block_on(self.inner().count_tokens(params))
+    }
+
+    /// Count the tokens in a message, overriding client settings for this
+    /// request.
+    pub fn count_tokens_with_options(
+        &self,
+        params: CountTokensParams,
+        options: RequestOptions,
+    ) -> Result<TokenCount> {
         self.client
-            .block_on(self.client.inner().messages().count_tokens(params))
+            .block_on(self.inner().count_tokens_with_options(params, options))
+    }
+
+    /// Count the tokens of the request `params` would send.
+    pub fn count_tokens_for(&self, params: &MessageCreateParams) -> Result<TokenCount> {
+        self.client.block_on(self.inner().count_tokens_for(params))
+    }
+
+    /// Count the tokens in a message, returning the response status and
+    /// headers with the count.
+    pub fn count_tokens_with_response(
+        &self,
+        params: CountTokensParams,
+    ) -> Result<ApiResponse<TokenCount>> {
+        self.client
+            .block_on(self.inner().count_tokens_with_response(params))
+    }
+
+    /// Access the Message Batches API.
+    pub fn batches(&self) -> BlockingBatches<'a> {
+        BlockingBatches::with_betas(self.client, self.betas.clone())
+    }
+
+    /// Create a runner that executes the given tools in a tool use loop.
+    pub fn tool_runner(&self, tools: impl Into<ToolRegistry>) -> BlockingToolRunner<'a> {
+        BlockingToolRunner::new(self.client, self.inner().tool_runner(tools))
+    }
+
+    /// Create a message, executing tool calls until the model finishes its turn.
+    ///
+    /// Tool handlers run on the client's runtime.
+    pub fn run_tools(
+        &self,
+        params: MessageCreateParams,
+        tools: impl Into<ToolRegistry>,
+    ) -> Result<ToolRunResult> {
+        self.tool_runner(tools).run(params)
+    }
+}
+
+/// How long generating `max_tokens` of output may take, at 128K tokens an
+/// hour.
+fn expected_duration(max_tokens: u32) -> Duration {
+    Duration::from_secs(3600 * u64::from(max_tokens) / 128_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_duration() {
+        assert_eq!(expected_duration(1024), Duration::from_secs(28));
+        assert_eq!(expected_duration(64_000), Duration::from_secs(1800));
+        assert_eq!(expected_duration(128_000), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_explicit_timeout_is_not_scaled() {
+        let params = MessageCreateParams::builder().max_tokens(128_000).build();
+        let timeout = |config: crate::ClientConfig, stream: bool| {
+            let config = crate::ClientConfig {
+                api_key: Some("sk-test".into()),
+                ..config
+            };
+            let client = AsyncAnthropic::with_config(config).unwrap();
+            client
+                .messages()
+                .options(&params, RequestOptions::default(), stream)
+                .timeout
+        };
+
+        let hour = Some(Duration::from_secs(3600));
+        assert_eq!(timeout(crate::ClientConfig::default(), false), hour);
+        assert_eq!(timeout(crate::ClientConfig::default(), true), hour);
+
+        let ten_minutes = Duration::from_secs(DEFAULT_TIMEOUT_SECS);
+        let explicit = crate::ClientConfig::default().timeout(ten_minutes);
+        assert_eq!(timeout(explicit.clone(), false), None);
+        assert_eq!(timeout(explicit, true), None);
+        let disabled = crate::ClientConfig::default().scale_timeout(false);
+        assert_eq!(timeout(disabled, false), None);
     }
 }
@@ -1,9 +1,24 @@
 //! API resource implementations.
 
+mod admin;
+mod batches;
+mod beta;
 mod completions;
+mod files;
 mod messages;
 mod models;
+#[cfg(feature = "beta")]
+mod prompt_tools;
 
-pub use completions::{BlockingCompletions, Completions};
+pub use admin::{
+    Admin, ApiKeys, BlockingAdmin, BlockingApiKeys, BlockingInvites, BlockingReports,
+    BlockingWorkspaceMembers, BlockingWorkspaces, Invites, Reports, WorkspaceMembers, Workspaces,
+};
+pub use batches::{Batches, BlockingBatches, PollConfig};
+pub use beta::{Beta, BlockingBeta};
+pub use completions::{BlockingCompletions, Completion, CompletionCreateParams, Completions};
+pub use files::{BlockingFiles, Files};
 pub use messages::{BlockingMessages, Messages};
-pub use models::{BlockingModels, Models};
+pub use models::{BlockingModelIter, BlockingModels, Models};
+#[cfg(feature = "beta")]
+pub use prompt_tools::{BlockingPromptTools, PromptTools};
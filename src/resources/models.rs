@@ -1,18 +1,28 @@
 //! Models API resource.
 
-use crate::client::{Anthropic, AsyncAnthropic};
-use crate::error::Result;
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::{AnthropicError, Result};
 use crate::types::{ListModelsParams, Model, ModelList};
 
 /// Models API resource (async).
 pub struct Models<'a> {
     client: &'a AsyncAnthropic,
+    betas: Vec<String>,
 }
 
 impl<'a> Models<'a> {
     /// Create a new Models resource.
     pub(crate) fn new(client: &'a AsyncAnthropic) -> Self {
-        Self { client }
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new Models resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a AsyncAnthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
     }
 
     /// List available models.
@@ -35,6 +45,16 @@ impl<'a> Models<'a> {
     /// }
     /// ```
     pub async fn list(&self, params: ListModelsParams) -> Result<ModelList> {
+        self.list_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// List available models, overriding client settings for this request.
+    pub async fn list_with_options(
+        &self,
+        params: ListModelsParams,
+        options: RequestOptions,
+    ) -> Result<ModelList> {
         let mut path = "/models".to_string();
         let mut query_parts = Vec::new();
 
@@ -53,7 +73,15 @@ impl<'a> Models<'a> {
             path.push_str(&query_parts.join("&"));
         }
 
-        self.client.get(&path).await
+        self.client
+            .request_with_options(
+                Method::GET,
+                &path,
+                None::<&()>,
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await
     }
 
     /// Retrieve a specific model.
@@ -74,30 +102,145 @@ impl<'a> Models<'a> {
     /// }
     /// ```
     pub async fn retrieve(&self, model_id: &str) -> Result<Model> {
-        self.client.get(&format!("/models/{}", model_id)).await
+        self.retrieve_with_options(model_id, RequestOptions::default())
+            .await
+    }
+
+    /// Retrieve a specific model, overriding client settings for this request.
+    pub async fn retrieve_with_options(
+        &self,
+        model_id: &str,
+        options: RequestOptions,
+    ) -> Result<Model> {
+        self.client
+            .request_with_options(
+                Method::GET,
+                &format!("/models/{}", model_id),
+                None::<&()>,
+                self.client.beta_headers(&self.betas),
+                &options,
+            )
+            .await
+    }
+
+    /// List all available models, fetching further pages as needed.
+    ///
+    /// Pages are requested lazily by following the `after_id` cursor, starting
+    /// from the given params. `limit` controls the page size.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::AsyncAnthropic;
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let mut models = client.models().list_all(Default::default());
+    ///     while let Some(model) = models.try_next().await? {
+    ///         println!("{}", model.id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_all(&self, params: ListModelsParams) -> BoxStream<'a, Result<Model>> {
+        let models = Models::with_betas(self.client, self.betas.clone());
+
+        stream::try_unfold((models, Some(params)), |(models, params)| async move {
+            let Some(params) = params else {
+                return Ok::<_, AnthropicError>(None);
+            };
+
+            let page = models.list(params.clone()).await?;
+            let next = match page.last_id {
+                Some(last_id) if page.has_more => Some(ListModelsParams {
+                    before_id: None,
+                    after_id: Some(last_id),
+                    ..params
+                }),
+                _ => None,
+            };
+
+            Ok(Some((
+                stream::iter(page.data.into_iter().map(Ok)),
+                (models, next),
+            )))
+        })
+        .try_flatten()
+        .boxed()
     }
 }
 
 /// Models API resource (blocking).
 pub struct BlockingModels<'a> {
     client: &'a Anthropic,
+    betas: Vec<String>,
 }
 
 impl<'a> BlockingModels<'a> {
     /// Create a new blocking Models resource.
     pub(crate) fn new(client: &'a Anthropic) -> Self {
-        Self { client }
+        Self::with_betas(client, Vec::new())
+    }
+
+    /// Create a new blocking Models resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The async resource this wraps.
+    fn inner(&self) -> Models<'a> {
+        Models::with_betas(self.client.inner(), self.betas.clone())
     }
 
     /// List available models.
     pub fn list(&self, params: ListModelsParams) -> Result<ModelList> {
+        self.client.block_on(self.inner().list(params))
+    }
+
+    /// List available models, overriding client settings for this request.
+    pub fn list_with_options(
+        &self,
+        params: ListModelsParams,
+        options: RequestOptions,
+    ) -> Result<ModelList> {
         self.client
-            .block_on(self.client.inner().models().list(params))
+            .block_on(self.inner().list_with_options(params, options))
     }
 
     /// Retrieve a specific model.
     pub fn retrieve(&self, model_id: &str) -> Result<Model> {
+        self.client.block_on(self.inner().retrieve(model_id))
+    }
+
+    /// Retrieve a specific model, overriding client settings for this request.
+    pub fn retrieve_with_options(&self, model_id: &str, options: RequestOptions) -> Result<Model> {
         self.client
-            .block_on(self.client.inner().models().retrieve(model_id))
+            .block_on(self.inner().retrieve_with_options(model_id, options))
+    }
+
+    /// List all available models, fetching further pages as needed.
+    pub fn list_all(&self, params: ListModelsParams) -> BlockingModelIter<'a> {
+        BlockingModelIter {
+            client: self.client,
+            inner: self.inner().list_all(params),
+        }
+    }
+}
+
+/// A blocking iterator over all models, returned by [`BlockingModels::list_all`].
+pub struct BlockingModelIter<'a> {
+    client: &'a Anthropic,
+    inner: BoxStream<'a, Result<Model>>,
+}
+
+impl Iterator for BlockingModelIter<'_> {
+    type Item = Result<Model>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.client.block_on(self.inner.next())
     }
 }
@@ -0,0 +1,186 @@
+//! Experimental prompt tools resource.
+
+use reqwest::Method;
+
+use crate::client::{Anthropic, AsyncAnthropic, RequestOptions};
+use crate::error::Result;
+use crate::types::{
+    betas, GeneratePromptParams, ImprovePromptParams, PromptResponse, TemplatizePromptParams,
+    TemplatizePromptResponse,
+};
+
+/// Experimental prompt tools resource (async).
+pub struct PromptTools<'a> {
+    client: &'a AsyncAnthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> PromptTools<'a> {
+    /// Create a new PromptTools resource that sends the given beta flags.
+    ///
+    /// The prompt tools beta flag is always sent in addition to these.
+    pub(crate) fn with_betas(client: &'a AsyncAnthropic, mut betas: Vec<String>) -> Self {
+        if !betas.iter().any(|beta| beta == betas::PROMPT_TOOLS) {
+            betas.push(betas::PROMPT_TOOLS.to_string());
+        }
+        Self { client, betas }
+    }
+
+    /// Generate a prompt from a task description.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::types::{betas, GeneratePromptParams};
+    /// use anthropic_sdk::AsyncAnthropic;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+    ///     let client = AsyncAnthropic::new()?;
+    ///
+    ///     let prompt = client
+    ///         .beta([betas::PROMPT_TOOLS])
+    ///         .prompt_tools()
+    ///         .generate(GeneratePromptParams::new("a chef for meal prep planning"))
+    ///         .await?;
+    ///
+    ///     println!("{}", prompt.system);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn generate(&self, params: GeneratePromptParams) -> Result<PromptResponse> {
+        self.generate_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Generate a prompt from a task description, overriding client settings
+    /// for this request.
+    pub async fn generate_with_options(
+        &self,
+        params: GeneratePromptParams,
+        options: RequestOptions,
+    ) -> Result<PromptResponse> {
+        self.post("/experimental/generate_prompt", &params, &options)
+            .await
+    }
+
+    /// Improve an existing prompt, optionally guided by feedback.
+    pub async fn improve(&self, params: ImprovePromptParams) -> Result<PromptResponse> {
+        self.improve_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Improve an existing prompt, optionally guided by feedback, overriding
+    /// client settings for this request.
+    pub async fn improve_with_options(
+        &self,
+        params: ImprovePromptParams,
+        options: RequestOptions,
+    ) -> Result<PromptResponse> {
+        self.post("/experimental/improve_prompt", &params, &options)
+            .await
+    }
+
+    /// Turn a prompt into a template by extracting its variable parts.
+    pub async fn templatize(
+        &self,
+        params: TemplatizePromptParams,
+    ) -> Result<TemplatizePromptResponse> {
+        self.templatize_with_options(params, RequestOptions::default())
+            .await
+    }
+
+    /// Turn a prompt into a template by extracting its variable parts,
+    /// overriding client settings for this request.
+    pub async fn templatize_with_options(
+        &self,
+        params: TemplatizePromptParams,
+        options: RequestOptions,
+    ) -> Result<TemplatizePromptResponse> {
+        self.post("/experimental/templatize_prompt", &params, &options)
+            .await
+    }
+
+    /// Send a prompt tools request.
+    async fn post<T, B>(&self, path: &str, body: &B, options: &RequestOptions) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.client
+            .request_with_options(
+                Method::POST,
+                path,
+                Some(body),
+                self.client.beta_headers(&self.betas),
+                options,
+            )
+            .await
+    }
+}
+
+/// Experimental prompt tools resource (blocking).
+pub struct BlockingPromptTools<'a> {
+    client: &'a Anthropic,
+    betas: Vec<String>,
+}
+
+impl<'a> BlockingPromptTools<'a> {
+    /// Create a new blocking PromptTools resource that sends the given beta flags.
+    pub(crate) fn with_betas(client: &'a Anthropic, betas: Vec<String>) -> Self {
+        Self { client, betas }
+    }
+
+    /// The async resource this wraps.
+    fn inner(&self) -> PromptTools<'a> {
+        PromptTools::with_betas(self.client.inner(), self.betas.clone())
+    }
+
+    /// Generate a prompt from a task description.
+    pub fn generate(&self, params: GeneratePromptParams) -> Result<PromptResponse> {
+        self.client.block_on(self.inner().generate(params))
+    }
+
+    /// Generate a prompt from a task description, overriding client settings
+    /// for this request.
+    pub fn generate_with_options(
+        &self,
+        params: GeneratePromptParams,
+        options: RequestOptions,
+    ) -> Result<PromptResponse> {
+        self.client
+            .block_on(self.inner().generate_with_options(params, options))
+    }
+
+    /// Improve an existing prompt, optionally guided by feedback.
+    pub fn improve(&self, params: ImprovePromptParams) -> Result<PromptResponse> {
+        self.client.block_on(self.inner().improve(params))
+    }
+
+    /// Improve an existing prompt, optionally guided by feedback, overriding
+    /// client settings for this request.
+    pub fn improve_with_options(
+        &self,
+        params: ImprovePromptParams,
+        options: RequestOptions,
+    ) -> Result<PromptResponse> {
+        self.client
+            .block_on(self.inner().improve_with_options(params, options))
+    }
+
+    /// Turn a prompt into a template by extracting its variable parts.
+    pub fn templatize(&self, params: TemplatizePromptParams) -> Result<TemplatizePromptResponse> {
+        self.client.block_on(self.inner().templatize(params))
+    }
+
+    /// Turn a prompt into a template by extracting its variable parts,
+    /// overriding client settings for this request.
+    pub fn templatize_with_options(
+        &self,
+        params: TemplatizePromptParams,
+        options: RequestOptions,
+    ) -> Result<TemplatizePromptResponse> {
+        self.client
+            .block_on(self.inner().templatize_with_options(params, options))
+    }
+}
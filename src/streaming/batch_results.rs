@@ -0,0 +1,126 @@
+//! Message batch results stream implementation.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+use pin_project_lite::pin_project;
+use reqwest::Response;
+
+use crate::client::block_on;
+use crate::error::{AnthropicError, Result};
+use crate::types::BatchIndividualResponse;
+
+use super::jsonl::JsonlDecoder;
+
+pin_project! {
+    /// A stream of individual results from a message batch `.jsonl` results file.
+    ///
+    /// Each line is decoded lazily as it arrives, so the full results file never
+    /// has to be held in memory.
+    pub struct BatchResultsStream {
+        #[pin]
+        inner: futures::stream::BoxStream<'static, std::result::Result<Bytes, reqwest::Error>>,
+        decoder: JsonlDecoder,
+        pending: VecDeque<String>,
+        finished: bool,
+    }
+}
+
+impl BatchResultsStream {
+    /// Create a new batch results stream from a response.
+    pub(crate) fn new(response: Response) -> Self {
+        use futures::StreamExt;
+
+        Self {
+            inner: response.bytes_stream().boxed(),
+            decoder: JsonlDecoder::new(),
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    /// Consume the stream and collect all results.
+    pub async fn collect_all(mut self) -> Result<Vec<BatchIndividualResponse>> {
+        use futures::StreamExt;
+
+        let mut results = Vec::new();
+        while let Some(result) = self.next().await {
+            results.push(result?);
+        }
+
+        Ok(results)
+    }
+}
+
+/// Parse a single results line.
+fn parse_line(line: &str) -> Result<BatchIndividualResponse> {
+    serde_json::from_str(line).map_err(AnthropicError::Json)
+}
+
+impl Stream for BatchResultsStream {
+    type Item = Result<BatchIndividualResponse>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(line) = this.pending.pop_front() {
+                return Poll::Ready(Some(parse_line(&line)));
+            }
+
+            if *this.finished {
+                return Poll::Ready(None);
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => {
+                    this.pending.extend(this.decoder.decode(bytes));
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    *this.finished = true;
+                    return Poll::Ready(Some(Err(AnthropicError::Connection(e))));
+                }
+                Poll::Ready(None) => {
+                    *this.finished = true;
+                    this.pending.extend(this.decoder.flush());
+                }
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// A blocking iterator over message batch results.
+pub struct BlockingBatchResultsStream {
+    inner: BatchResultsStream,
+    runtime: std::sync::Arc<tokio::runtime::Runtime>,
+}
+
+impl BlockingBatchResultsStream {
+    /// Create a new blocking batch results stream.
+    pub(crate) fn new(
+        inner: BatchResultsStream,
+        runtime: std::sync::Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Consume the stream and collect all results.
+    pub fn collect_all(self) -> Result<Vec<BatchIndividualResponse>> {
+        block_on(&self.runtime, self.inner.collect_all())
+    }
+}
+
+impl Iterator for BlockingBatchResultsStream {
+    type Item = Result<BatchIndividualResponse>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use futures::StreamExt;
+        block_on(&self.runtime, self.inner.next())
+    }
+}
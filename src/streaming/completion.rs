@@ -0,0 +1,275 @@
+//! Legacy completion stream implementation.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+use pin_project_lite::pin_project;
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+
+use crate::client::{block_on, RequestPermit};
+use crate::error::{AnthropicError, Result};
+
+use super::events::{RawStreamEvent, StreamError};
+use super::stream::decode_events;
+
+/// A streaming event from the legacy Completions API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CompletionStreamEvent {
+    /// A chunk of generated text.
+    Completion(CompletionChunk),
+
+    /// Ping event (keep-alive).
+    Ping,
+
+    /// Error event.
+    Error { error: StreamError },
+}
+
+/// A chunk of a streamed completion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionChunk {
+    /// Unique object identifier.
+    #[serde(default)]
+    pub id: String,
+
+    /// The text generated in this chunk.
+    pub completion: String,
+
+    /// The reason the model stopped generating, set on the last chunk.
+    #[serde(default)]
+    pub stop_reason: Option<String>,
+
+    /// The model that generated the completion.
+    #[serde(default)]
+    pub model: String,
+}
+
+pin_project! {
+    /// A stream of completion events from the legacy Completions API.
+    pub struct CompletionStream {
+        #[pin]
+        inner: futures::stream::BoxStream<'static, Result<Vec<RawStreamEvent>>>,
+        pending: VecDeque<RawStreamEvent>,
+        text: String,
+        stop_reason: Option<String>,
+        finished: bool,
+    }
+}
+
+impl CompletionStream {
+    /// Create a new completion stream from a response, holding the client's
+    /// concurrency `permit` until dropped and failing on events larger than
+    /// `max_event_size` bytes.
+    pub(crate) fn new(
+        response: Response,
+        permit: Option<RequestPermit>,
+        max_event_size: Option<usize>,
+    ) -> Self {
+        use futures::{StreamExt, TryStreamExt};
+
+        let bytes = response
+            .bytes_stream()
+            .map(move |chunk| {
+                let _permit = &permit;
+                chunk
+            })
+            .map_err(AnthropicError::Connection);
+        Self::from_bytes(bytes.boxed(), max_event_size)
+    }
+
+    /// Create a new completion stream from raw SSE bytes.
+    fn from_bytes(
+        bytes: futures::stream::BoxStream<'static, Result<Bytes>>,
+        max_event_size: Option<usize>,
+    ) -> Self {
+        use futures::StreamExt;
+
+        Self {
+            inner: decode_events(bytes, max_event_size).boxed(),
+            pending: VecDeque::new(),
+            text: String::new(),
+            stop_reason: None,
+            finished: false,
+        }
+    }
+
+    /// Get the accumulated text so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Get the stop reason, once the final chunk has arrived.
+    pub fn stop_reason(&self) -> Option<&str> {
+        self.stop_reason.as_deref()
+    }
+
+    /// Check if the stream has completed.
+    pub fn is_complete(&self) -> bool {
+        self.stop_reason.is_some()
+    }
+
+    /// Consume the stream and collect all text.
+    pub async fn collect_text(mut self) -> Result<String> {
+        use futures::StreamExt;
+
+        while let Some(result) = self.next().await {
+            result?;
+        }
+
+        Ok(self.text)
+    }
+}
+
+/// Parse a raw event into a typed event.
+fn parse_event(event: &RawStreamEvent) -> Result<CompletionStreamEvent> {
+    let mut data: serde_json::Value =
+        serde_json::from_str(&event.data).map_err(AnthropicError::Json)?;
+
+    // Add type field if missing
+    if data.get("type").is_none() {
+        data["type"] = serde_json::Value::String(event.event.clone());
+    }
+
+    serde_json::from_value(data).map_err(AnthropicError::Json)
+}
+
+impl Stream for CompletionStream {
+    type Item = Result<CompletionStreamEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(raw_event) = this.pending.pop_front() {
+                let event = parse_event(&raw_event);
+                if let Ok(CompletionStreamEvent::Completion(chunk)) = &event {
+                    this.text.push_str(&chunk.completion);
+                    if chunk.stop_reason.is_some() {
+                        *this.stop_reason = chunk.stop_reason.clone();
+                    }
+                }
+                return Poll::Ready(Some(event));
+            }
+
+            if *this.finished {
+                return Poll::Ready(None);
+            }
+
+            match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(events))) => {
+                    this.pending.extend(events);
+                }
+                Poll::Ready(Some(Err(e))) => {
+                    *this.finished = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Ready(None) => {
+                    *this.finished = true;
+                }
+                Poll::Pending => {
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// A blocking iterator over completion stream events.
+pub struct BlockingCompletionStream {
+    inner: CompletionStream,
+    runtime: std::sync::Arc<tokio::runtime::Runtime>,
+}
+
+impl BlockingCompletionStream {
+    /// Create a new blocking stream.
+    pub(crate) fn new(
+        inner: CompletionStream,
+        runtime: std::sync::Arc<tokio::runtime::Runtime>,
+    ) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Get the accumulated text so far.
+    pub fn text(&self) -> &str {
+        self.inner.text()
+    }
+
+    /// Consume the stream and collect all text.
+    pub fn collect_text(self) -> Result<String> {
+        block_on(&self.runtime, self.inner.collect_text())
+    }
+}
+
+impl Iterator for BlockingCompletionStream {
+    type Item = Result<CompletionStreamEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use futures::StreamExt;
+        block_on(&self.runtime, self.inner.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(event: &str, data: &str) -> RawStreamEvent {
+        RawStreamEvent {
+            event: event.to_string(),
+            data: data.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_completion_chunk() {
+        let event = parse_event(&raw(
+            "completion",
+            r#"{"type":"completion","id":"compl_1","completion":" Hello","stop_reason":null,"model":"claude-2.1"}"#,
+        ))
+        .unwrap();
+
+        match event {
+            CompletionStreamEvent::Completion(chunk) => {
+                assert_eq!(chunk.completion, " Hello");
+                assert!(chunk.stop_reason.is_none());
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_ping_without_type() {
+        let event = parse_event(&raw("ping", "{}")).unwrap();
+        assert!(matches!(event, CompletionStreamEvent::Ping));
+    }
+
+    #[tokio::test]
+    async fn test_max_event_size() {
+        use futures::StreamExt;
+
+        let sse = concat!(
+            "event: completion\n",
+            r#"data: {"type":"completion","completion":" Hi","stop_reason":null,"model":"claude-2.1"}"#,
+            "\n\n",
+            "event: completion\n",
+            r#"data: {"type":"completion","completion":" there, this chunk is far too long","stop_reason":null,"model":"claude-2.1"}"#,
+            "\n\n",
+        );
+        let bytes = futures::stream::iter([Ok(Bytes::from(sse))]).boxed();
+        let events: Vec<_> = CompletionStream::from_bytes(bytes, Some(90))
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            Err(AnthropicError::Stream { message })
+                if message == "Stream event exceeds the limit of 90 bytes"
+        ));
+    }
+}
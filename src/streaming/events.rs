@@ -1,13 +1,26 @@
 //! Streaming event types.
 
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::types::{ContentBlock, Message, StopReason};
+use crate::types::{
+    unknown_fallback, Citation, Container, ContentBlock, Message, MessageDeltaUsage,
+    ServerToolUsage, StopReason, Usage,
+};
+
+use super::PartialJson;
 
 /// A streaming event from the API.
+///
+/// Events of a type added to the API after this SDK are kept as
+/// [`Unknown`](Self::Unknown).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+#[non_exhaustive]
 pub enum MessageStreamEvent {
     /// Message started event.
     MessageStart { message: Message },
@@ -40,7 +53,55 @@ pub enum MessageStreamEvent {
     Ping,
 
     /// Error event.
+    ///
+    /// [`MessageStream`](super::MessageStream) yields these as errors, mapped
+    /// to the matching [`AnthropicError`](crate::AnthropicError) variant by
+    /// their type.
     Error { error: StreamError },
+
+    /// An event of a type this SDK does not know, kept as its raw JSON.
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+unknown_fallback!(
+    MessageStreamEvent,
+    [
+        "message_start",
+        "message_delta",
+        "message_stop",
+        "content_block_start",
+        "content_block_delta",
+        "content_block_stop",
+        "ping",
+        "error",
+    ]
+);
+
+impl MessageStreamEvent {
+    /// Get the SSE event name of this event, such as `message_start`.
+    pub fn event_type(&self) -> &str {
+        match self {
+            MessageStreamEvent::MessageStart { .. } => "message_start",
+            MessageStreamEvent::MessageDelta { .. } => "message_delta",
+            MessageStreamEvent::MessageStop => "message_stop",
+            MessageStreamEvent::ContentBlockStart { .. } => "content_block_start",
+            MessageStreamEvent::ContentBlockDelta { .. } => "content_block_delta",
+            MessageStreamEvent::ContentBlockStop { .. } => "content_block_stop",
+            MessageStreamEvent::Ping => "ping",
+            MessageStreamEvent::Error { .. } => "error",
+            MessageStreamEvent::Unknown(value) => value["type"].as_str().unwrap_or_default(),
+        }
+    }
+
+    /// Serialize this event in SSE format, as the API sends it.
+    pub fn to_sse(&self) -> serde_json::Result<String> {
+        let raw = RawStreamEvent {
+            event: self.event_type().to_string(),
+            data: serde_json::to_string(self)?,
+        };
+        Ok(raw.to_sse())
+    }
 }
 
 /// Message delta (updates to the message).
@@ -53,18 +114,19 @@ pub struct MessageDelta {
     /// The stop sequence that caused the model to stop.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop_sequence: Option<String>,
-}
 
-/// Message delta usage information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MessageDeltaUsage {
-    /// The number of output tokens generated so far.
-    pub output_tokens: u32,
+    /// The code execution container used by the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<Container>,
 }
 
 /// Content block delta (incremental content update).
+///
+/// Deltas of a type added to the API after this SDK are kept as
+/// [`Unknown`](Self::Unknown).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+#[non_exhaustive]
 pub enum ContentBlockDelta {
     /// Text delta.
     TextDelta { text: String },
@@ -80,8 +142,23 @@ pub enum ContentBlockDelta {
 
     /// Citations delta.
     CitationsDelta { citation: Value },
+
+    /// A delta of a type this SDK does not know, kept as its raw JSON.
+    #[serde(skip)]
+    Unknown(Value),
 }
 
+unknown_fallback!(
+    ContentBlockDelta,
+    [
+        "text_delta",
+        "input_json_delta",
+        "thinking_delta",
+        "signature_delta",
+        "citations_delta",
+    ]
+);
+
 impl ContentBlockDelta {
     /// Get the text content if this is a text delta.
     pub fn as_text(&self) -> Option<&str> {
@@ -129,12 +206,42 @@ pub struct RawStreamEvent {
     pub data: String,
 }
 
+impl RawStreamEvent {
+    /// Serialize this event in SSE format, ending with a blank line.
+    pub fn to_sse(&self) -> String {
+        let mut sse = String::new();
+        if !self.event.is_empty() {
+            sse.push_str("event: ");
+            sse.push_str(&self.event);
+            sse.push('\n');
+        }
+        for line in self.data.split('\n') {
+            sse.push_str("data: ");
+            sse.push_str(line);
+            sse.push('\n');
+        }
+        sse.push('\n');
+        sse
+    }
+
+    /// Serialize this event in SSE format as bytes.
+    pub fn to_sse_bytes(&self) -> Bytes {
+        Bytes::from(self.to_sse())
+    }
+}
+
 /// Accumulated message state from streaming.
 #[derive(Debug, Clone, Default)]
 pub struct StreamState {
     /// The accumulated message.
     pub message: Option<Message>,
 
+    /// Content blocks received so far, by index.
+    ///
+    /// Tool use blocks get their input once the block's `content_block_stop`
+    /// event arrives; until then it is the empty object they started with.
+    pub content: Vec<ContentBlock>,
+
     /// Accumulated text content.
     pub text: String,
 
@@ -147,8 +254,27 @@ pub struct StreamState {
     /// The final stop reason.
     pub stop_reason: Option<StopReason>,
 
+    /// The stop sequence that was generated, if any.
+    pub stop_sequence: Option<String>,
+
+    /// The code execution container used by the message, if any.
+    pub container: Option<Container>,
+
     /// Total output tokens.
     pub output_tokens: u32,
+
+    /// Cumulative usage, starting from the usage in `message_start` and
+    /// updated by each `message_delta`.
+    pub usage: Option<Usage>,
+
+    /// Cumulative server tool usage.
+    pub server_tool_use: Option<ServerToolUsage>,
+
+    /// Timing of the stream.
+    pub metrics: StreamMetrics,
+
+    /// Partial input JSON of tool use blocks that have not stopped yet.
+    input_json: HashMap<usize, PartialJson>,
 }
 
 impl StreamState {
@@ -162,36 +288,216 @@ impl StreamState {
         match event {
             MessageStreamEvent::MessageStart { message } => {
                 self.message = Some(message.clone());
+                self.usage = Some(message.usage.clone());
             }
             MessageStreamEvent::MessageDelta { delta, usage } => {
-                if let Some(stop_reason) = delta.stop_reason {
-                    self.stop_reason = Some(stop_reason);
+                if delta.stop_reason.is_some() {
+                    self.stop_reason = delta.stop_reason.clone();
+                }
+                if delta.stop_sequence.is_some() {
+                    self.stop_sequence = delta.stop_sequence.clone();
+                }
+                if delta.container.is_some() {
+                    self.container = delta.container.clone();
                 }
                 self.output_tokens = usage.output_tokens;
+                self.update_usage(usage);
             }
             MessageStreamEvent::MessageStop => {
                 self.is_complete = true;
             }
-            MessageStreamEvent::ContentBlockDelta { delta, .. } => match delta {
-                ContentBlockDelta::TextDelta { text } => {
-                    self.text.push_str(text);
+            MessageStreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => {
+                if matches!(
+                    content_block,
+                    ContentBlock::ToolUse { .. }
+                        | ContentBlock::ServerToolUse { .. }
+                        | ContentBlock::McpToolUse { .. }
+                ) {
+                    self.input_json.insert(*index, PartialJson::new());
                 }
-                ContentBlockDelta::ThinkingDelta { thinking } => {
-                    self.thinking.push_str(thinking);
+
+                if *index >= self.content.len() {
+                    self.content.resize(
+                        index + 1,
+                        ContentBlock::Text {
+                            text: String::new(),
+                            citations: None,
+                        },
+                    );
+                }
+                self.content[*index] = content_block.clone();
+            }
+            MessageStreamEvent::ContentBlockDelta { index, delta } => {
+                self.metrics.record_delta(Instant::now());
+                match delta {
+                    ContentBlockDelta::TextDelta { text } => {
+                        self.text.push_str(text);
+                    }
+                    ContentBlockDelta::ThinkingDelta { thinking } => {
+                        self.thinking.push_str(thinking);
+                    }
+                    ContentBlockDelta::InputJsonDelta { partial_json } => {
+                        if let Some(json) = self.input_json.get_mut(index) {
+                            json.append(partial_json);
+                        }
+                    }
+                    _ => {}
                 }
-                _ => {}
-            },
+                if let Some(block) = self.content.get_mut(*index) {
+                    apply_delta(block, delta);
+                }
+            }
+            MessageStreamEvent::ContentBlockStop { index } => {
+                let Some(json) = self.input_json.remove(index) else {
+                    return;
+                };
+                let parsed = json.finish();
+                if let (
+                    Some(
+                        ContentBlock::ToolUse { input, .. }
+                        | ContentBlock::ServerToolUse { input, .. }
+                        | ContentBlock::McpToolUse { input, .. },
+                    ),
+                    Ok(parsed),
+                ) = (self.content.get_mut(*index), parsed)
+                {
+                    *input = parsed;
+                }
+            }
             _ => {}
         }
     }
 
+    /// Apply the cumulative counts of a `message_delta` event.
+    fn update_usage(&mut self, delta: &MessageDeltaUsage) {
+        if let Some(usage) = &mut self.usage {
+            usage.output_tokens = delta.output_tokens;
+            if let Some(input_tokens) = delta.input_tokens {
+                usage.input_tokens = input_tokens;
+            }
+            if delta.cache_creation_input_tokens.is_some() {
+                usage.cache_creation_input_tokens = delta.cache_creation_input_tokens;
+            }
+            if delta.cache_read_input_tokens.is_some() {
+                usage.cache_read_input_tokens = delta.cache_read_input_tokens;
+            }
+            if delta.server_tool_use.is_some() {
+                usage.server_tool_use = delta.server_tool_use.clone();
+            }
+        }
+        if delta.server_tool_use.is_some() {
+            self.server_tool_use = delta.server_tool_use.clone();
+        }
+    }
+
+    /// Output tokens generated per second, measured from the first delta to
+    /// the last.
+    ///
+    /// `None` until at least two deltas have arrived.
+    pub fn tokens_per_second(&self) -> Option<f64> {
+        let duration = self.metrics.generation_duration()?;
+        if duration.is_zero() {
+            return None;
+        }
+        Some(f64::from(self.output_tokens) / duration.as_secs_f64())
+    }
+
+    /// Get the best-effort input of a tool use block that is still streaming.
+    ///
+    /// Returns `None` once the block has stopped; its complete input is then
+    /// in [`content`](Self::content).
+    pub fn partial_input(&self, index: usize) -> Option<&Value> {
+        self.input_json.get(&index).and_then(PartialJson::value)
+    }
+
     /// Get the final message with accumulated content.
     pub fn into_message(self) -> Option<Message> {
         self.message.map(|mut msg| {
-            // Update usage with final output tokens
+            msg.content = self.content;
+            if let Some(usage) = self.usage {
+                msg.usage = usage;
+            }
             msg.usage.output_tokens = self.output_tokens;
             msg.stop_reason = self.stop_reason;
+            msg.stop_sequence = self.stop_sequence;
+            if self.container.is_some() {
+                msg.container = self.container;
+            }
             msg
         })
     }
 }
+
+/// Timestamps recorded while a stream is consumed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamMetrics {
+    /// When the request was sent, or when the stream was created if it did
+    /// not come from a request.
+    pub started_at: Option<Instant>,
+
+    /// When the first chunk of the body was received.
+    pub first_byte_at: Option<Instant>,
+
+    /// When the first content block delta was received.
+    pub first_delta_at: Option<Instant>,
+
+    /// When the latest content block delta was received.
+    pub last_delta_at: Option<Instant>,
+
+    /// The number of content block deltas received.
+    pub deltas: u32,
+}
+
+impl StreamMetrics {
+    /// Time from the start of the stream to its first byte.
+    pub fn time_to_first_byte(&self) -> Option<Duration> {
+        Some(self.first_byte_at?.duration_since(self.started_at?))
+    }
+
+    /// Time from the start of the stream to its first content delta.
+    pub fn time_to_first_token(&self) -> Option<Duration> {
+        Some(self.first_delta_at?.duration_since(self.started_at?))
+    }
+
+    /// Time from the first content delta to the latest one.
+    pub fn generation_duration(&self) -> Option<Duration> {
+        Some(self.last_delta_at?.duration_since(self.first_delta_at?))
+    }
+
+    /// Record a content block delta received at `at`.
+    fn record_delta(&mut self, at: Instant) {
+        self.first_delta_at.get_or_insert(at);
+        self.last_delta_at = Some(at);
+        self.deltas += 1;
+    }
+}
+
+/// Apply a text, citation, thinking or signature delta to its content block.
+fn apply_delta(block: &mut ContentBlock, delta: &ContentBlockDelta) {
+    match (block, delta) {
+        (ContentBlock::Text { text, .. }, ContentBlockDelta::TextDelta { text: delta }) => {
+            text.push_str(delta);
+        }
+        (ContentBlock::Text { citations, .. }, ContentBlockDelta::CitationsDelta { citation }) => {
+            if let Ok(citation) = serde_json::from_value::<Citation>(citation.clone()) {
+                citations.get_or_insert_with(Vec::new).push(citation);
+            }
+        }
+        (
+            ContentBlock::Thinking { thinking, .. },
+            ContentBlockDelta::ThinkingDelta { thinking: delta },
+        ) => {
+            thinking.push_str(delta);
+        }
+        (
+            ContentBlock::Thinking { signature, .. },
+            ContentBlockDelta::SignatureDelta { signature: delta },
+        ) => {
+            signature.push_str(delta);
+        }
+        _ => {}
+    }
+}
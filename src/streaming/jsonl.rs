@@ -0,0 +1,90 @@
+//! JSON Lines (JSONL) decoder.
+
+use bytes::Bytes;
+
+/// JSONL decoder state.
+#[derive(Debug, Default)]
+pub struct JsonlDecoder {
+    /// Buffer for an incomplete trailing line.
+    buffer: Vec<u8>,
+}
+
+impl JsonlDecoder {
+    /// Create a new JSONL decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode bytes into complete, non-empty lines.
+    pub fn decode(&mut self, bytes: Bytes) -> Vec<String> {
+        // The buffered partial line has no newline, so only the new bytes
+        // need scanning.
+        let scanned = self.buffer.len();
+        self.buffer.extend_from_slice(&bytes);
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for (offset, _) in bytes.iter().enumerate().filter(|(_, &b)| b == b'\n') {
+            let end = scanned + offset;
+            if let Some(line) = Self::finish_line(&self.buffer[start..end]) {
+                lines.push(line);
+            }
+            start = end + 1;
+        }
+        self.buffer.drain(..start);
+
+        lines
+    }
+
+    /// Flush the final line if the body did not end with a newline.
+    pub fn flush(&mut self) -> Option<String> {
+        let remaining = std::mem::take(&mut self.buffer);
+        Self::finish_line(&remaining)
+    }
+
+    /// Convert raw line bytes into a trimmed string, skipping blank lines.
+    fn finish_line(line: &[u8]) -> Option<String> {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim();
+        if line.is_empty() {
+            None
+        } else {
+            Some(line.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_lines() {
+        let mut decoder = JsonlDecoder::new();
+        let lines = decoder.decode(Bytes::from("{\"a\":1}\n{\"b\":2}\n"));
+        assert_eq!(lines, vec!["{\"a\":1}", "{\"b\":2}"]);
+        assert!(decoder.flush().is_none());
+    }
+
+    #[test]
+    fn test_decode_split_line() {
+        let mut decoder = JsonlDecoder::new();
+        assert!(decoder.decode(Bytes::from("{\"par")).is_empty());
+
+        let lines = decoder.decode(Bytes::from("tial\":true}\r\n\n{\"next\""));
+        assert_eq!(lines, vec!["{\"partial\":true}"]);
+        assert_eq!(decoder.flush().as_deref(), Some("{\"next\""));
+    }
+
+    #[test]
+    fn test_decode_split_utf8() {
+        let mut decoder = JsonlDecoder::new();
+        let text = "\"héllo\"\n".as_bytes();
+        assert!(decoder
+            .decode(Bytes::copy_from_slice(&text[..3]))
+            .is_empty());
+
+        let lines = decoder.decode(Bytes::copy_from_slice(&text[3..]));
+        assert_eq!(lines, vec!["\"héllo\""]);
+    }
+}
@@ -0,0 +1,158 @@
+//! Message stream for single-threaded executors.
+
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use bytes::Bytes;
+use futures::stream::LocalBoxStream;
+use futures::Stream;
+use pin_project_lite::pin_project;
+
+use crate::error::{AnthropicError, Result};
+use crate::types::Message;
+
+use super::events::{MessageStreamEvent, RawStreamEvent, StreamMetrics, StreamState};
+use super::protocol::ProtocolValidator;
+use super::stream::{decode_events, StreamCore};
+
+pin_project! {
+    /// A stream of message events read from a byte stream that is not `Send`.
+    ///
+    /// Behaves like [`MessageStream`](super::MessageStream), for SSE bodies
+    /// read by your own single-threaded HTTP client, such as one running on a
+    /// tokio `LocalSet`. The client's own streams are `Send` and returned as
+    /// [`MessageStream`](super::MessageStream). Created with
+    /// [`from_byte_stream`](Self::from_byte_stream).
+    pub struct LocalMessageStream {
+        #[pin]
+        inner: LocalBoxStream<'static, Result<Vec<RawStreamEvent>>>,
+        core: StreamCore,
+    }
+}
+
+impl LocalMessageStream {
+    /// Create a message stream from a stream of SSE bytes.
+    pub fn from_byte_stream<S, E>(bytes: S) -> Self
+    where
+        S: Stream<Item = std::result::Result<Bytes, E>> + 'static,
+        E: Into<AnthropicError> + 'static,
+    {
+        use futures::{StreamExt, TryStreamExt};
+
+        Self {
+            inner: decode_events(bytes.map_err(Into::into).boxed_local(), None).boxed_local(),
+            core: StreamCore::new(),
+        }
+    }
+
+    /// Validate the order of events.
+    ///
+    /// See [`MessageStream::strict`](super::MessageStream::strict).
+    pub fn strict(mut self) -> Self {
+        self.core.validator = Some(ProtocolValidator::default());
+        self
+    }
+
+    /// Get the current accumulated state.
+    pub fn state(&self) -> &StreamState {
+        &self.core.state
+    }
+
+    /// Get the timing of the stream so far.
+    pub fn metrics(&self) -> &StreamMetrics {
+        &self.core.state.metrics
+    }
+
+    /// Get the accumulated text so far.
+    pub fn text(&self) -> &str {
+        &self.core.state.text
+    }
+
+    /// Get the accumulated thinking so far.
+    pub fn thinking(&self) -> &str {
+        &self.core.state.thinking
+    }
+
+    /// Check if the stream has completed.
+    pub fn is_complete(&self) -> bool {
+        self.core.state.is_complete
+    }
+
+    /// Consume the stream and collect all text.
+    pub async fn collect_text(mut self) -> Result<String> {
+        use futures::StreamExt;
+
+        while let Some(result) = self.next().await {
+            result?;
+        }
+
+        Ok(self.core.state.text)
+    }
+
+    /// Consume the stream and return the complete message.
+    ///
+    /// See [`MessageStream::final_message`](super::MessageStream::final_message).
+    pub async fn final_message(mut self) -> Result<Message> {
+        use futures::StreamExt;
+
+        while let Some(event) = self.next().await {
+            event?;
+        }
+
+        self.core.into_message()
+    }
+}
+
+impl Stream for LocalMessageStream {
+    type Item = Result<MessageStreamEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(event) = this.core.next_event() {
+                return Poll::Ready(Some(event));
+            }
+            if this.core.finished {
+                return Poll::Ready(this.core.finish());
+            }
+
+            let chunk = ready!(this.inner.as_mut().poll_next(cx));
+            if let Some(error) = this.core.receive(chunk) {
+                return Poll::Ready(Some(Err(error)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::rc::Rc;
+
+    #[tokio::test]
+    async fn test_local_stream_accepts_non_send_body() {
+        // An `Rc` makes the body stream `!Send`.
+        let marker = Rc::new(());
+        let body = futures::stream::iter([concat!(
+            "event: message_start\n",
+            r#"data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","content":[],"model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+            "\n\n",
+            "event: message_stop\n",
+            r#"data: {"type":"message_stop"}"#,
+            "\n\n",
+        )])
+        .map(move |chunk| {
+            let _ = &marker;
+            Ok::<_, AnthropicError>(Bytes::from(chunk))
+        });
+
+        let message = LocalMessageStream::from_byte_stream(body)
+            .strict()
+            .final_message()
+            .await
+            .unwrap();
+        assert_eq!(message.id, "msg_1");
+    }
+}
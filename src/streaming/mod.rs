@@ -1,11 +1,28 @@
 //! Streaming support for the Anthropic API.
 
+mod batch_results;
+mod completion;
 mod events;
+mod jsonl;
+mod local;
+mod partial_json;
+mod protocol;
 mod sse;
 mod stream;
 
+pub use batch_results::{BatchResultsStream, BlockingBatchResultsStream};
+pub use completion::{
+    BlockingCompletionStream, CompletionChunk, CompletionStream, CompletionStreamEvent,
+};
 pub use events::{
-    ContentBlockDelta, MessageDelta, MessageDeltaUsage, MessageStreamEvent, RawStreamEvent,
-    StreamError, StreamState,
+    ContentBlockDelta, MessageDelta, MessageStreamEvent, RawStreamEvent, StreamError,
+    StreamMetrics, StreamState,
 };
+pub use local::LocalMessageStream;
+pub use partial_json::PartialJson;
 pub use stream::{BlockingMessageStream, MessageStream};
+
+pub use crate::types::MessageDeltaUsage;
+
+#[cfg(feature = "mcp")]
+pub(crate) use sse::SseDecoder;
@@ -0,0 +1,319 @@
+//! Best-effort parsing of incomplete JSON.
+
+use std::sync::OnceLock;
+
+use serde_json::Value;
+
+/// Accumulates `input_json_delta` fragments and parses them on request.
+///
+/// When the value is read the buffer is completed into the most recent valid
+/// JSON value: open strings are closed, dangling keys, commas and partial
+/// literals are dropped, and open objects and arrays are closed. This lets a
+/// UI render tool arguments while they stream. The buffer is parsed at most
+/// once per fragment, and only if the value is read.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::streaming::PartialJson;
+/// use serde_json::json;
+///
+/// let mut input = PartialJson::new();
+/// input.push(r#"{"location": "San Fr"#);
+/// assert_eq!(input.value(), Some(&json!({ "location": "San Fr" })));
+///
+/// input.push(r#"ancisco", "unit": "cel"#);
+/// assert_eq!(
+///     input.value(),
+///     Some(&json!({ "location": "San Francisco", "unit": "cel" })),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PartialJson {
+    buffer: String,
+    /// The buffer parsed, once read since the last fragment.
+    parsed: OnceLock<Option<Value>>,
+    /// The last value parsed from an earlier buffer.
+    last: Option<Value>,
+}
+
+impl PartialJson {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a fragment and return the value parsed so far.
+    pub fn push(&mut self, fragment: &str) -> Option<&Value> {
+        self.append(fragment);
+        self.value()
+    }
+
+    /// Append a fragment without parsing the buffer.
+    pub fn append(&mut self, fragment: &str) {
+        if let Some(Some(value)) = self.parsed.take() {
+            self.last = Some(value);
+        }
+        self.buffer.push_str(fragment);
+    }
+
+    /// Get the value parsed so far, if any.
+    pub fn value(&self) -> Option<&Value> {
+        self.parsed
+            .get_or_init(|| parse_partial(&self.buffer))
+            .as_ref()
+            .or(self.last.as_ref())
+    }
+
+    /// Get the raw JSON received so far.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Parse the complete buffer strictly.
+    ///
+    /// An empty buffer parses as an empty object, which is what the API
+    /// streams for a tool called without input.
+    pub fn finish(&self) -> serde_json::Result<Value> {
+        if self.buffer.trim().is_empty() {
+            Ok(Value::Object(Default::default()))
+        } else {
+            serde_json::from_str(&self.buffer)
+        }
+    }
+}
+
+/// Parse incomplete JSON into the most recent valid value.
+fn parse_partial(json: &str) -> Option<Value> {
+    let mut tokens = tokenize(json);
+    strip_incomplete(&mut tokens);
+
+    let mut completed = String::new();
+    let mut open = Vec::new();
+    for token in &tokens {
+        match token.kind {
+            Kind::Open(close) => open.push(close),
+            Kind::Close => {
+                open.pop();
+            }
+            _ => {}
+        }
+        completed.push_str(&token.text);
+    }
+    while let Some(close) = open.pop() {
+        completed.push(close);
+    }
+
+    serde_json::from_str(&completed).ok()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// `{` or `[`, holding the character that closes it.
+    Open(char),
+    Close,
+    Colon,
+    Comma,
+    String,
+    Number,
+    Literal,
+}
+
+#[derive(Debug)]
+struct Token {
+    kind: Kind,
+    text: String,
+}
+
+/// Split JSON into tokens, completing a trailing string and trimming a
+/// trailing number or literal that cannot be valid yet.
+fn tokenize(json: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = json.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        let (kind, text) = match ch {
+            '{' => (Kind::Open('}'), ch.to_string()),
+            '[' => (Kind::Open(']'), ch.to_string()),
+            '}' | ']' => (Kind::Close, ch.to_string()),
+            ':' => (Kind::Colon, ch.to_string()),
+            ',' => (Kind::Comma, ch.to_string()),
+            '"' => {
+                let mut text = String::from('"');
+                let mut closed = false;
+                while let Some(ch) = chars.next() {
+                    text.push(ch);
+                    match ch {
+                        '\\' => match chars.next() {
+                            Some(escaped) => text.push(escaped),
+                            None => {
+                                text.pop();
+                            }
+                        },
+                        '"' => {
+                            closed = true;
+                            break;
+                        }
+                        _ => {}
+                    }
+                }
+                if !closed {
+                    // Drop a partial `\uXXXX` escape before closing the string.
+                    if let Some(start) = text.rfind("\\u") {
+                        if text.len() - start < 6 && !text[..start].ends_with('\\') {
+                            text.truncate(start);
+                        }
+                    }
+                    text.push('"');
+                }
+                (Kind::String, text)
+            }
+            '-' | '0'..='9' => {
+                let mut text = ch.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !matches!(next, '0'..='9' | '.' | 'e' | 'E' | '+' | '-') {
+                        break;
+                    }
+                    text.push(next);
+                    chars.next();
+                }
+                if chars.peek().is_none() {
+                    let trimmed = text.trim_end_matches(['.', 'e', 'E', '+', '-']).len();
+                    text.truncate(trimmed);
+                    if text.is_empty() {
+                        continue;
+                    }
+                }
+                (Kind::Number, text)
+            }
+            'a'..='z' => {
+                let mut text = ch.to_string();
+                while let Some(&next) = chars.peek() {
+                    if !next.is_ascii_lowercase() {
+                        break;
+                    }
+                    text.push(next);
+                    chars.next();
+                }
+                if !matches!(text.as_str(), "true" | "false" | "null") {
+                    continue;
+                }
+                (Kind::Literal, text)
+            }
+            _ => continue,
+        };
+        tokens.push(Token { kind, text });
+    }
+
+    tokens
+}
+
+/// Drop trailing tokens that leave the last value incomplete: commas, object
+/// keys without a value, and colons.
+fn strip_incomplete(tokens: &mut Vec<Token>) {
+    loop {
+        let Some(last) = tokens.last() else {
+            return;
+        };
+
+        match last.kind {
+            Kind::Comma => {
+                tokens.pop();
+            }
+            Kind::Colon => {
+                tokens.pop();
+                tokens.pop();
+            }
+            Kind::String if is_object_key(tokens) => {
+                tokens.pop();
+            }
+            _ => return,
+        }
+    }
+}
+
+/// Whether the last token is a key in an object rather than a value.
+fn is_object_key(tokens: &[Token]) -> bool {
+    let mut depth = 0;
+    let previous = tokens.len().checked_sub(2).map(|i| &tokens[i]);
+    if !matches!(
+        previous.map(|token| token.kind),
+        Some(Kind::Open('}') | Kind::Comma)
+    ) {
+        return false;
+    }
+
+    // Find the container the key belongs to.
+    for token in tokens[..tokens.len() - 1].iter().rev() {
+        match token.kind {
+            Kind::Close => depth += 1,
+            Kind::Open(close) if depth == 0 => return close == '}',
+            Kind::Open(_) => depth -= 1,
+            _ => {}
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_partial() {
+        let cases = [
+            ("", None),
+            ("{", Some(json!({}))),
+            (r#"{"loc"#, Some(json!({}))),
+            (r#"{"location""#, Some(json!({}))),
+            (r#"{"location": "#, Some(json!({}))),
+            (r#"{"location": "Par"#, Some(json!({ "location": "Par" }))),
+            (r#"{"a": 1, "#, Some(json!({ "a": 1 }))),
+            (r#"{"a": 1.5e"#, Some(json!({ "a": 1.5 }))),
+            (r#"{"a": -"#, Some(json!({}))),
+            (r#"{"a": tr"#, Some(json!({}))),
+            (
+                r#"{"a": true, "b": [1, {"c": nu"#,
+                Some(json!({ "a": true, "b": [1, {}] })),
+            ),
+            (r#"{"a": ["x", "y"#, Some(json!({ "a": ["x", "y"] }))),
+            (r#"{"a": "line\"#, Some(json!({ "a": "line" }))),
+            (r#"{"a": "caf\u00e"#, Some(json!({ "a": "caf" }))),
+            (r#"{"a": {"b": 2}}"#, Some(json!({ "a": { "b": 2 } }))),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse_partial(input), expected, "input: {}", input);
+        }
+    }
+
+    #[test]
+    fn test_push_keeps_last_value() {
+        let mut json = PartialJson::new();
+        assert_eq!(
+            json.push(r#"{"query": "rust"#),
+            Some(&json!({ "query": "rust" }))
+        );
+        assert_eq!(
+            json.push(r#"", "limit": 1"#).cloned(),
+            Some(json!({ "query": "rust", "limit": 1 }))
+        );
+        assert_eq!(
+            json.push("0}").cloned(),
+            Some(json!({ "query": "rust", "limit": 10 }))
+        );
+        assert_eq!(
+            json.finish().unwrap(),
+            json!({ "query": "rust", "limit": 10 })
+        );
+        assert_eq!(PartialJson::new().finish().unwrap(), json!({}));
+
+        let mut json = PartialJson::new();
+        json.append(r#"{"query": "ru"#);
+        json.append(r#"st", "#);
+        assert_eq!(json.value(), Some(&json!({ "query": "rust" })));
+        json.append("x");
+        assert_eq!(json.value(), Some(&json!({ "query": "rust" })));
+    }
+}
@@ -0,0 +1,278 @@
+//! Validation of stream event ordering.
+
+use crate::types::ContentBlock;
+
+use super::events::{ContentBlockDelta, MessageStreamEvent};
+
+/// Checks that events arrive in the order the streaming protocol defines.
+#[derive(Debug, Default)]
+pub(crate) struct ProtocolValidator {
+    started: bool,
+    stopped: bool,
+    /// The number of content blocks started so far.
+    blocks: usize,
+    /// The kind of the open content block, if any.
+    open: Option<BlockKind>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlockKind {
+    Text,
+    Thinking,
+    ToolUse,
+    Other,
+}
+
+impl ProtocolValidator {
+    /// Check the next event, describing the violation if it is out of order.
+    pub(crate) fn check(&mut self, event: &MessageStreamEvent) -> Result<(), String> {
+        if let MessageStreamEvent::Ping
+        | MessageStreamEvent::Error { .. }
+        | MessageStreamEvent::Unknown(_) = event
+        {
+            return Ok(());
+        }
+        if self.stopped {
+            return Err(format!(
+                "{} received after message_stop",
+                event.event_type()
+            ));
+        }
+        if !self.started && !matches!(event, MessageStreamEvent::MessageStart { .. }) {
+            return Err(format!(
+                "{} received before message_start",
+                event.event_type()
+            ));
+        }
+
+        match event {
+            MessageStreamEvent::MessageStart { .. } => {
+                if self.started {
+                    return Err("message_start received twice".to_string());
+                }
+                self.started = true;
+            }
+            MessageStreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => {
+                self.check_closed(event)?;
+                if *index != self.blocks {
+                    return Err(format!(
+                        "content_block_start for index {} but expected index {}",
+                        index, self.blocks
+                    ));
+                }
+                self.blocks += 1;
+                self.open = Some(BlockKind::of(content_block));
+            }
+            MessageStreamEvent::ContentBlockDelta { index, delta } => {
+                let kind = self.check_open(*index, event)?;
+                if !kind.accepts(delta) {
+                    return Err(format!(
+                        "{} for content block {} of kind {:?}",
+                        delta_type(delta),
+                        index,
+                        kind
+                    ));
+                }
+            }
+            MessageStreamEvent::ContentBlockStop { index } => {
+                self.check_open(*index, event)?;
+                self.open = None;
+            }
+            MessageStreamEvent::MessageDelta { .. } => {
+                self.check_closed(event)?;
+            }
+            MessageStreamEvent::MessageStop => {
+                self.check_closed(event)?;
+                self.stopped = true;
+            }
+            MessageStreamEvent::Ping
+            | MessageStreamEvent::Error { .. }
+            | MessageStreamEvent::Unknown(_) => {}
+        }
+
+        Ok(())
+    }
+
+    /// Check that the stream ended after `message_stop`.
+    pub(crate) fn finish(&self) -> Result<(), String> {
+        if self.stopped {
+            Ok(())
+        } else {
+            Err("stream ended before message_stop".to_string())
+        }
+    }
+
+    /// Check that `index` is the open content block and return its kind.
+    fn check_open(&self, index: usize, event: &MessageStreamEvent) -> Result<BlockKind, String> {
+        match self.open {
+            Some(kind) if index + 1 == self.blocks => Ok(kind),
+            _ => Err(format!(
+                "{} for content block {}, which is not open",
+                event.event_type(),
+                index
+            )),
+        }
+    }
+
+    /// Check that no content block is open.
+    fn check_closed(&self, event: &MessageStreamEvent) -> Result<(), String> {
+        match self.open {
+            Some(_) => Err(format!(
+                "{} received while content block {} is open",
+                event.event_type(),
+                self.blocks - 1
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl BlockKind {
+    fn of(block: &ContentBlock) -> Self {
+        match block {
+            ContentBlock::Text { .. } => BlockKind::Text,
+            ContentBlock::Thinking { .. } => BlockKind::Thinking,
+            ContentBlock::ToolUse { .. }
+            | ContentBlock::ServerToolUse { .. }
+            | ContentBlock::McpToolUse { .. } => BlockKind::ToolUse,
+            _ => BlockKind::Other,
+        }
+    }
+
+    /// Whether a block of this kind can receive `delta`.
+    fn accepts(self, delta: &ContentBlockDelta) -> bool {
+        match delta {
+            ContentBlockDelta::TextDelta { .. } | ContentBlockDelta::CitationsDelta { .. } => {
+                self == BlockKind::Text
+            }
+            ContentBlockDelta::InputJsonDelta { .. } => self == BlockKind::ToolUse,
+            ContentBlockDelta::ThinkingDelta { .. } | ContentBlockDelta::SignatureDelta { .. } => {
+                self == BlockKind::Thinking
+            }
+            ContentBlockDelta::Unknown(_) => true,
+        }
+    }
+}
+
+fn delta_type(delta: &ContentBlockDelta) -> &str {
+    match delta {
+        ContentBlockDelta::TextDelta { .. } => "text_delta",
+        ContentBlockDelta::InputJsonDelta { .. } => "input_json_delta",
+        ContentBlockDelta::ThinkingDelta { .. } => "thinking_delta",
+        ContentBlockDelta::SignatureDelta { .. } => "signature_delta",
+        ContentBlockDelta::CitationsDelta { .. } => "citations_delta",
+        ContentBlockDelta::Unknown(value) => value["type"].as_str().unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(value: serde_json::Value) -> MessageStreamEvent {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn start() -> MessageStreamEvent {
+        event(json!({
+            "type": "message_start",
+            "message": {
+                "id": "msg_1", "type": "message", "role": "assistant", "content": [],
+                "model": "claude-sonnet-4-5",
+                "usage": { "input_tokens": 1, "output_tokens": 1 }
+            }
+        }))
+    }
+
+    fn text_start(index: usize) -> MessageStreamEvent {
+        event(json!({
+            "type": "content_block_start",
+            "index": index,
+            "content_block": { "type": "text", "text": "" }
+        }))
+    }
+
+    #[test]
+    fn test_valid_order() {
+        let mut validator = ProtocolValidator::default();
+        for event in [
+            start(),
+            MessageStreamEvent::Ping,
+            text_start(0),
+            event(json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "text_delta", "text": "Hi" }
+            })),
+            event(json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "sparkle_delta", "sparkles": 3 }
+            })),
+            event(json!({ "type": "progress", "percent": 50 })),
+            MessageStreamEvent::ContentBlockStop { index: 0 },
+            text_start(1),
+            MessageStreamEvent::ContentBlockStop { index: 1 },
+            MessageStreamEvent::MessageStop,
+        ] {
+            validator.check(&event).unwrap();
+        }
+        validator.finish().unwrap();
+    }
+
+    #[test]
+    fn test_mcp_tool_use_accepts_input_json() {
+        let mut validator = ProtocolValidator::default();
+        for event in [
+            start(),
+            event(json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": {
+                    "type": "mcp_tool_use", "id": "mcptoolu_1", "name": "search",
+                    "server_name": "docs", "input": {}
+                }
+            })),
+            event(json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "input_json_delta", "partial_json": "{}" }
+            })),
+            MessageStreamEvent::ContentBlockStop { index: 0 },
+            MessageStreamEvent::MessageStop,
+        ] {
+            validator.check(&event).unwrap();
+        }
+        validator.finish().unwrap();
+    }
+
+    #[test]
+    fn test_violations() {
+        let mut validator = ProtocolValidator::default();
+        assert!(validator.check(&text_start(0)).is_err());
+
+        let mut validator = ProtocolValidator::default();
+        validator.check(&start()).unwrap();
+        let error = validator.check(&text_start(1)).unwrap_err();
+        assert_eq!(
+            error,
+            "content_block_start for index 1 but expected index 0"
+        );
+
+        let mut validator = ProtocolValidator::default();
+        validator.check(&start()).unwrap();
+        validator.check(&text_start(0)).unwrap();
+        let thinking = event(json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": { "type": "thinking_delta", "thinking": "Hmm" }
+        }));
+        assert!(validator.check(&thinking).is_err());
+        assert!(validator.check(&MessageStreamEvent::MessageStop).is_err());
+        assert!(validator.finish().is_err());
+    }
+}
@@ -105,6 +105,11 @@ impl SseDecoder {
         Some(RawStreamEvent { event, data })
     }
 
+    /// The number of bytes held for the event being decoded.
+    pub(crate) fn buffered_len(&self) -> usize {
+        self.buffer.len() + self.data_lines.iter().map(String::len).sum::<usize>()
+    }
+
     /// Check if there's buffered data.
     pub fn has_buffered_data(&self) -> bool {
         !self.buffer.is_empty() || !self.data_lines.is_empty()
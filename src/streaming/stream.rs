@@ -1,62 +1,195 @@
 //! Message stream implementation.
 
+use std::collections::VecDeque;
 use std::pin::Pin;
-use std::task::{Context, Poll};
+use std::task::{ready, Context, Poll};
+use std::time::Instant;
 
 use bytes::Bytes;
+use futures::stream::BoxStream;
 use futures::Stream;
 use pin_project_lite::pin_project;
 use reqwest::Response;
+use tokio::io::{AsyncRead, AsyncWrite};
 
+use crate::client::{block_on, RequestPermit};
 use crate::error::{AnthropicError, Result};
+use crate::types::{ContentBlock, Message};
 
-use super::events::{MessageStreamEvent, RawStreamEvent, StreamState};
+use super::events::{
+    ContentBlockDelta, MessageStreamEvent, RawStreamEvent, StreamMetrics, StreamState,
+};
+use super::protocol::ProtocolValidator;
 use super::sse::SseDecoder;
 
 pin_project! {
     /// A stream of message events from the API.
     pub struct MessageStream {
         #[pin]
-        inner: futures::stream::BoxStream<'static, std::result::Result<Bytes, reqwest::Error>>,
-        decoder: SseDecoder,
-        state: StreamState,
-        finished: bool,
+        inner: BoxStream<'static, Result<Vec<RawStreamEvent>>>,
+        core: StreamCore,
+        tee: Option<Tee>,
     }
 }
 
 impl MessageStream {
-    /// Create a new message stream from a response.
-    pub(crate) fn new(response: Response) -> Self {
-        use futures::StreamExt;
+    /// Create a new message stream from a response to a request sent at
+    /// `started_at`, holding the client's concurrency `permit` until dropped
+    /// and failing on events larger than `max_event_size` bytes.
+    pub(crate) fn new(
+        response: Response,
+        started_at: Instant,
+        permit: Option<RequestPermit>,
+        max_event_size: Option<usize>,
+    ) -> Self {
+        use futures::{StreamExt, TryStreamExt};
 
-        let inner = response.bytes_stream().boxed();
+        let request_id = request_id(&response);
+        let bytes = response
+            .bytes_stream()
+            .map(move |chunk| {
+                let _permit = &permit;
+                chunk
+            })
+            .map_err(AnthropicError::Connection);
+        let mut stream = Self {
+            inner: decode_events(bytes.boxed(), max_event_size).boxed(),
+            core: StreamCore::new(),
+            tee: None,
+        };
+        stream.core.request_id = request_id;
+        stream.core.state.metrics.started_at = Some(started_at);
+        stream
+    }
+
+    /// Create a new message stream from raw SSE bytes.
+    pub(crate) fn from_bytes(inner: BoxStream<'static, Result<Bytes>>) -> Self {
+        use futures::StreamExt;
 
         Self {
-            inner,
-            decoder: SseDecoder::new(),
-            state: StreamState::new(),
-            finished: false,
+            inner: decode_events(inner, None).boxed(),
+            core: StreamCore::new(),
+            tee: None,
         }
     }
 
+    /// Replay a stream from SSE text, such as a transcript written by
+    /// [`tee_to`](Self::tee_to).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::MessageStream;
+    ///
+    /// # async fn run() -> Result<(), anthropic_sdk::AnthropicError> {
+    /// let transcript = tokio::fs::File::open("stream.sse").await.unwrap();
+    /// let message = MessageStream::from_reader(transcript).final_message().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader(reader: impl AsyncRead + Send + 'static) -> Self {
+        use futures::{StreamExt, TryStreamExt};
+
+        Self::from_bytes(
+            tokio_util::io::ReaderStream::new(reader)
+                .map_err(|e| AnthropicError::Stream {
+                    message: format!("Failed to read stream transcript: {}", e),
+                })
+                .boxed(),
+        )
+    }
+
+    /// Read and decode up to `capacity` events ahead of the consumer.
+    ///
+    /// A spawned task keeps reading the response while the consumer is busy,
+    /// so a slow consumer does not stall the connection and trigger an idle
+    /// disconnect on the server. Must be called within a tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn buffered(mut self, capacity: usize) -> Self {
+        use futures::StreamExt;
+
+        let (tx, rx) = tokio::sync::mpsc::channel(capacity);
+        let mut inner = std::mem::replace(&mut self.inner, futures::stream::empty().boxed());
+        tokio::spawn(async move {
+            while let Some(events) = inner.next().await {
+                let events = match events {
+                    Ok(events) => events.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                };
+                for event in events {
+                    if tx.send(event).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        self.inner = futures::stream::unfold(rx, |mut rx| async move {
+            let event = rx.recv().await?;
+            Some((event.map(|event| vec![event]), rx))
+        })
+        .boxed();
+        self
+    }
+
+    /// Validate the order of events.
+    ///
+    /// The stream yields [`AnthropicError::Stream`] describing the violation
+    /// when an event arrives out of protocol order: anything before
+    /// `message_start`, a content block starting at an unexpected index, a
+    /// delta or stop for a block that is not open or of the wrong kind, or the
+    /// stream ending without `message_stop`. This catches streams corrupted by
+    /// proxies early instead of producing a wrong message.
+    pub fn strict(mut self) -> Self {
+        self.core.validator = Some(ProtocolValidator::default());
+        self
+    }
+
+    /// Copy every SSE event to `writer` as it is received.
+    ///
+    /// Events are written in SSE format, so the transcript can be replayed
+    /// with [`from_reader`](Self::from_reader). The stream waits for each
+    /// write, and a write error is yielded once as [`AnthropicError::Stream`]
+    /// before the stream continues without the writer.
+    pub fn tee_to(mut self, writer: impl AsyncWrite + Send + 'static) -> Self {
+        self.tee = Some(Tee {
+            writer: Box::pin(writer),
+            buffer: Vec::new(),
+        });
+        self
+    }
+
     /// Get the current accumulated state.
     pub fn state(&self) -> &StreamState {
-        &self.state
+        &self.core.state
+    }
+
+    /// Get the timing of the stream so far.
+    pub fn metrics(&self) -> &StreamMetrics {
+        &self.core.state.metrics
+    }
+
+    /// Get the ID of the request that started the stream, if available.
+    pub fn request_id(&self) -> Option<&str> {
+        self.core.request_id.as_deref()
     }
 
     /// Get the accumulated text so far.
     pub fn text(&self) -> &str {
-        &self.state.text
+        &self.core.state.text
     }
 
     /// Get the accumulated thinking so far.
     pub fn thinking(&self) -> &str {
-        &self.state.thinking
+        &self.core.state.thinking
     }
 
     /// Check if the stream has completed.
     pub fn is_complete(&self) -> bool {
-        self.state.is_complete
+        self.core.state.is_complete
     }
 
     /// Consume the stream and collect all text.
@@ -67,7 +200,178 @@ impl MessageStream {
             result?;
         }
 
-        Ok(self.state.text)
+        Ok(self.core.state.text)
+    }
+
+    /// Consume the stream and return the complete message.
+    ///
+    /// The message is rebuilt from the events, with every content block
+    /// (text with citations, thinking with its signature, tool use with its
+    /// parsed input) as a non-streaming request would return it.
+    pub async fn final_message(mut self) -> Result<Message> {
+        use futures::StreamExt;
+
+        while let Some(event) = self.next().await {
+            event?;
+        }
+
+        self.core.into_message()
+    }
+
+    /// Drive the stream to completion, writing each text delta to `writer`.
+    ///
+    /// The writer is flushed after every delta, so output appears as it
+    /// streams. Returns the final accumulated state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams};
+    ///
+    /// # async fn run(client: AsyncAnthropic, params: MessageCreateParams) -> Result<(), anthropic_sdk::AnthropicError> {
+    /// let stream = client.messages().create_stream(params).await?;
+    /// let state = stream.write_text_to(tokio::io::stdout()).await?;
+    /// println!("\n[{} output tokens]", state.output_tokens);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_text_to<W>(mut self, writer: W) -> Result<StreamState>
+    where
+        W: AsyncWrite,
+    {
+        use futures::StreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        tokio::pin!(writer);
+        while let Some(event) = self.next().await {
+            if let MessageStreamEvent::ContentBlockDelta {
+                delta: ContentBlockDelta::TextDelta { text },
+                ..
+            } = event?
+            {
+                writer
+                    .write_all(text.as_bytes())
+                    .await
+                    .map_err(write_error)?;
+                writer.flush().await.map_err(write_error)?;
+            }
+        }
+
+        Ok(self.core.state)
+    }
+
+    /// Only the text deltas of the stream.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams};
+    /// use futures::StreamExt;
+    ///
+    /// # async fn run(client: AsyncAnthropic, params: MessageCreateParams) -> Result<(), anthropic_sdk::AnthropicError> {
+    /// let mut text = client.messages().create_stream(params).await?.only_text();
+    /// while let Some(chunk) = text.next().await {
+    ///     print!("{}", chunk?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn only_text(self) -> BoxStream<'static, Result<String>> {
+        self.filter_deltas(|delta| delta.as_text().map(String::from))
+    }
+
+    /// Only the thinking deltas of the stream.
+    pub fn only_thinking(self) -> BoxStream<'static, Result<String>> {
+        self.filter_deltas(|delta| delta.as_thinking().map(String::from))
+    }
+
+    /// The content blocks of the stream, each yielded complete once its
+    /// `content_block_stop` event arrives.
+    ///
+    /// Tool use blocks carry their full parsed input.
+    pub fn content_blocks(self) -> BoxStream<'static, Result<ContentBlock>> {
+        use futures::StreamExt;
+
+        futures::stream::unfold(self, |mut stream| async move {
+            loop {
+                match stream.next().await? {
+                    Ok(MessageStreamEvent::ContentBlockStop { index }) => {
+                        if let Some(block) = stream.core.state.content.get(index).cloned() {
+                            return Some((Ok(block), stream));
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Some((Err(e), stream)),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Keep the deltas `filter` maps to a value, and all errors.
+    fn filter_deltas<F>(self, filter: F) -> BoxStream<'static, Result<String>>
+    where
+        F: Fn(&ContentBlockDelta) -> Option<String> + Send + 'static,
+    {
+        use futures::StreamExt;
+
+        self.filter_map(move |event| {
+            let item = match event {
+                Ok(MessageStreamEvent::ContentBlockDelta { delta, .. }) => filter(&delta).map(Ok),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            };
+            futures::future::ready(item)
+        })
+        .boxed()
+    }
+
+    /// Re-serialize the events as an SSE body, for proxying the stream to
+    /// another client.
+    ///
+    /// The events still update this stream's state as they pass through. An
+    /// `error` event is passed on as an `error` frame rather than ending the
+    /// body, so the other client sees why the stream failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // In an axum handler
+    /// let stream = client.messages().create_stream(params).await?;
+    /// Response::builder()
+    ///     .header("content-type", "text/event-stream")
+    ///     .body(Body::from_stream(stream.into_sse()))
+    /// ```
+    pub fn into_sse(mut self) -> BoxStream<'static, Result<Bytes>> {
+        use futures::StreamExt;
+
+        self.core.forward_errors = true;
+        self.map(|event| Ok(Bytes::from(event?.to_sse()?))).boxed()
+    }
+
+    /// Drive the stream on a spawned task and receive its events on a channel.
+    ///
+    /// At most `capacity` events are buffered. The task stops when the stream
+    /// ends or the receiver is dropped. Must be called within a tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn into_channel(
+        mut self,
+        capacity: usize,
+    ) -> tokio::sync::mpsc::Receiver<Result<MessageStreamEvent>> {
+        use futures::StreamExt;
+
+        let (tx, rx) = tokio::sync::mpsc::channel(capacity);
+        tokio::spawn(async move {
+            while let Some(event) = self.next().await {
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        });
+        rx
     }
 }
 
@@ -78,13 +382,6 @@ fn parse_event(event: &RawStreamEvent) -> Result<MessageStreamEvent> {
         return Ok(MessageStreamEvent::Ping);
     }
 
-    // Handle error events
-    if event.event == "error" {
-        let error: super::events::StreamError =
-            serde_json::from_str(&event.data).map_err(AnthropicError::Json)?;
-        return Ok(MessageStreamEvent::Error { error });
-    }
-
     // Parse the data JSON, injecting the event type if needed
     let mut data: serde_json::Value =
         serde_json::from_str(&event.data).map_err(AnthropicError::Json)?;
@@ -104,66 +401,223 @@ impl Stream for MessageStream {
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut this = self.project();
 
-        if *this.finished {
-            return Poll::Ready(None);
-        }
-
         loop {
-            match this.inner.as_mut().poll_next(cx) {
-                Poll::Ready(Some(Ok(bytes))) => {
-                    // Decode SSE events from bytes
-                    let raw_events = this.decoder.decode(bytes);
-
-                    if let Some(raw_event) = raw_events.into_iter().next() {
-                        match parse_event(&raw_event) {
-                            Ok(event) => {
-                                // Update state
-                                this.state.update(&event);
-
-                                // Check if this is the final event
-                                if matches!(event, MessageStreamEvent::MessageStop) {
-                                    *this.finished = true;
-                                }
-
-                                return Poll::Ready(Some(Ok(event)));
-                            }
-                            Err(e) => {
-                                return Poll::Ready(Some(Err(e)));
-                            }
-                        }
-                    }
-
-                    // Continue reading if no events were produced
-                    continue;
+            let core = &mut *this.core;
+            if let Some(tee) = this.tee.as_mut() {
+                let done = core.finished && core.pending.is_empty();
+                let written = match ready!(tee.poll_write_all(cx)) {
+                    Ok(()) if done => ready!(tee.writer.as_mut().poll_flush(cx)),
+                    written => written,
+                };
+                if done || written.is_err() {
+                    *this.tee = None;
                 }
-                Poll::Ready(Some(Err(e))) => {
-                    *this.finished = true;
-                    return Poll::Ready(Some(Err(AnthropicError::Connection(e))));
+                if let Err(e) = written {
+                    return Poll::Ready(Some(Err(AnthropicError::Stream {
+                        message: format!("Failed to write stream transcript: {}", e),
+                    })));
                 }
-                Poll::Ready(None) => {
-                    // Stream ended, flush any remaining data
-                    if let Some(raw_event) = this.decoder.flush() {
-                        match parse_event(&raw_event) {
-                            Ok(event) => {
-                                this.state.update(&event);
-                                *this.finished = true;
-                                return Poll::Ready(Some(Ok(event)));
-                            }
-                            Err(e) => {
-                                *this.finished = true;
-                                return Poll::Ready(Some(Err(e)));
-                            }
-                        }
-                    }
+            }
 
-                    *this.finished = true;
-                    return Poll::Ready(None);
-                }
-                Poll::Pending => {
-                    return Poll::Pending;
+            if let Some(event) = core.next_event() {
+                return Poll::Ready(Some(event));
+            }
+            if core.finished {
+                return Poll::Ready(core.finish());
+            }
+
+            let chunk = ready!(this.inner.as_mut().poll_next(cx));
+            if let (Some(tee), Some(Ok(events))) = (this.tee.as_mut(), &chunk) {
+                events.iter().for_each(|event| tee.record(event));
+            }
+            if let Some(error) = core.receive(chunk) {
+                return Poll::Ready(Some(Err(error)));
+            }
+        }
+    }
+}
+
+/// Event parsing and accumulation shared by the message stream types.
+#[derive(Debug)]
+pub(super) struct StreamCore {
+    pub(super) pending: VecDeque<RawStreamEvent>,
+    pub(super) state: StreamState,
+    pub(super) request_id: Option<String>,
+    pub(super) validator: Option<ProtocolValidator>,
+    pub(super) finished: bool,
+    /// Yield `error` events as events instead of mapping them to errors.
+    pub(super) forward_errors: bool,
+}
+
+impl StreamCore {
+    pub(super) fn new() -> Self {
+        let mut state = StreamState::new();
+        state.metrics.started_at = Some(Instant::now());
+
+        Self {
+            pending: VecDeque::new(),
+            state,
+            request_id: None,
+            validator: None,
+            finished: false,
+            forward_errors: false,
+        }
+    }
+
+    /// Parse the next pending event, mapping error events and protocol
+    /// violations to errors, and update the state with it.
+    pub(super) fn next_event(&mut self) -> Option<Result<MessageStreamEvent>> {
+        let raw_event = self.pending.pop_front()?;
+        let event = match parse_event(&raw_event) {
+            Ok(MessageStreamEvent::Error { error }) if !self.forward_errors => {
+                Err(AnthropicError::from_error_type(
+                    &error.error_type,
+                    error.message,
+                    self.request_id.clone(),
+                ))
+            }
+            event => event,
+        };
+        let event = match (event, self.validator.as_mut()) {
+            (Ok(event), Some(validator)) => match validator.check(&event) {
+                Ok(()) => Ok(event),
+                Err(message) => Err(protocol_error(message)),
+            },
+            (event, _) => event,
+        };
+        if let Ok(event) = &event {
+            self.state.update(event);
+        }
+        Some(event)
+    }
+
+    /// The last item of a finished stream: a protocol violation if the
+    /// stream ended early in strict mode, otherwise nothing.
+    pub(super) fn finish(&mut self) -> Option<Result<MessageStreamEvent>> {
+        let validator = self.validator.take()?;
+        validator
+            .finish()
+            .err()
+            .map(|message| Err(protocol_error(message)))
+    }
+
+    /// The message rebuilt from the events.
+    pub(super) fn into_message(self) -> Result<Message> {
+        self.state
+            .into_message()
+            .ok_or_else(|| AnthropicError::Stream {
+                message: "Stream ended before message_start".to_string(),
+            })
+    }
+
+    /// Take in the next chunk of decoded events from the body, returning an
+    /// error that ends the stream.
+    pub(super) fn receive(
+        &mut self,
+        chunk: Option<Result<Vec<RawStreamEvent>>>,
+    ) -> Option<AnthropicError> {
+        match chunk {
+            Some(Ok(events)) => {
+                self.state
+                    .metrics
+                    .first_byte_at
+                    .get_or_insert_with(Instant::now);
+                self.pending.extend(events);
+                None
+            }
+            Some(Err(e)) => {
+                self.finished = true;
+                Some(e)
+            }
+            None => {
+                self.finished = true;
+                None
+            }
+        }
+    }
+}
+
+/// Get the request ID of a response.
+pub(super) fn request_id(response: &Response) -> Option<String> {
+    response
+        .headers()
+        .get("request-id")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from)
+}
+
+/// Decode a body of SSE bytes into the events of each chunk.
+///
+/// With `max_event_size`, an event whose data exceeds that many bytes ends
+/// the stream with an error.
+pub(super) fn decode_events<S>(
+    bytes: S,
+    max_event_size: Option<usize>,
+) -> impl Stream<Item = Result<Vec<RawStreamEvent>>>
+where
+    S: Stream<Item = Result<Bytes>> + Unpin,
+{
+    use futures::StreamExt;
+
+    let decoder = Some(SseDecoder::new());
+    futures::stream::unfold((bytes, decoder), move |(mut bytes, decoder)| async move {
+        let mut decoder = decoder?;
+        match bytes.next().await {
+            Some(Ok(chunk)) => {
+                let events = decoder.decode(chunk);
+                if let Some(limit) = max_event_size {
+                    let largest = events.iter().map(|event| event.data.len()).max();
+                    if decoder.buffered_len().max(largest.unwrap_or(0)) > limit {
+                        let error = AnthropicError::Stream {
+                            message: format!("Stream event exceeds the limit of {} bytes", limit),
+                        };
+                        return Some((Err(error), (bytes, None)));
+                    }
                 }
+                Some((Ok(events), (bytes, Some(decoder))))
             }
+            Some(Err(e)) => Some((Err(e), (bytes, Some(decoder)))),
+            None => Some((Ok(decoder.flush().into_iter().collect()), (bytes, None))),
         }
+    })
+}
+
+/// Convert a protocol violation into an SDK error.
+fn protocol_error(message: String) -> AnthropicError {
+    AnthropicError::Stream {
+        message: format!("Protocol violation: {}", message),
+    }
+}
+
+/// Convert an error writing streamed text into an SDK error.
+fn write_error(error: std::io::Error) -> AnthropicError {
+    AnthropicError::Stream {
+        message: format!("Failed to write streamed text: {}", error),
+    }
+}
+
+/// Writer receiving a copy of every SSE event.
+struct Tee {
+    writer: Pin<Box<dyn AsyncWrite + Send>>,
+    buffer: Vec<u8>,
+}
+
+impl Tee {
+    /// Queue an event for writing in SSE format.
+    fn record(&mut self, event: &RawStreamEvent) {
+        self.buffer.extend_from_slice(event.to_sse().as_bytes());
+    }
+
+    /// Write out everything queued so far.
+    fn poll_write_all(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while !self.buffer.is_empty() {
+            let written = ready!(self.writer.as_mut().poll_write(cx, &self.buffer))?;
+            if written == 0 {
+                return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into()));
+            }
+            self.buffer.drain(..written);
+        }
+        Poll::Ready(Ok(()))
     }
 }
 
@@ -192,17 +646,442 @@ impl BlockingMessageStream {
         self.inner.text()
     }
 
+    /// Read and decode up to `capacity` events ahead of the consumer.
+    ///
+    /// See [`MessageStream::buffered`]. The reading task runs on the client's
+    /// runtime.
+    pub fn buffered(mut self, capacity: usize) -> Self {
+        let _guard = self.runtime.enter();
+        self.inner = self.inner.buffered(capacity);
+        self
+    }
+
+    /// Validate the order of events.
+    ///
+    /// See [`MessageStream::strict`].
+    pub fn strict(mut self) -> Self {
+        self.inner = self.inner.strict();
+        self
+    }
+
+    /// Copy every SSE event to `writer` as it is received.
+    ///
+    /// See [`MessageStream::tee_to`].
+    pub fn tee_to(mut self, writer: impl AsyncWrite + Send + 'static) -> Self {
+        self.inner = self.inner.tee_to(writer);
+        self
+    }
+
     /// Consume the stream and collect all text.
     pub fn collect_text(self) -> Result<String> {
-        self.runtime.block_on(self.inner.collect_text())
+        block_on(&self.runtime, self.inner.collect_text())
+    }
+
+    /// Consume the stream and return the complete message.
+    ///
+    /// See [`MessageStream::final_message`].
+    pub fn final_message(self) -> Result<Message> {
+        block_on(&self.runtime, self.inner.final_message())
+    }
+
+    /// Drive the stream to completion, writing each text delta to `writer`.
+    ///
+    /// See [`MessageStream::write_text_to`].
+    pub fn write_text_to(mut self, mut writer: impl std::io::Write) -> Result<StreamState> {
+        for event in self.by_ref() {
+            if let MessageStreamEvent::ContentBlockDelta {
+                delta: ContentBlockDelta::TextDelta { text },
+                ..
+            } = event?
+            {
+                writer.write_all(text.as_bytes()).map_err(write_error)?;
+                writer.flush().map_err(write_error)?;
+            }
+        }
+
+        Ok(self.inner.core.state)
+    }
+
+    /// Only the text deltas of the stream.
+    pub fn only_text(self) -> impl Iterator<Item = Result<String>> {
+        let stream = self.inner.only_text();
+        blocking_iter(stream, self.runtime)
+    }
+
+    /// Only the thinking deltas of the stream.
+    pub fn only_thinking(self) -> impl Iterator<Item = Result<String>> {
+        let stream = self.inner.only_thinking();
+        blocking_iter(stream, self.runtime)
+    }
+
+    /// The content blocks of the stream, each yielded once complete.
+    ///
+    /// See [`MessageStream::content_blocks`].
+    pub fn content_blocks(self) -> impl Iterator<Item = Result<ContentBlock>> {
+        let stream = self.inner.content_blocks();
+        blocking_iter(stream, self.runtime)
+    }
+
+    /// Drive the stream on a background thread and receive its events on a
+    /// channel.
+    ///
+    /// At most `capacity` events are buffered. The thread stops when the
+    /// stream ends or the receiver is dropped.
+    pub fn into_channel(
+        self,
+        capacity: usize,
+    ) -> std::sync::mpsc::Receiver<Result<MessageStreamEvent>> {
+        let (tx, rx) = std::sync::mpsc::sync_channel(capacity);
+        std::thread::spawn(move || {
+            for event in self {
+                if tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+        rx
     }
 }
 
+/// Iterate over a stream by blocking on the runtime.
+fn blocking_iter<T>(
+    mut stream: BoxStream<'static, T>,
+    runtime: std::sync::Arc<tokio::runtime::Runtime>,
+) -> impl Iterator<Item = T> {
+    use futures::StreamExt;
+
+    std::iter::from_fn(move || block_on(&runtime, stream.next()))
+}
+
 impl Iterator for BlockingMessageStream {
     type Item = Result<MessageStreamEvent>;
 
     fn next(&mut self) -> Option<Self::Item> {
         use futures::StreamExt;
-        self.runtime.block_on(self.inner.next())
+        block_on(&self.runtime, self.inner.next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde_json::json;
+
+    fn stream(sse: &str) -> MessageStream {
+        // Split mid-event to exercise buffering across chunks.
+        let (first, second) = sse.split_at(sse.len() / 2);
+        let chunks = [first, second].map(|chunk| Ok(Bytes::copy_from_slice(chunk.as_bytes())));
+        MessageStream::from_bytes(futures::stream::iter(chunks).boxed())
+    }
+
+    const TOOL_USE_STREAM: &str = concat!(
+        "event: message_start\n",
+        r#"data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","content":[],"model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+        "\n\n",
+        "event: content_block_start\n",
+        r#"data: {"type":"content_block_start","index":0,"content_block":{"type":"thinking","thinking":"","signature":""}}"#,
+        "\n\n",
+        "event: content_block_delta\n",
+        r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"Need the weather."}}"#,
+        "\n\n",
+        "event: content_block_delta\n",
+        r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"signature_delta","signature":"sig"}}"#,
+        "\n\n",
+        "event: content_block_stop\n",
+        r#"data: {"type":"content_block_stop","index":0}"#,
+        "\n\n",
+        "event: content_block_start\n",
+        r#"data: {"type":"content_block_start","index":1,"content_block":{"type":"text","text":""}}"#,
+        "\n\n",
+        "event: content_block_delta\n",
+        r#"data: {"type":"content_block_delta","index":1,"delta":{"type":"text_delta","text":"Checking."}}"#,
+        "\n\n",
+        "event: content_block_stop\n",
+        r#"data: {"type":"content_block_stop","index":1}"#,
+        "\n\n",
+        "event: content_block_start\n",
+        r#"data: {"type":"content_block_start","index":2,"content_block":{"type":"tool_use","id":"toolu_1","name":"get_weather","input":{}}}"#,
+        "\n\n",
+        "event: content_block_delta\n",
+        r#"data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":"{\"location\": \"Par"}}"#,
+        "\n\n",
+        "event: content_block_delta\n",
+        r#"data: {"type":"content_block_delta","index":2,"delta":{"type":"input_json_delta","partial_json":"is\"}"}}"#,
+        "\n\n",
+        "event: content_block_stop\n",
+        r#"data: {"type":"content_block_stop","index":2}"#,
+        "\n\n",
+        "event: message_delta\n",
+        r#"data: {"type":"message_delta","delta":{"stop_reason":"tool_use","stop_sequence":null,"container":{"id":"container_1","expires_at":"2025-06-01T00:00:00Z"}},"usage":{"output_tokens":42,"input_tokens":12,"cache_read_input_tokens":8,"server_tool_use":{"web_search_requests":1}}}"#,
+        "\n\n",
+        "event: message_stop\n",
+        r#"data: {"type":"message_stop"}"#,
+        "\n\n",
+    );
+
+    #[tokio::test]
+    async fn test_final_message_rebuilds_content() {
+        let message = stream(TOOL_USE_STREAM).final_message().await.unwrap();
+
+        assert_eq!(message.stop_reason, Some(crate::types::StopReason::ToolUse));
+        assert_eq!(message.usage.output_tokens, 42);
+        assert_eq!(message.usage.input_tokens, 12);
+        assert_eq!(message.usage.cache_read_input_tokens, Some(8));
+        assert_eq!(
+            message.usage.server_tool_use.unwrap().web_search_requests,
+            Some(1)
+        );
+        assert_eq!(message.container.unwrap().id, "container_1");
+        assert_eq!(message.content.len(), 3);
+        match &message.content[0] {
+            ContentBlock::Thinking {
+                thinking,
+                signature,
+            } => {
+                assert_eq!(thinking, "Need the weather.");
+                assert_eq!(signature, "sig");
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+        assert_eq!(message.content[1].as_text(), Some("Checking."));
+        match &message.content[2] {
+            ContentBlock::ToolUse { name, input, .. } => {
+                assert_eq!(name, "get_weather");
+                assert_eq!(input, &json!({ "location": "Paris" }));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_final_message_collects_mcp_tool_input() {
+        let sse = concat!(
+            "event: message_start\n",
+            r#"data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","content":[],"model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+            "\n\n",
+            "event: content_block_start\n",
+            r#"data: {"type":"content_block_start","index":0,"content_block":{"type":"mcp_tool_use","id":"mcptoolu_1","name":"search","server_name":"docs","input":{}}}"#,
+            "\n\n",
+            "event: content_block_delta\n",
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"input_json_delta","partial_json":"{\"query\": \"ru"}}"#,
+            "\n\n",
+            "event: content_block_delta\n",
+            r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"input_json_delta","partial_json":"st\"}"}}"#,
+            "\n\n",
+            "event: content_block_stop\n",
+            r#"data: {"type":"content_block_stop","index":0}"#,
+            "\n\n",
+            "event: message_stop\n",
+            r#"data: {"type":"message_stop"}"#,
+            "\n\n",
+        );
+
+        let message = stream(sse).final_message().await.unwrap();
+        match &message.content[0] {
+            ContentBlock::McpToolUse {
+                server_name, input, ..
+            } => {
+                assert_eq!(server_name, "docs");
+                assert_eq!(input, &json!({ "query": "rust" }));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_every_event_in_a_chunk() {
+        let events: Vec<_> = MessageStream::from_bytes(
+            futures::stream::iter([Ok(Bytes::from(TOOL_USE_STREAM))]).boxed(),
+        )
+        .collect()
+        .await;
+
+        assert_eq!(events.len(), 14);
+        assert!(events.iter().all(|event| event.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_state_tracks_tool_use_blocks() {
+        let mut stream = stream(TOOL_USE_STREAM);
+        while let Some(event) = stream.next().await {
+            event.unwrap();
+        }
+
+        let state = stream.state();
+        assert_eq!(state.text, "Checking.");
+        match &state.content[2] {
+            ContentBlock::ToolUse { id, input, .. } => {
+                assert_eq!(id, "toolu_1");
+                assert_eq!(input, &json!({ "location": "Paris" }));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_into_channel_forwards_events() {
+        let mut rx = stream(TOOL_USE_STREAM).into_channel(2);
+
+        let mut count = 0;
+        while let Some(event) = rx.recv().await {
+            event.unwrap();
+            count += 1;
+        }
+        assert_eq!(count, 14);
+    }
+
+    #[tokio::test]
+    async fn test_tee_transcript_replays() {
+        use tokio::io::AsyncReadExt;
+
+        let (writer, mut reader) = tokio::io::duplex(64 * 1024);
+        let original = stream(TOOL_USE_STREAM)
+            .tee_to(writer)
+            .final_message()
+            .await
+            .unwrap();
+
+        let mut transcript = String::new();
+        reader.read_to_string(&mut transcript).await.unwrap();
+        assert!(transcript.starts_with("event: message_start\ndata: {"));
+
+        let replayed = MessageStream::from_reader(std::io::Cursor::new(transcript.into_bytes()))
+            .final_message()
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&replayed).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_error_event_maps_to_error() {
+        let sse = concat!(
+            "event: message_start\n",
+            r#"data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","content":[],"model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":1}}}"#,
+            "\n\n",
+            "event: error\n",
+            r#"data: {"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+            "\n\n",
+        );
+
+        let error = stream(sse).final_message().await.unwrap_err();
+        assert!(matches!(error, AnthropicError::Overloaded { .. }));
+        assert!(error.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn test_combinators() {
+        let thinking: Vec<_> = stream(TOOL_USE_STREAM).only_thinking().collect().await;
+        assert_eq!(thinking.len(), 1);
+        assert_eq!(thinking[0].as_ref().unwrap(), "Need the weather.");
+
+        let text: Vec<_> = stream(TOOL_USE_STREAM).only_text().collect().await;
+        assert_eq!(text.len(), 1);
+        assert_eq!(text[0].as_ref().unwrap(), "Checking.");
+
+        let blocks: Vec<_> = stream(TOOL_USE_STREAM).content_blocks().collect().await;
+        assert_eq!(blocks.len(), 3);
+        assert!(matches!(
+            blocks[2].as_ref().unwrap(),
+            ContentBlock::ToolUse { input, .. } if input["location"] == "Paris"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_write_text_to() {
+        let mut output = Vec::new();
+        let state = stream(TOOL_USE_STREAM)
+            .write_text_to(&mut output)
+            .await
+            .unwrap();
+
+        assert_eq!(output, b"Checking.");
+        assert!(state.is_complete);
+    }
+
+    #[tokio::test]
+    async fn test_metrics() {
+        let mut stream = stream(TOOL_USE_STREAM);
+        assert!(stream.metrics().time_to_first_byte().is_none());
+        while let Some(event) = stream.next().await {
+            event.unwrap();
+        }
+
+        let metrics = stream.metrics();
+        assert_eq!(metrics.deltas, 5);
+        assert!(metrics.time_to_first_byte().unwrap() <= metrics.time_to_first_token().unwrap());
+        assert!(metrics.generation_duration().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_into_sse_round_trips() {
+        let original = stream(TOOL_USE_STREAM).final_message().await.unwrap();
+
+        let chunks: Vec<_> = stream(TOOL_USE_STREAM).into_sse().collect().await;
+        let replayed = MessageStream::from_bytes(futures::stream::iter(chunks).boxed())
+            .final_message()
+            .await
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&replayed).unwrap(),
+            serde_json::to_value(&original).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_into_sse_forwards_error_events() {
+        let end = TOOL_USE_STREAM.find("event: content_block_stop").unwrap();
+        let sse = format!(
+            "{}event: error\ndata: {}\n\n",
+            &TOOL_USE_STREAM[..end],
+            r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+        );
+
+        let chunks: Vec<_> =
+            MessageStream::from_bytes(futures::stream::iter([Ok(Bytes::from(sse))]).boxed())
+                .into_sse()
+                .collect()
+                .await;
+        let last = chunks.last().unwrap().as_ref().unwrap();
+        assert!(last.starts_with(b"event: error\ndata: {"));
+
+        let error = MessageStream::from_bytes(futures::stream::iter(chunks).boxed())
+            .final_message()
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AnthropicError::Overloaded { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_strict_rejects_truncated_stream() {
+        let end = TOOL_USE_STREAM.find("event: message_delta").unwrap();
+        let truncated = &TOOL_USE_STREAM[..end];
+
+        assert!(stream(truncated).final_message().await.is_ok());
+
+        let error = stream(truncated)
+            .strict()
+            .final_message()
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Stream error: Protocol violation: stream ended before message_stop"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buffered_yields_all_events() {
+        let message = stream(TOOL_USE_STREAM)
+            .buffered(1)
+            .strict()
+            .final_message()
+            .await
+            .unwrap();
+
+        assert_eq!(message.content.len(), 3);
     }
 }
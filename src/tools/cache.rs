@@ -0,0 +1,152 @@
+//! Tool result caching.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+/// Cached outputs with the time they were stored, keyed by tool name and
+/// canonical input.
+type Entries = HashMap<(String, String), (Instant, String)>;
+
+/// A cache of successful tool results, keyed by tool name and input.
+///
+/// Inputs are compared as canonical JSON, so key order does not matter.
+/// Entries expire after the TTL. Clones share the same entries, so one cache
+/// can serve several runs.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use anthropic_sdk::tools::{FunctionTool, ToolCache};
+/// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam, Tool, ToolInputSchema};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+///     let client = AsyncAnthropic::new()?;
+///     let lookup = FunctionTool::new(
+///         Tool::new("lookup", ToolInputSchema::empty()),
+///         |_input| async { Ok("expensive result".to_string()) },
+///     );
+///
+///     let result = client
+///         .messages()
+///         .tool_runner(vec![lookup])
+///         .cache(ToolCache::new(Duration::from_secs(300)))
+///         .run(
+///             MessageCreateParams::builder()
+///                 .model("claude-sonnet-4-5-20250929")
+///                 .max_tokens(1024)
+///                 .messages(vec![MessageParam::user("Look it up twice.")])
+///                 .build(),
+///         )
+///         .await?;
+///
+///     println!("{}", result.message.text());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ToolCache {
+    ttl: Duration,
+    entries: Arc<Mutex<Entries>>,
+}
+
+impl ToolCache {
+    /// Create an empty cache whose entries live for `ttl`.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::default(),
+        }
+    }
+
+    /// Get the time to live of cache entries.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Get a cached result if one exists and has not expired.
+    pub fn get(&self, name: &str, input: &Value) -> Option<String> {
+        let key = (name.to_string(), canonical_json(input));
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&key) {
+            Some((stored, output)) if stored.elapsed() < self.ttl => Some(output.clone()),
+            Some(_) => {
+                entries.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store a result.
+    pub fn insert(&self, name: &str, input: &Value, output: String) {
+        let key = (name.to_string(), canonical_json(input));
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key, (Instant::now(), output));
+    }
+
+    /// Remove all entries.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// Serialize a value with object keys sorted at every level.
+fn canonical_json(value: &Value) -> String {
+    fn sort(value: &Value) -> Value {
+        match value {
+            Value::Object(object) => {
+                let mut entries: Vec<_> = object.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(k, v)| (k.clone(), sort(v)))
+                        .collect(),
+                )
+            }
+            Value::Array(items) => Value::Array(items.iter().map(sort).collect()),
+            other => other.clone(),
+        }
+    }
+
+    sort(value).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cache_ignores_key_order() {
+        let cache = ToolCache::new(Duration::from_secs(60));
+        cache.insert(
+            "search",
+            &json!({ "query": "rust", "filters": { "lang": "en", "year": 2024 } }),
+            "results".into(),
+        );
+
+        let hit = cache.get(
+            "search",
+            &json!({ "filters": { "year": 2024, "lang": "en" }, "query": "rust" }),
+        );
+        assert_eq!(hit.as_deref(), Some("results"));
+        assert!(cache.get("search", &json!({ "query": "go" })).is_none());
+        assert!(cache.get("fetch", &json!({ "query": "rust" })).is_none());
+    }
+
+    #[test]
+    fn test_cache_entries_expire() {
+        let cache = ToolCache::new(Duration::ZERO);
+        cache.insert("search", &json!({}), "results".into());
+        assert!(cache.get("search", &json!({})).is_none());
+    }
+}
@@ -0,0 +1,117 @@
+//! Typed tool handlers.
+
+use std::future::Future;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::ToolError;
+use crate::types::parse_tool_input;
+
+/// An async tool implementation with typed input and output.
+///
+/// The `input` of each `tool_use` block is deserialized into
+/// [`Input`](Self::Input) before [`call`](Self::call) runs, and the returned
+/// [`Output`](Self::Output) is serialized back for the model. Strings are sent
+/// as-is; other values are sent as JSON text.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::tools::{ToolError, ToolHandler};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize)]
+/// struct WeatherInput {
+///     location: String,
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Weather {
+///     temperature: f32,
+///     conditions: String,
+/// }
+///
+/// struct GetWeather;
+///
+/// impl ToolHandler for GetWeather {
+///     type Input = WeatherInput;
+///     type Output = Weather;
+///
+///     async fn call(&self, input: WeatherInput) -> Result<Weather, ToolError> {
+///         Ok(Weather {
+///             temperature: 21.5,
+///             conditions: format!("Sunny in {}", input.location),
+///         })
+///     }
+/// }
+/// ```
+pub trait ToolHandler: Send + Sync + 'static {
+    /// The tool input, deserialized from the `tool_use` block.
+    type Input: DeserializeOwned + Send;
+
+    /// The tool output, serialized into the tool result.
+    type Output: Serialize;
+
+    /// Execute the tool.
+    fn call(
+        &self,
+        input: Self::Input,
+    ) -> impl Future<Output = Result<Self::Output, ToolError>> + Send;
+}
+
+/// Deserialize the input, run the handler and serialize its output.
+pub(crate) async fn call_handler<H: ToolHandler>(
+    handler: &H,
+    name: &str,
+    input: Value,
+) -> Result<String, ToolError> {
+    let input = parse_tool_input(name, &input).map_err(|e| ToolError::new(e.to_string()))?;
+    let output = handler.call(input).await?;
+
+    match serde_json::to_value(&output)? {
+        Value::String(text) => Ok(text),
+        value => Ok(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize)]
+    struct AddInput {
+        a: i64,
+        b: i64,
+    }
+
+    struct Add;
+
+    impl ToolHandler for Add {
+        type Input = AddInput;
+        type Output = Value;
+
+        async fn call(&self, input: AddInput) -> Result<Value, ToolError> {
+            Ok(json!({ "sum": input.a + input.b }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_handler_serializes_output() {
+        let output = call_handler(&Add, "add", json!({ "a": 1, "b": 2 }))
+            .await
+            .unwrap();
+        assert_eq!(output, r#"{"sum":3}"#);
+    }
+
+    #[tokio::test]
+    async fn test_call_handler_rejects_invalid_input() {
+        let error = call_handler(&Add, "add", json!({ "a": 1 }))
+            .await
+            .unwrap_err();
+        assert!(error.message().starts_with("Invalid input for tool add:"));
+    }
+}
@@ -0,0 +1,583 @@
+//! Bridge to local MCP servers.
+
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use reqwest::header::ACCEPT;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::oneshot;
+
+use super::FunctionTool;
+use crate::error::{AnthropicError, Result};
+use crate::streaming::{RawStreamEvent, SseDecoder};
+use crate::types::{Tool, ToolInputSchema};
+
+/// The MCP protocol version this client speaks.
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// How long to wait for the server to answer a request, unless changed with
+/// [`McpClient::timeout`]. Also bounds connecting and the handshake.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The outcome of a JSON-RPC request: its result or the error message.
+type Response = std::result::Result<Value, String>;
+
+/// Requests waiting for a response, keyed by JSON-RPC id.
+///
+/// `None` once the connection has closed, so no request can register after
+/// the reader has stopped.
+struct Pending(Mutex<Option<HashMap<u64, oneshot::Sender<Response>>>>);
+
+impl Pending {
+    /// Create an empty set for an open connection.
+    fn open() -> Arc<Self> {
+        Arc::new(Self(Mutex::new(Some(HashMap::new()))))
+    }
+
+    /// Register a request, failing if the connection has closed.
+    fn register(&self, id: u64) -> Result<oneshot::Receiver<Response>> {
+        let (sender, receiver) = oneshot::channel();
+        match self.0.lock().unwrap().as_mut() {
+            Some(pending) => {
+                pending.insert(id, sender);
+                Ok(receiver)
+            }
+            None => Err(closed()),
+        }
+    }
+
+    /// Take the sender of a request, if it is still waiting.
+    fn take(&self, id: u64) -> Option<oneshot::Sender<Response>> {
+        self.0.lock().unwrap().as_mut()?.remove(&id)
+    }
+
+    /// Mark the connection closed, failing every waiting request.
+    fn close(&self) {
+        self.0.lock().unwrap().take();
+    }
+}
+
+/// A client connected to a local MCP server.
+///
+/// Lists the server's tools and calls them. [`tools`](Self::tools) turns them
+/// into [`FunctionTool`]s, so the [`ToolRunner`](super::ToolRunner) routes
+/// the model's tool calls through the MCP protocol. Cloning is cheap; clones
+/// share the connection.
+///
+/// Each request fails with [`AnthropicError::Mcp`] if the server does not
+/// answer within the [`timeout`](Self::timeout), 60 seconds by default, or
+/// once the connection has closed.
+///
+/// Requests from the server to the client, such as sampling, are not
+/// supported.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::tools::McpClient;
+/// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam};
+/// use tokio::process::Command;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+///     let client = AsyncAnthropic::new()?;
+///     let mcp = McpClient::stdio(
+///         Command::new("npx").args(["-y", "@modelcontextprotocol/server-filesystem", "."]),
+///     )
+///     .await?;
+///
+///     let result = client.messages().run_tools(
+///         MessageCreateParams::builder()
+///             .model("claude-sonnet-4-5-20250929")
+///             .max_tokens(1024)
+///             .messages(vec![MessageParam::user("What files are in this directory?")])
+///             .build(),
+///         mcp.tools().await?,
+///     ).await?;
+///
+///     println!("{}", result.message.text());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct McpClient {
+    inner: Arc<Inner>,
+    timeout: Duration,
+}
+
+struct Inner {
+    transport: Transport,
+    pending: Arc<Pending>,
+    next_id: AtomicU64,
+}
+
+enum Transport {
+    /// A child process speaking newline-delimited JSON-RPC.
+    Stdio {
+        stdin: tokio::sync::Mutex<ChildStdin>,
+        // Held so the process is killed when the client is dropped.
+        _child: Child,
+    },
+
+    /// A server receiving requests by POST and answering over SSE.
+    Sse {
+        http: reqwest::Client,
+        endpoint: reqwest::Url,
+    },
+}
+
+impl McpClient {
+    /// Start an MCP server process and connect to it over stdio.
+    ///
+    /// The server's stderr is inherited. The process is killed when the last
+    /// clone of the client is dropped.
+    pub async fn stdio(command: &mut Command) -> Result<Self> {
+        let mut child = command
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| mcp_error(format!("Failed to start MCP server: {}", e)))?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        let pending = Pending::open();
+        tokio::spawn(read_stdio(stdout, pending.clone()));
+
+        let transport = Transport::Stdio {
+            stdin: tokio::sync::Mutex::new(stdin),
+            _child: child,
+        };
+        Self::initialize(transport, pending).await
+    }
+
+    /// Connect to an MCP server over the HTTP with SSE transport.
+    ///
+    /// `url` is the server's SSE endpoint, e.g. `http://localhost:8080/sse`.
+    pub async fn sse(url: &str) -> Result<Self> {
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| mcp_error(format!("Invalid MCP server URL {}: {}", url, e)))?;
+        let http = reqwest::Client::new();
+        let connect = async {
+            let response = http
+                .get(url.clone())
+                .header(ACCEPT, "text/event-stream")
+                .send()
+                .await?
+                .error_for_status()?;
+
+            // The first event names the endpoint to POST requests to.
+            let mut stream = response.bytes_stream();
+            let mut decoder = SseDecoder::new();
+            let mut backlog = Vec::new();
+            let mut endpoint = None;
+            while endpoint.is_none() {
+                let chunk = stream.next().await.ok_or_else(|| {
+                    mcp_error("MCP server closed the stream before sending its endpoint")
+                })??;
+                for event in decoder.decode(chunk) {
+                    if endpoint.is_none() && event.event == "endpoint" {
+                        endpoint = Some(url.join(event.data.trim()).map_err(|e| {
+                            mcp_error(format!("Invalid MCP endpoint {}: {}", event.data, e))
+                        })?);
+                    } else {
+                        backlog.push(event);
+                    }
+                }
+            }
+            let endpoint = endpoint.expect("endpoint was received");
+            Ok::<_, AnthropicError>((stream, decoder, backlog, endpoint))
+        };
+        let (stream, decoder, backlog, endpoint) = tokio::time::timeout(DEFAULT_TIMEOUT, connect)
+            .await
+            .map_err(|_| {
+                mcp_error(format!(
+                    "MCP server did not send its endpoint within {:?}",
+                    DEFAULT_TIMEOUT
+                ))
+            })??;
+
+        let pending = Pending::open();
+        tokio::spawn(read_sse(stream, decoder, backlog, pending.clone()));
+
+        let transport = Transport::Sse { http, endpoint };
+        Self::initialize(transport, pending).await
+    }
+
+    /// Perform the MCP handshake.
+    async fn initialize(transport: Transport, pending: Arc<Pending>) -> Result<Self> {
+        let client = Self {
+            inner: Arc::new(Inner {
+                transport,
+                pending,
+                next_id: AtomicU64::new(1),
+            }),
+            timeout: DEFAULT_TIMEOUT,
+        };
+
+        client
+            .request(
+                "initialize",
+                json!({
+                    "protocolVersion": PROTOCOL_VERSION,
+                    "capabilities": {},
+                    "clientInfo": {
+                        "name": "anthropic-sdk-rust",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    },
+                }),
+            )
+            .await?;
+        client
+            .send(&json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/initialized",
+            }))
+            .await?;
+
+        Ok(client)
+    }
+
+    /// Set how long to wait for the server to answer each request.
+    ///
+    /// Applies to this client and clones made from it afterwards.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// List the tools the server provides.
+    pub async fn list_tools(&self) -> Result<Vec<McpTool>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ListToolsResult {
+            tools: Vec<McpTool>,
+            next_cursor: Option<String>,
+        }
+
+        let mut tools = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let params = match &cursor {
+                Some(cursor) => json!({ "cursor": cursor }),
+                None => json!({}),
+            };
+            let page: ListToolsResult =
+                serde_json::from_value(self.request("tools/list", params).await?)?;
+            tools.extend(page.tools);
+
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => return Ok(tools),
+            }
+        }
+    }
+
+    /// Call a tool on the server.
+    pub async fn call_tool(&self, name: &str, arguments: Value) -> Result<McpToolResult> {
+        let result = self
+            .request(
+                "tools/call",
+                json!({ "name": name, "arguments": arguments }),
+            )
+            .await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// List the server's tools as [`FunctionTool`]s that call back into it.
+    ///
+    /// Results flagged as errors by the server are reported to the model as
+    /// `is_error` tool results.
+    pub async fn tools(&self) -> Result<Vec<FunctionTool>> {
+        Ok(self
+            .list_tools()
+            .await?
+            .into_iter()
+            .map(|tool| {
+                let client = self.clone();
+                let name: Arc<str> = tool.name.as_str().into();
+                FunctionTool::new(tool.to_tool(), move |input| {
+                    let client = client.clone();
+                    let name = name.clone();
+                    async move {
+                        let result = client.call_tool(&name, input).await?;
+                        if result.is_error {
+                            Err(super::ToolError::new(result.text()))
+                        } else {
+                            Ok(result.text())
+                        }
+                    }
+                })
+            })
+            .collect())
+    }
+
+    /// Send a JSON-RPC request and wait for its response.
+    async fn request(&self, method: &str, params: Value) -> Result<Value> {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let receiver = self.inner.pending.register(id)?;
+
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        if let Err(error) = self.send(&message).await {
+            self.inner.pending.take(id);
+            return Err(error);
+        }
+
+        match tokio::time::timeout(self.timeout, receiver).await {
+            Ok(Ok(Ok(result))) => Ok(result),
+            Ok(Ok(Err(message))) => Err(mcp_error(format!("{} failed: {}", method, message))),
+            Ok(Err(_)) => Err(closed()),
+            Err(_) => {
+                self.inner.pending.take(id);
+                Err(mcp_error(format!(
+                    "{} timed out after {:?}",
+                    method, self.timeout
+                )))
+            }
+        }
+    }
+
+    /// Write a JSON-RPC message to the transport.
+    async fn send(&self, message: &Value) -> Result<()> {
+        match &self.inner.transport {
+            Transport::Stdio { stdin, .. } => {
+                let mut line = serde_json::to_vec(message)?;
+                line.push(b'\n');
+
+                let mut stdin = stdin.lock().await;
+                stdin
+                    .write_all(&line)
+                    .await
+                    .map_err(|e| mcp_error(format!("Failed to write to MCP server: {}", e)))?;
+                stdin
+                    .flush()
+                    .await
+                    .map_err(|e| mcp_error(format!("Failed to write to MCP server: {}", e)))?;
+            }
+            Transport::Sse { http, endpoint } => {
+                http.post(endpoint.clone())
+                    .json(message)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A tool provided by an MCP server.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpTool {
+    /// The tool name.
+    pub name: String,
+
+    /// A description of what the tool does.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// The JSON schema for the tool's input.
+    pub input_schema: Value,
+}
+
+impl McpTool {
+    /// Convert into a tool definition for the API.
+    pub fn to_tool(&self) -> Tool {
+        let tool = Tool::new(
+            self.name.clone(),
+            ToolInputSchema::from_value(self.input_schema.clone()),
+        );
+        match &self.description {
+            Some(description) => tool.description(description.clone()),
+            None => tool,
+        }
+    }
+}
+
+/// The result of an MCP tool call.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpToolResult {
+    /// The content items of the result.
+    #[serde(default)]
+    pub content: Vec<Value>,
+
+    /// Whether the tool reported an error.
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+impl McpToolResult {
+    /// Get the result as text.
+    ///
+    /// Text items are joined with newlines; other items are included as JSON.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .map(|item| match item.get("text").and_then(Value::as_str) {
+                Some(text) if item["type"] == "text" => text.to_string(),
+                _ => item.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Read newline-delimited messages from a stdio server.
+async fn read_stdio(stdout: ChildStdout, pending: Arc<Pending>) {
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if let Ok(message) = serde_json::from_str(&line) {
+            dispatch(&pending, message);
+        }
+    }
+    pending.close();
+}
+
+/// Read messages from an SSE server stream.
+async fn read_sse<S>(
+    mut stream: S,
+    mut decoder: SseDecoder,
+    backlog: Vec<RawStreamEvent>,
+    pending: Arc<Pending>,
+) where
+    S: Stream<Item = reqwest::Result<Bytes>> + Unpin,
+{
+    let handle = |event: RawStreamEvent| {
+        if event.event == "message" {
+            if let Ok(message) = serde_json::from_str(&event.data) {
+                dispatch(&pending, message);
+            }
+        }
+    };
+
+    backlog.into_iter().for_each(handle);
+    while let Some(Ok(chunk)) = stream.next().await {
+        decoder.decode(chunk).into_iter().for_each(handle);
+    }
+    pending.close();
+}
+
+/// Complete the pending request a JSON-RPC response belongs to.
+///
+/// Notifications and requests from the server are ignored.
+fn dispatch(pending: &Pending, message: Value) {
+    if message.get("method").is_some() {
+        return;
+    }
+    let Some(id) = message.get("id").and_then(Value::as_u64) else {
+        return;
+    };
+    let Some(sender) = pending.take(id) else {
+        return;
+    };
+
+    let result = match message.get("error") {
+        Some(error) => Err(error
+            .get("message")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown error")
+            .to_string()),
+        None => Ok(message.get("result").cloned().unwrap_or(Value::Null)),
+    };
+    let _ = sender.send(result);
+}
+
+/// The error for a request on a closed connection.
+fn closed() -> AnthropicError {
+    mcp_error("MCP server closed the connection")
+}
+
+fn mcp_error(message: impl Into<String>) -> AnthropicError {
+    AnthropicError::Mcp {
+        message: message.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dispatch_routes_responses_by_id() {
+        let pending = Pending::open();
+        let first_rx = pending.register(1).unwrap();
+        let second_rx = pending.register(2).unwrap();
+
+        dispatch(
+            &pending,
+            json!({ "jsonrpc": "2.0", "method": "notifications/progress" }),
+        );
+        dispatch(
+            &pending,
+            json!({ "jsonrpc": "2.0", "id": 2, "error": { "code": -32601, "message": "nope" } }),
+        );
+        dispatch(
+            &pending,
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "ok": true } }),
+        );
+
+        assert_eq!(first_rx.await.unwrap().unwrap(), json!({ "ok": true }));
+        assert_eq!(second_rx.await.unwrap().unwrap_err(), "nope");
+        assert!(pending.take(1).is_none());
+        assert!(pending.take(2).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_closed_connection_fails_requests() {
+        let pending = Pending::open();
+        let waiting = pending.register(1).unwrap();
+        pending.close();
+
+        assert!(waiting.await.is_err());
+        assert!(matches!(
+            pending.register(2),
+            Err(AnthropicError::Mcp { ref message }) if message == "MCP server closed the connection"
+        ));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_request_timeout() {
+        // Answers the handshake, then never replies
+        let script = r#"read line; echo '{"jsonrpc":"2.0","id":1,"result":{}}'; while read line; do :; done"#;
+        let client = McpClient::stdio(Command::new("sh").args(["-c", script]))
+            .await
+            .unwrap()
+            .timeout(Duration::from_millis(50));
+
+        let error = client.list_tools().await.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "MCP error: tools/list timed out after 50ms"
+        );
+        assert!(client.inner.pending.take(2).is_none());
+    }
+
+    #[test]
+    fn test_tool_result_text() {
+        let result: McpToolResult = serde_json::from_value(json!({
+            "content": [
+                { "type": "text", "text": "first" },
+                { "type": "image", "data": "aGk=", "mimeType": "image/png" },
+                { "type": "text", "text": "second" },
+            ],
+        }))
+        .unwrap();
+
+        assert!(!result.is_error);
+        assert_eq!(
+            result.text(),
+            "first\n{\"data\":\"aGk=\",\"mimeType\":\"image/png\",\"type\":\"image\"}\nsecond"
+        );
+    }
+}
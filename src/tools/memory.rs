@@ -0,0 +1,300 @@
+//! File-backed memory tool handler.
+
+use std::path::{Component, Path, PathBuf};
+
+use tokio::fs;
+
+use super::{FunctionTool, ToolError, ToolHandler};
+use crate::types::{MemoryCommand, MemoryTool};
+
+/// The directory all memory paths are relative to.
+const MEMORY_ROOT: &str = "/memories";
+
+/// A [`ToolHandler`] for the memory tool that stores memories on disk.
+///
+/// The model's `/memories` directory maps to `root`. Paths that would escape
+/// it are rejected.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::tools::FileMemory;
+/// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+///     let client = AsyncAnthropic::new()?;
+///
+///     let result = client.messages().run_tools(
+///         MessageCreateParams::builder()
+///             .model("claude-sonnet-4-5-20250929")
+///             .max_tokens(1024)
+///             .messages(vec![MessageParam::user("Remember that I prefer tabs.")])
+///             .build(),
+///         vec![FileMemory::new("./memories").into_tool()],
+///     ).await?;
+///
+///     println!("{}", result.message.text());
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileMemory {
+    root: PathBuf,
+}
+
+impl FileMemory {
+    /// Create a handler that stores memories under `root`.
+    ///
+    /// The directory is created when the first memory is written.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Get the directory memories are stored in.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Pair this handler with the memory tool definition.
+    pub fn into_tool(self) -> FunctionTool {
+        FunctionTool::with_handler(MemoryTool::new(), self)
+    }
+
+    /// Map a `/memories` path to a path under the root.
+    fn resolve(&self, path: &str) -> Result<PathBuf, ToolError> {
+        let relative = path
+            .strip_prefix(MEMORY_ROOT)
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            .ok_or_else(|| {
+                ToolError::new(format!("Path must be under {}: {}", MEMORY_ROOT, path))
+            })?;
+
+        let mut resolved = self.root.clone();
+        for component in Path::new(relative.trim_start_matches('/')).components() {
+            match component {
+                Component::Normal(part) => resolved.push(part),
+                Component::CurDir => {}
+                _ => return Err(ToolError::new(format!("Invalid path: {}", path))),
+            }
+        }
+        Ok(resolved)
+    }
+
+    async fn view(&self, path: &str, view_range: Option<[i64; 2]>) -> Result<String, ToolError> {
+        let resolved = self.resolve(path)?;
+        if path.trim_end_matches('/') == MEMORY_ROOT {
+            fs::create_dir_all(&resolved).await?;
+        }
+
+        if fs::metadata(&resolved).await?.is_dir() {
+            let mut entries = Vec::new();
+            let mut dir = fs::read_dir(&resolved).await?;
+            while let Some(entry) = dir.next_entry().await? {
+                let name = entry.file_name().to_string_lossy().into_owned();
+                if name.starts_with('.') {
+                    continue;
+                }
+                let suffix = if entry.file_type().await?.is_dir() {
+                    "/"
+                } else {
+                    ""
+                };
+                entries.push(format!("- {}{}", name, suffix));
+            }
+            entries.sort();
+            return Ok(format!("Directory: {}\n{}", path, entries.join("\n")));
+        }
+
+        let text = fs::read_to_string(&resolved).await?;
+        let lines: Vec<&str> = text.lines().collect();
+        let (start, end) = match view_range {
+            Some([start, end]) => {
+                let end = if end < 0 { lines.len() } else { end as usize };
+                (
+                    (start.max(1) as usize).min(lines.len() + 1),
+                    end.min(lines.len()),
+                )
+            }
+            None => (1, lines.len()),
+        };
+
+        Ok(lines
+            .iter()
+            .enumerate()
+            .take(end)
+            .skip(start - 1)
+            .map(|(i, line)| format!("{:6}\t{}", i + 1, line))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    async fn create(&self, path: &str, file_text: &str) -> Result<String, ToolError> {
+        let resolved = self.resolve(path)?;
+        if let Some(parent) = resolved.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(&resolved, file_text).await?;
+        Ok(format!("File created successfully at {}", path))
+    }
+
+    async fn str_replace(
+        &self,
+        path: &str,
+        old_str: &str,
+        new_str: &str,
+    ) -> Result<String, ToolError> {
+        let resolved = self.resolve(path)?;
+        let text = fs::read_to_string(&resolved).await?;
+        match text.matches(old_str).count() {
+            0 => Err(ToolError::new(format!("No match for old_str in {}", path))),
+            1 => {
+                fs::write(&resolved, text.replacen(old_str, new_str, 1)).await?;
+                Ok(format!("File {} has been edited", path))
+            }
+            n => Err(ToolError::new(format!(
+                "old_str matches {} times in {}; it must be unique",
+                n, path
+            ))),
+        }
+    }
+
+    async fn insert(
+        &self,
+        path: &str,
+        insert_line: u32,
+        insert_text: &str,
+    ) -> Result<String, ToolError> {
+        let resolved = self.resolve(path)?;
+        let text = fs::read_to_string(&resolved).await?;
+        let mut lines: Vec<&str> = text.lines().collect();
+        let index = insert_line as usize;
+        if index > lines.len() {
+            return Err(ToolError::new(format!(
+                "insert_line {} is past the end of {} ({} lines)",
+                insert_line,
+                path,
+                lines.len()
+            )));
+        }
+
+        let inserted: Vec<&str> = insert_text.lines().collect();
+        lines.splice(index..index, inserted);
+        fs::write(&resolved, lines.join("\n") + "\n").await?;
+        Ok(format!("Text inserted at line {} of {}", insert_line, path))
+    }
+
+    async fn delete(&self, path: &str) -> Result<String, ToolError> {
+        let resolved = self.resolve(path)?;
+        if resolved == self.root {
+            return Err(ToolError::new("Cannot delete the memory directory"));
+        }
+
+        if fs::metadata(&resolved).await?.is_dir() {
+            fs::remove_dir_all(&resolved).await?;
+        } else {
+            fs::remove_file(&resolved).await?;
+        }
+        Ok(format!("Deleted {}", path))
+    }
+
+    async fn rename(&self, old_path: &str, new_path: &str) -> Result<String, ToolError> {
+        let from = self.resolve(old_path)?;
+        let to = self.resolve(new_path)?;
+        if fs::try_exists(&to).await? {
+            return Err(ToolError::new(format!("{} already exists", new_path)));
+        }
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::rename(&from, &to).await?;
+        Ok(format!("Renamed {} to {}", old_path, new_path))
+    }
+}
+
+impl ToolHandler for FileMemory {
+    type Input = MemoryCommand;
+    type Output = String;
+
+    async fn call(&self, input: MemoryCommand) -> Result<String, ToolError> {
+        match input {
+            MemoryCommand::View { path, view_range } => self.view(&path, view_range).await,
+            MemoryCommand::Create { path, file_text } => self.create(&path, &file_text).await,
+            MemoryCommand::StrReplace {
+                path,
+                old_str,
+                new_str,
+            } => self.str_replace(&path, &old_str, &new_str).await,
+            MemoryCommand::Insert {
+                path,
+                insert_line,
+                insert_text,
+            } => self.insert(&path, insert_line, &insert_text).await,
+            MemoryCommand::Delete { path } => self.delete(&path).await,
+            MemoryCommand::Rename { old_path, new_path } => self.rename(&old_path, &new_path).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_rejects_escaping_paths() {
+        let memory = FileMemory::new("/tmp/memories");
+
+        assert_eq!(
+            memory.resolve("/memories/notes/todo.md").unwrap(),
+            PathBuf::from("/tmp/memories/notes/todo.md")
+        );
+        assert_eq!(
+            memory.resolve("/memories").unwrap(),
+            PathBuf::from("/tmp/memories")
+        );
+        assert!(memory.resolve("/memories/../secrets").is_err());
+        assert!(memory.resolve("/memoriesx/notes.md").is_err());
+        assert!(memory.resolve("/etc/passwd").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_memory_edits() {
+        let root = std::env::temp_dir().join(format!("anthropic-memory-{}", uuid::Uuid::new_v4()));
+        let memory = FileMemory::new(&root);
+
+        memory
+            .call(MemoryCommand::Create {
+                path: "/memories/prefs.md".into(),
+                file_text: "indent: spaces\n".into(),
+            })
+            .await
+            .unwrap();
+        memory
+            .call(MemoryCommand::StrReplace {
+                path: "/memories/prefs.md".into(),
+                old_str: "spaces".into(),
+                new_str: "tabs".into(),
+            })
+            .await
+            .unwrap();
+        memory
+            .call(MemoryCommand::Insert {
+                path: "/memories/prefs.md".into(),
+                insert_line: 0,
+                insert_text: "# Preferences".into(),
+            })
+            .await
+            .unwrap();
+
+        let view = memory
+            .call(MemoryCommand::View {
+                path: "/memories/prefs.md".into(),
+                view_range: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(view, "     1\t# Preferences\n     2\tindent: tabs");
+
+        fs::remove_dir_all(&root).await.unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Client-side tool execution.
+//!
+//! The [`ToolRunner`] drives the tool use loop: it sends a request, runs the
+//! handlers for any `tool_use` blocks in the response, sends their results
+//! back, and repeats until the model finishes its turn.
+
+mod cache;
+mod handler;
+#[cfg(feature = "mcp")]
+mod mcp;
+mod memory;
+mod registry;
+mod runner;
+
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde_json::Value;
+
+use crate::types::{AnthropicTool, ToolUnion};
+
+pub use cache::ToolCache;
+pub use handler::ToolHandler;
+#[cfg(feature = "mcp")]
+pub use mcp::{McpClient, McpTool, McpToolResult};
+pub use memory::FileMemory;
+pub use registry::ToolRegistry;
+pub use runner::{BlockingToolRunner, ToolErrorPolicy, ToolRunResult, ToolRunner};
+
+/// An error returned by a tool handler.
+///
+/// Its message is sent back to the model as an `is_error` tool result. Any
+/// [`std::error::Error`] converts into it, so handlers can use `?`.
+#[derive(Debug, Clone)]
+pub struct ToolError {
+    message: String,
+}
+
+impl ToolError {
+    /// Create a new tool error with the given message.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Get the error message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<E: std::error::Error> From<E> for ToolError {
+    fn from(error: E) -> Self {
+        Self::new(error.to_string())
+    }
+}
+
+/// The definition of a tool the runner can execute.
+///
+/// Either a custom tool with its own input schema, or an Anthropic-defined
+/// tool executed by the caller, such as [`BashTool`](crate::types::BashTool).
+pub type ToolDefinition = ToolUnion;
+
+/// Boxed handler function of a [`FunctionTool`].
+type ToolFn = Arc<dyn Fn(Value) -> BoxFuture<'static, Result<String, ToolError>> + Send + Sync>;
+
+/// A tool definition paired with the async function that executes it.
+#[derive(Clone)]
+pub struct FunctionTool {
+    definition: ToolDefinition,
+    handler: ToolFn,
+    timeout: Option<Duration>,
+}
+
+impl FunctionTool {
+    /// Create a tool from its definition and a handler.
+    ///
+    /// The definition is a custom [`Tool`] or an Anthropic-defined tool. The
+    /// handler receives the raw `input` of the `tool_use` block and returns
+    /// the text sent back to the model.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::tools::FunctionTool;
+    /// use anthropic_sdk::{Tool, ToolInputSchema};
+    /// use serde_json::json;
+    ///
+    /// let weather = FunctionTool::new(
+    ///     Tool::new(
+    ///         "get_weather",
+    ///         ToolInputSchema::with_properties(
+    ///             json!({ "location": { "type": "string" } }),
+    ///             vec!["location".to_string()],
+    ///         ),
+    ///     ),
+    ///     |input| async move {
+    ///         let location = input["location"].as_str().unwrap_or("unknown");
+    ///         Ok(format!("It is sunny in {}", location))
+    ///     },
+    /// );
+    /// ```
+    pub fn new<F, Fut>(definition: impl Into<ToolDefinition>, handler: F) -> Self
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, ToolError>> + Send + 'static,
+    {
+        Self {
+            definition: definition.into(),
+            handler: Arc::new(move |input| handler(input).boxed()),
+            timeout: None,
+        }
+    }
+
+    /// Create a tool from its definition and a typed [`ToolHandler`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::tools::{FunctionTool, ToolError, ToolHandler};
+    /// use anthropic_sdk::types::{BashCommand, BashTool};
+    ///
+    /// struct Shell;
+    ///
+    /// impl ToolHandler for Shell {
+    ///     type Input = BashCommand;
+    ///     type Output = String;
+    ///
+    ///     async fn call(&self, input: BashCommand) -> Result<String, ToolError> {
+    ///         let command = input.command.unwrap_or_default();
+    ///         let output = std::process::Command::new("sh").arg("-c").arg(command).output()?;
+    ///         Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    ///     }
+    /// }
+    ///
+    /// let bash = FunctionTool::with_handler(BashTool::new(), Shell);
+    /// ```
+    pub fn with_handler<H: ToolHandler>(definition: impl Into<ToolDefinition>, handler: H) -> Self {
+        let definition = definition.into();
+        let tool_handler = Arc::new(handler);
+        let name: Arc<str> = definition.name().into();
+
+        Self::new(definition, move |input| {
+            let tool_handler = tool_handler.clone();
+            let name = name.clone();
+            async move { handler::call_handler(tool_handler.as_ref(), &name, input).await }
+        })
+    }
+
+    /// Create a tool from a typed [`ToolHandler`] whose input describes the tool.
+    ///
+    /// The definition is taken from the input type's [`AnthropicTool`] impl,
+    /// usually derived with `#[derive(AnthropicTool)]`.
+    pub fn from_handler<H>(handler: H) -> Self
+    where
+        H: ToolHandler,
+        H::Input: AnthropicTool,
+    {
+        Self::with_handler(H::Input::tool(), handler)
+    }
+
+    /// Set how long the handler may run.
+    ///
+    /// When it takes longer, the call is abandoned and the model receives an
+    /// `is_error` result saying the tool timed out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the tool definition.
+    pub fn definition(&self) -> &ToolDefinition {
+        &self.definition
+    }
+
+    /// Get the tool name.
+    pub fn name(&self) -> &str {
+        self.definition.name()
+    }
+
+    /// Run the handler with the given input, enforcing the timeout if set.
+    pub async fn call(&self, input: Value) -> Result<String, ToolError> {
+        let Some(timeout) = self.timeout else {
+            return (self.handler)(input).await;
+        };
+
+        tokio::time::timeout(timeout, (self.handler)(input))
+            .await
+            .unwrap_or_else(|_| {
+                Err(ToolError::new(format!(
+                    "Tool {} timed out after {:?}",
+                    self.name(),
+                    timeout
+                )))
+            })
+    }
+}
+
+impl fmt::Debug for FunctionTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FunctionTool")
+            .field("definition", &self.definition)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,249 @@
+//! Name-based tool dispatch.
+
+use std::collections::HashMap;
+
+use futures::future::join_all;
+use serde_json::Value;
+
+use super::ToolDefinition;
+use super::{FunctionTool, ToolError};
+use crate::types::{ContentBlock, ContentBlockParam, Message, ServerTool, Tool, ToolUseBlock};
+
+/// A set of tools keyed by name.
+///
+/// Produces the tool definitions for a request and dispatches `tool_use`
+/// blocks to the matching handler. Used by the [`ToolRunner`](super::ToolRunner),
+/// and usable on its own in a hand-written tool loop.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anthropic_sdk::tools::{FunctionTool, ToolRegistry};
+/// use anthropic_sdk::{AsyncAnthropic, MessageCreateParams, MessageParam, Tool, ToolInputSchema};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), anthropic_sdk::AnthropicError> {
+///     let client = AsyncAnthropic::new()?;
+///     let registry = ToolRegistry::new().tool(FunctionTool::new(
+///         Tool::new("get_time", ToolInputSchema::empty()),
+///         |_input| async { Ok("12:00".to_string()) },
+///     ));
+///
+///     let message = client.messages().create(
+///         MessageCreateParams::builder()
+///             .model("claude-sonnet-4-5-20250929")
+///             .max_tokens(1024)
+///             .tools(registry.tool_definitions())
+///             .messages(vec![MessageParam::user("What time is it?")])
+///             .build()
+///     ).await?;
+///
+///     let results = registry.dispatch_message(&message).await;
+///     // Send `results` back in a user message...
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToolRegistry {
+    tools: HashMap<String, FunctionTool>,
+    order: Vec<String>,
+}
+
+impl ToolRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tool, replacing any tool with the same name.
+    pub fn register(&mut self, tool: FunctionTool) -> &mut Self {
+        let name = tool.name().to_string();
+        if self.tools.insert(name.clone(), tool).is_none() {
+            self.order.push(name);
+        }
+        self
+    }
+
+    /// Add a tool, replacing any tool with the same name.
+    pub fn tool(mut self, tool: FunctionTool) -> Self {
+        self.register(tool);
+        self
+    }
+
+    /// Remove a tool by name.
+    pub fn remove(&mut self, name: &str) -> Option<FunctionTool> {
+        let tool = self.tools.remove(name)?;
+        self.order.retain(|registered| registered != name);
+        Some(tool)
+    }
+
+    /// Get a tool by name.
+    pub fn get(&self, name: &str) -> Option<&FunctionTool> {
+        self.tools.get(name)
+    }
+
+    /// Get the number of registered tools.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Check if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Get all tool definitions, in registration order.
+    pub fn tool_definitions(&self) -> Vec<ToolDefinition> {
+        self.order
+            .iter()
+            .map(|name| self.tools[name].definition().clone())
+            .collect()
+    }
+
+    /// Get the custom tool definitions, in registration order.
+    pub fn definitions(&self) -> Vec<Tool> {
+        self.order
+            .iter()
+            .filter_map(|name| match self.tools[name].definition() {
+                ToolDefinition::Custom(tool) => Some(tool.clone()),
+                ToolDefinition::Server(_) => None,
+            })
+            .collect()
+    }
+
+    /// Get the Anthropic-defined tool definitions, in registration order.
+    pub fn server_tools(&self) -> Vec<ServerTool> {
+        self.order
+            .iter()
+            .filter_map(|name| match self.tools[name].definition() {
+                ToolDefinition::Server(tool) => Some(tool.clone()),
+                ToolDefinition::Custom(_) => None,
+            })
+            .collect()
+    }
+
+    /// Execute a tool by name.
+    pub async fn execute(&self, name: &str, input: Value) -> Result<String, ToolError> {
+        match self.get(name) {
+            Some(tool) => tool.call(input).await,
+            None => Err(ToolError::new(format!("Unknown tool: {}", name))),
+        }
+    }
+
+    /// Execute a tool call and build its result block.
+    ///
+    /// Unknown tools and handler errors produce an `is_error` result.
+    pub async fn call(&self, id: &str, name: &str, input: &Value) -> ContentBlockParam {
+        match self.execute(name, input.clone()).await {
+            Ok(output) => ContentBlockParam::tool_result(id, output),
+            Err(error) => ContentBlockParam::tool_error(id, error.message()),
+        }
+    }
+
+    /// Execute a tool use block and build its result block.
+    pub async fn dispatch(&self, tool_use: &ToolUseBlock) -> ContentBlockParam {
+        self.call(&tool_use.id, &tool_use.name, &tool_use.input)
+            .await
+    }
+
+    /// Execute every tool use block in a message concurrently.
+    ///
+    /// The results are returned in the order of the tool use blocks.
+    pub async fn dispatch_message(&self, message: &Message) -> Vec<ContentBlockParam> {
+        join_all(message.content.iter().filter_map(|block| match block {
+            ContentBlock::ToolUse { id, name, input } => Some(self.call(id, name, input)),
+            _ => None,
+        }))
+        .await
+    }
+}
+
+impl From<Vec<FunctionTool>> for ToolRegistry {
+    fn from(tools: Vec<FunctionTool>) -> Self {
+        tools.into_iter().collect()
+    }
+}
+
+impl FromIterator<FunctionTool> for ToolRegistry {
+    fn from_iter<I: IntoIterator<Item = FunctionTool>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        for tool in iter {
+            registry.register(tool);
+        }
+        registry
+    }
+}
+
+impl Extend<FunctionTool> for ToolRegistry {
+    fn extend<I: IntoIterator<Item = FunctionTool>>(&mut self, iter: I) {
+        for tool in iter {
+            self.register(tool);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ToolInputSchema, ToolResultContent};
+    use serde_json::json;
+
+    fn echo(name: &str) -> FunctionTool {
+        FunctionTool::new(
+            Tool::new(name, ToolInputSchema::empty()),
+            |input| async move { Ok(input.to_string()) },
+        )
+    }
+
+    fn result_text(block: &ContentBlockParam) -> (&str, bool) {
+        match block {
+            ContentBlockParam::ToolResult {
+                content: Some(ToolResultContent::Text(text)),
+                is_error,
+                ..
+            } => (text, is_error.unwrap_or(false)),
+            other => panic!("unexpected block: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_definitions_keep_registration_order() {
+        let registry = ToolRegistry::new()
+            .tool(echo("b"))
+            .tool(echo("a"))
+            .tool(echo("b"));
+
+        let names: Vec<_> = registry.definitions().into_iter().map(|t| t.name).collect();
+        assert_eq!(names, vec!["b", "a"]);
+    }
+
+    #[tokio::test]
+    async fn test_call_dispatches_by_name() {
+        let registry = ToolRegistry::from(vec![echo("echo")]);
+
+        let result = registry.call("toolu_1", "echo", &json!({ "x": 1 })).await;
+        assert_eq!(result_text(&result), (r#"{"x":1}"#, false));
+
+        let result = registry.call("toolu_2", "missing", &json!({})).await;
+        assert_eq!(result_text(&result), ("Unknown tool: missing", true));
+    }
+
+    #[tokio::test]
+    async fn test_call_reports_timeout() {
+        let slow = FunctionTool::new(
+            Tool::new("slow", ToolInputSchema::empty()),
+            |_input| async {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                Ok("done".to_string())
+            },
+        )
+        .timeout(std::time::Duration::from_millis(10));
+        let registry = ToolRegistry::from(vec![slow]);
+
+        let result = registry.call("toolu_1", "slow", &json!({})).await;
+        assert_eq!(
+            result_text(&result),
+            ("Tool slow timed out after 10ms", true)
+        );
+    }
+}
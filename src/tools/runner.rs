@@ -0,0 +1,488 @@
+//! Tool use loop.
+
+use std::sync::Arc;
+
+use futures::future::try_join_all;
+use serde_json::Value;
+
+use super::{ToolCache, ToolError, ToolRegistry};
+use crate::client::Anthropic;
+use crate::error::{AnthropicError, Result};
+use crate::resources::Messages;
+use crate::types::{
+    ContentBlock, ContentBlockParam, Message, MessageCreateParams, MessageParam, StopReason,
+};
+
+/// What the runner does when a tool handler returns an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToolErrorPolicy {
+    /// Send the error to the model as an `is_error` tool result.
+    #[default]
+    SurfaceToModel,
+
+    /// Run the handler again up to `retries` more times, then send the last
+    /// error to the model.
+    RetryN { retries: u32 },
+
+    /// Stop the loop and return [`AnthropicError::Tool`].
+    Abort,
+}
+
+/// The outcome of a completed tool use loop.
+#[derive(Debug, Clone)]
+pub struct ToolRunResult {
+    /// The final message, which did not stop for tool use.
+    pub message: Message,
+
+    /// The full conversation, starting with the request messages and ending
+    /// with the final assistant message.
+    pub messages: Vec<MessageParam>,
+}
+
+/// Hook called with each response from the model.
+type MessageHook = Arc<dyn Fn(&Message) + Send + Sync>;
+
+/// Hook called with the name and input of each tool call.
+type ToolCallHook = Arc<dyn Fn(&str, &Value) + Send + Sync>;
+
+/// Hook called with the name and final outcome of each tool call.
+type ToolResultHook = Arc<dyn Fn(&str, &std::result::Result<String, ToolError>) + Send + Sync>;
+
+/// Runs the tool use loop against the Messages API.
+///
+/// Created with [`Messages::tool_runner`].
+pub struct ToolRunner<'a> {
+    messages: Messages<'a>,
+    registry: ToolRegistry,
+    error_policy: ToolErrorPolicy,
+    max_iterations: Option<u32>,
+    cache: Option<ToolCache>,
+    on_message: Option<MessageHook>,
+    on_tool_call: Option<ToolCallHook>,
+    on_tool_result: Option<ToolResultHook>,
+}
+
+impl<'a> ToolRunner<'a> {
+    /// Create a new tool runner.
+    pub(crate) fn new(messages: Messages<'a>, registry: ToolRegistry) -> Self {
+        Self {
+            messages,
+            registry,
+            error_policy: ToolErrorPolicy::default(),
+            max_iterations: None,
+            cache: None,
+            on_message: None,
+            on_tool_call: None,
+            on_tool_result: None,
+        }
+    }
+
+    /// Set what happens when a tool handler returns an error.
+    pub fn error_policy(mut self, policy: ToolErrorPolicy) -> Self {
+        self.error_policy = policy;
+        self
+    }
+
+    /// Limit the number of requests sent to the model.
+    ///
+    /// When the response to the last allowed request still asks for tool use,
+    /// the run stops with [`AnthropicError::MaxIterationsExceeded`] instead of
+    /// executing the tools.
+    pub fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = Some(max_iterations);
+        self
+    }
+
+    /// Reuse successful results of identical tool calls from `cache`.
+    ///
+    /// A call is identical when the tool name and input JSON match.
+    pub fn cache(mut self, cache: ToolCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Call `hook` with every response from the model, including the final one.
+    pub fn on_message<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Message) + Send + Sync + 'static,
+    {
+        self.on_message = Some(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` with the tool name and input before each tool call runs.
+    pub fn on_tool_call<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, &Value) + Send + Sync + 'static,
+    {
+        self.on_tool_call = Some(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` with the tool name and outcome after each tool call, once
+    /// the error policy's retries are used up.
+    pub fn on_tool_result<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, &std::result::Result<String, ToolError>) + Send + Sync + 'static,
+    {
+        self.on_tool_result = Some(Arc::new(hook));
+        self
+    }
+
+    /// Get the tools this runner executes.
+    pub fn registry(&self) -> &ToolRegistry {
+        &self.registry
+    }
+
+    /// Run the loop until the model stops for a reason other than tool use.
+    ///
+    /// The tool definitions are appended to `params.tools`. Tool calls within
+    /// one response run concurrently, and their results are sent back in a
+    /// single user message.
+    pub async fn run(&self, params: MessageCreateParams) -> Result<ToolRunResult> {
+        let mut params = params;
+        params
+            .tools
+            .get_or_insert_with(Vec::new)
+            .extend(self.registry.tool_definitions());
+
+        let mut iterations = 0;
+        loop {
+            let message = self.messages.create(params.clone()).await?;
+            iterations += 1;
+            if let Some(hook) = &self.on_message {
+                hook(&message);
+            }
+            params.messages.push(message.clone().into());
+
+            if message.stop_reason != Some(StopReason::ToolUse) {
+                return Ok(ToolRunResult {
+                    message,
+                    messages: params.messages,
+                });
+            }
+
+            if let Some(max_iterations) = self.max_iterations {
+                if iterations >= max_iterations {
+                    return Err(AnthropicError::MaxIterationsExceeded { max_iterations });
+                }
+            }
+
+            let results = try_join_all(message.content.iter().filter_map(|block| match block {
+                ContentBlock::ToolUse { id, name, input } => Some(self.call(id, name, input)),
+                _ => None,
+            }))
+            .await?;
+            params
+                .messages
+                .push(MessageParam::user_with_blocks(results));
+        }
+    }
+
+    /// Execute one tool call under the error policy and build its result block.
+    async fn call(&self, id: &str, name: &str, input: &Value) -> Result<ContentBlockParam> {
+        let retries = match self.error_policy {
+            ToolErrorPolicy::RetryN { retries } => retries,
+            _ => 0,
+        };
+
+        if let Some(hook) = &self.on_tool_call {
+            hook(name, input);
+        }
+
+        let cached = self.cache.as_ref().and_then(|cache| cache.get(name, input));
+        let outcome = match cached {
+            Some(output) => Ok(output),
+            None => {
+                let mut attempt = 0;
+                let outcome = loop {
+                    match self.registry.execute(name, input.clone()).await {
+                        Err(_) if attempt < retries => attempt += 1,
+                        outcome => break outcome,
+                    }
+                };
+                if let (Some(cache), Ok(output)) = (&self.cache, &outcome) {
+                    cache.insert(name, input, output.clone());
+                }
+                outcome
+            }
+        };
+
+        if let Some(hook) = &self.on_tool_result {
+            hook(name, &outcome);
+        }
+
+        let error = match outcome {
+            Ok(output) => return Ok(ContentBlockParam::tool_result(id, output)),
+            Err(error) => error,
+        };
+
+        match self.error_policy {
+            ToolErrorPolicy::Abort => Err(AnthropicError::Tool {
+                name: name.to_string(),
+                message: error.message().to_string(),
+            }),
+            _ => Ok(ContentBlockParam::tool_error(id, error.message())),
+        }
+    }
+}
+
+/// Runs the tool use loop against the Messages API (blocking).
+///
+/// Created with [`BlockingMessages::tool_runner`](crate::BlockingMessages::tool_runner).
+/// Tool handlers run on the client's runtime.
+pub struct BlockingToolRunner<'a> {
+    client: &'a Anthropic,
+    inner: ToolRunner<'a>,
+}
+
+impl<'a> BlockingToolRunner<'a> {
+    /// Create a new blocking tool runner.
+    pub(crate) fn new(client: &'a Anthropic, inner: ToolRunner<'a>) -> Self {
+        Self { client, inner }
+    }
+
+    /// Get the tools this runner executes.
+    pub fn registry(&self) -> &ToolRegistry {
+        self.inner.registry()
+    }
+
+    /// Set what happens when a tool handler returns an error.
+    pub fn error_policy(mut self, policy: ToolErrorPolicy) -> Self {
+        self.inner = self.inner.error_policy(policy);
+        self
+    }
+
+    /// Limit the number of requests sent to the model.
+    pub fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.inner = self.inner.max_iterations(max_iterations);
+        self
+    }
+
+    /// Reuse successful results of identical tool calls from `cache`.
+    pub fn cache(mut self, cache: ToolCache) -> Self {
+        self.inner = self.inner.cache(cache);
+        self
+    }
+
+    /// Call `hook` with every response from the model, including the final one.
+    pub fn on_message<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&Message) + Send + Sync + 'static,
+    {
+        self.inner = self.inner.on_message(hook);
+        self
+    }
+
+    /// Call `hook` with the tool name and input before each tool call runs.
+    pub fn on_tool_call<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, &Value) + Send + Sync + 'static,
+    {
+        self.inner = self.inner.on_tool_call(hook);
+        self
+    }
+
+    /// Call `hook` with the tool name and outcome after each tool call.
+    pub fn on_tool_result<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, &std::result::Result<String, ToolError>) + Send + Sync + 'static,
+    {
+        self.inner = self.inner.on_tool_result(hook);
+        self
+    }
+
+    /// Run the loop until the model stops for a reason other than tool use.
+    pub fn run(&self, params: MessageCreateParams) -> Result<ToolRunResult> {
+        self.client.block_on(self.inner.run(params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use crate::client::Replay;
+    use crate::tools::FunctionTool;
+    use crate::types::{Tool, ToolInputSchema};
+    use crate::{AsyncAnthropic, ClientConfig};
+
+    const TOOL_USE: &str = r#"{"id":"msg_1","type":"message","role":"assistant","model":"claude-sonnet-4-5",
+        "content":[{"type":"tool_use","id":"toolu_1","name":"lookup","input":{"q":"rust"}}],
+        "stop_reason":"tool_use","usage":{"input_tokens":1,"output_tokens":1}}"#;
+
+    const DONE: &str = r#"{"id":"msg_2","type":"message","role":"assistant","model":"claude-sonnet-4-5",
+        "content":[{"type":"text","text":"Done."}],"stop_reason":"end_turn",
+        "usage":{"input_tokens":1,"output_tokens":1}}"#;
+
+    fn client_for(replay: &Arc<Replay>) -> AsyncAnthropic {
+        AsyncAnthropic::with_config(ClientConfig::with_api_key("sk-test").transport(replay.clone()))
+            .unwrap()
+    }
+
+    fn params() -> MessageCreateParams {
+        MessageCreateParams::builder()
+            .max_tokens(16)
+            .message(MessageParam::user("Look up rust."))
+            .build()
+    }
+
+    /// A `lookup` tool that fails its first `failures` calls, counting calls.
+    fn lookup(failures: u32, calls: Arc<AtomicU32>) -> ToolRegistry {
+        ToolRegistry::new().tool(FunctionTool::new(
+            Tool::new("lookup", ToolInputSchema::empty()),
+            move |_input| {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if call < failures {
+                        Err(ToolError::new("unavailable"))
+                    } else {
+                        Ok("found".to_string())
+                    }
+                }
+            },
+        ))
+    }
+
+    /// The tool result sent back in the second request.
+    fn tool_result(replay: &Replay) -> Value {
+        replay.bodies()[1]["messages"][2]["content"][0].clone()
+    }
+
+    #[tokio::test]
+    async fn test_error_policies() {
+        let replay = Replay::new(vec![(200, TOOL_USE), (200, DONE)]);
+        let calls = Arc::new(AtomicU32::new(0));
+        let client = client_for(&replay);
+        let result = client
+            .messages()
+            .tool_runner(lookup(u32::MAX, calls.clone()))
+            .run(params())
+            .await
+            .unwrap();
+        assert_eq!(result.message.text(), "Done.");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        let sent = tool_result(&replay);
+        assert_eq!(sent["is_error"], true);
+        assert_eq!(sent["content"], "unavailable");
+
+        let replay = Replay::new(vec![(200, TOOL_USE), (200, DONE)]);
+        let calls = Arc::new(AtomicU32::new(0));
+        let client = client_for(&replay);
+        client
+            .messages()
+            .tool_runner(lookup(2, calls.clone()))
+            .error_policy(ToolErrorPolicy::RetryN { retries: 2 })
+            .run(params())
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(tool_result(&replay)["content"], "found");
+
+        let replay = Replay::new(vec![(200, TOOL_USE)]);
+        let client = client_for(&replay);
+        let error = client
+            .messages()
+            .tool_runner(lookup(1, Arc::default()))
+            .error_policy(ToolErrorPolicy::Abort)
+            .run(params())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, AnthropicError::Tool { name, .. } if name == "lookup"));
+        assert_eq!(replay.requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_max_iterations() {
+        let replay = Replay::new(vec![(200, TOOL_USE), (200, TOOL_USE)]);
+        let calls = Arc::new(AtomicU32::new(0));
+        let client = client_for(&replay);
+        let error = client
+            .messages()
+            .tool_runner(lookup(0, calls.clone()))
+            .max_iterations(2)
+            .run(params())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            AnthropicError::MaxIterationsExceeded { max_iterations: 2 }
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(replay.requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hook_order() {
+        let replay = Replay::new(vec![(200, TOOL_USE), (200, DONE)]);
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let record = |log: &Arc<Mutex<Vec<String>>>| {
+            let log = log.clone();
+            move |entry: String| log.lock().unwrap().push(entry)
+        };
+        let registry = ToolRegistry::new().tool(FunctionTool::new(
+            Tool::new("lookup", ToolInputSchema::empty()),
+            {
+                let record = record(&log);
+                move |_input| {
+                    record("execute".into());
+                    async { Ok("found".to_string()) }
+                }
+            },
+        ));
+
+        let client = client_for(&replay);
+        client
+            .messages()
+            .tool_runner(registry)
+            .on_message({
+                let record = record(&log);
+                move |message| record(format!("message {}", message.id))
+            })
+            .on_tool_call({
+                let record = record(&log);
+                move |name, input| record(format!("call {} {}", name, input))
+            })
+            .on_tool_result({
+                let record = record(&log);
+                move |name, outcome| record(format!("result {} {:?}", name, outcome.is_ok()))
+            })
+            .run(params())
+            .await
+            .unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "message msg_1",
+                r#"call lookup {"q":"rust"}"#,
+                "execute",
+                "result lookup true",
+                "message msg_2",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cache_hit_skips_execution() {
+        let replay = Replay::new(vec![(200, TOOL_USE), (200, DONE)]);
+        let calls = Arc::new(AtomicU32::new(0));
+        let cache = ToolCache::new(Duration::from_secs(60));
+        cache.insert("lookup", &json!({ "q": "rust" }), "cached".into());
+
+        let client = client_for(&replay);
+        client
+            .messages()
+            .tool_runner(lookup(0, calls.clone()))
+            .cache(cache)
+            .run(params())
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(tool_result(&replay)["content"], "cached");
+    }
+}
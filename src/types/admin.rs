@@ -0,0 +1,444 @@
+//! Admin API types.
+
+use serde::{Deserialize, Serialize};
+
+/// The actor that created an object.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Actor {
+    /// ID of the actor.
+    pub id: String,
+
+    /// Type of the actor (e.g. "user").
+    #[serde(rename = "type")]
+    pub actor_type: String,
+}
+
+/// Status of an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyStatus {
+    /// The key can be used.
+    Active,
+    /// The key is disabled but can be re-enabled.
+    Inactive,
+    /// The key is permanently disabled.
+    Archived,
+}
+
+impl ApiKeyStatus {
+    /// Get the wire representation of the status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyStatus::Active => "active",
+            ApiKeyStatus::Inactive => "inactive",
+            ApiKeyStatus::Archived => "archived",
+        }
+    }
+}
+
+/// An organization API key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// Unique API key identifier.
+    pub id: String,
+
+    /// Object type, always "api_key".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// Name of the API key.
+    pub name: String,
+
+    /// ID of the workspace the key belongs to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+
+    /// When the key was created (RFC 3339).
+    pub created_at: String,
+
+    /// Who created the key.
+    pub created_by: Actor,
+
+    /// Partially redacted hint of the key value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partial_key_hint: Option<String>,
+
+    /// Status of the key.
+    pub status: ApiKeyStatus,
+}
+
+/// List of API keys response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyList {
+    /// The list of API keys.
+    pub data: Vec<ApiKey>,
+
+    /// Whether there are more API keys.
+    pub has_more: bool,
+
+    /// Cursor for the first item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+
+    /// Cursor for the last item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing API keys.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListApiKeysParams {
+    /// Number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor for pagination (before this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Cursor for pagination (after this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+
+    /// Only return keys with this status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApiKeyStatus>,
+
+    /// Only return keys in this workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+
+    /// Only return keys created by this user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by_user_id: Option<String>,
+}
+
+/// Parameters for updating an API key.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiKeyUpdateParams {
+    /// New name for the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// New status for the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApiKeyStatus>,
+}
+
+impl ApiKeyUpdateParams {
+    /// Set the new name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the new status.
+    pub fn status(mut self, status: ApiKeyStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// A workspace within the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workspace {
+    /// Unique workspace identifier.
+    pub id: String,
+
+    /// Object type, always "workspace".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// Name of the workspace.
+    pub name: String,
+
+    /// When the workspace was created (RFC 3339).
+    pub created_at: String,
+
+    /// When the workspace was archived (RFC 3339), if it has been.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+
+    /// Hex color code used to represent the workspace in the Console.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_color: Option<String>,
+}
+
+impl Workspace {
+    /// Check if the workspace has been archived.
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+/// List of workspaces response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceList {
+    /// The list of workspaces.
+    pub data: Vec<Workspace>,
+
+    /// Whether there are more workspaces.
+    pub has_more: bool,
+
+    /// Cursor for the first item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+
+    /// Cursor for the last item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing workspaces.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListWorkspacesParams {
+    /// Number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor for pagination (before this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Cursor for pagination (after this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+
+    /// Whether to include archived workspaces.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_archived: Option<bool>,
+}
+
+/// Parameters for creating or updating a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceParams {
+    /// Name of the workspace.
+    pub name: String,
+}
+
+impl WorkspaceParams {
+    /// Create workspace params with a name.
+    pub fn new(name: impl Into<String>) -> Self {
+        WorkspaceParams { name: name.into() }
+    }
+}
+
+/// Role of a member within a workspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceRole {
+    /// Can use the workspace.
+    WorkspaceUser,
+    /// Can use the workspace and manage its API keys.
+    WorkspaceDeveloper,
+    /// Full control over the workspace.
+    WorkspaceAdmin,
+    /// Can manage billing for the workspace.
+    WorkspaceBilling,
+}
+
+/// A member of a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceMember {
+    /// Object type, always "workspace_member".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// ID of the user.
+    pub user_id: String,
+
+    /// ID of the workspace.
+    pub workspace_id: String,
+
+    /// Role of the user within the workspace.
+    pub workspace_role: WorkspaceRole,
+}
+
+/// List of workspace members response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceMemberList {
+    /// The list of members.
+    pub data: Vec<WorkspaceMember>,
+
+    /// Whether there are more members.
+    pub has_more: bool,
+
+    /// Cursor for the first item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+
+    /// Cursor for the last item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing workspace members.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListWorkspaceMembersParams {
+    /// Number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor for pagination (before this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Cursor for pagination (after this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+}
+
+/// Parameters for adding a member to a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceMemberAddParams {
+    /// ID of the user to add.
+    pub user_id: String,
+
+    /// Role to grant the user.
+    pub workspace_role: WorkspaceRole,
+}
+
+impl WorkspaceMemberAddParams {
+    /// Create params to add a user with a role.
+    pub fn new(user_id: impl Into<String>, workspace_role: WorkspaceRole) -> Self {
+        WorkspaceMemberAddParams {
+            user_id: user_id.into(),
+            workspace_role,
+        }
+    }
+}
+
+/// Response from removing a workspace member.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedWorkspaceMember {
+    /// Object type, always "workspace_member_deleted".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// ID of the removed user.
+    pub user_id: String,
+
+    /// ID of the workspace.
+    pub workspace_id: String,
+}
+
+/// Role of a user within the organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationRole {
+    /// Regular user.
+    User,
+    /// Developer with API key access.
+    Developer,
+    /// Billing manager.
+    Billing,
+    /// Organization administrator.
+    Admin,
+    /// Claude Code user.
+    ClaudeCodeUser,
+}
+
+/// Status of an organization invite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InviteStatus {
+    /// The invite has been accepted.
+    Accepted,
+    /// The invite expired before being accepted.
+    Expired,
+    /// The invite was deleted.
+    Deleted,
+    /// The invite is awaiting a response.
+    Pending,
+}
+
+/// An invitation to join the organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invite {
+    /// Unique invite identifier.
+    pub id: String,
+
+    /// Object type, always "invite".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// Email address of the invited user.
+    pub email: String,
+
+    /// Organization role granted on acceptance.
+    pub role: OrganizationRole,
+
+    /// When the invite was sent (RFC 3339).
+    pub invited_at: String,
+
+    /// When the invite expires (RFC 3339).
+    pub expires_at: String,
+
+    /// Status of the invite.
+    pub status: InviteStatus,
+}
+
+/// List of invites response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteList {
+    /// The list of invites.
+    pub data: Vec<Invite>,
+
+    /// Whether there are more invites.
+    pub has_more: bool,
+
+    /// Cursor for the first item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+
+    /// Cursor for the last item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing invites.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListInvitesParams {
+    /// Number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor for pagination (before this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Cursor for pagination (after this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+}
+
+/// Parameters for creating an invite.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteCreateParams {
+    /// Email address of the user to invite.
+    pub email: String,
+
+    /// Organization role to grant.
+    pub role: OrganizationRole,
+}
+
+impl InviteCreateParams {
+    /// Create params to invite an email address with a role.
+    pub fn new(email: impl Into<String>, role: OrganizationRole) -> Self {
+        InviteCreateParams {
+            email: email.into(),
+            role,
+        }
+    }
+}
+
+/// Response from deleting an invite.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedInvite {
+    /// ID of the deleted invite.
+    pub id: String,
+
+    /// Object type, always "invite_deleted".
+    #[serde(rename = "type")]
+    pub object_type: String,
+}
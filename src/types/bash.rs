@@ -0,0 +1,55 @@
+//! Bash tool types.
+
+use serde::{Deserialize, Serialize};
+
+use super::CacheControl;
+
+/// The bash tool (`bash_20250124`).
+///
+/// The model runs shell commands in a persistent session, which the caller
+/// executes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BashTool {
+    /// The tool name, always `bash`.
+    pub name: String,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl BashTool {
+    /// Create a bash tool.
+    pub fn new() -> Self {
+        BashTool {
+            name: "bash".into(),
+            cache_control: None,
+        }
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+impl Default for BashTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The input of a bash tool call.
+///
+/// Either `command` is set, or `restart` asks for a fresh shell session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BashCommand {
+    /// The command to run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Whether to restart the shell session.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub restart: bool,
+}
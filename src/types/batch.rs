@@ -0,0 +1,165 @@
+//! Message Batches types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Message, MessageCreateParams};
+use crate::error::ErrorResponse;
+
+/// Parameters for creating a message batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCreateParams {
+    /// The requests to process in this batch.
+    pub requests: Vec<BatchRequest>,
+}
+
+impl BatchCreateParams {
+    /// Create batch params from a list of requests.
+    pub fn new(requests: Vec<BatchRequest>) -> Self {
+        BatchCreateParams { requests }
+    }
+
+    /// Add a single request to the batch.
+    pub fn request(mut self, custom_id: impl Into<String>, params: MessageCreateParams) -> Self {
+        self.requests.push(BatchRequest::new(custom_id, params));
+        self
+    }
+}
+
+/// A single request within a message batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchRequest {
+    /// Developer-provided ID used to match results to requests.
+    pub custom_id: String,
+
+    /// The Messages API parameters for this request.
+    pub params: MessageCreateParams,
+}
+
+impl BatchRequest {
+    /// Create a new batch request.
+    pub fn new(custom_id: impl Into<String>, params: MessageCreateParams) -> Self {
+        BatchRequest {
+            custom_id: custom_id.into(),
+            params,
+        }
+    }
+}
+
+/// Processing status of a message batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchProcessingStatus {
+    /// The batch is still being processed.
+    InProgress,
+    /// Cancellation has been initiated.
+    Canceling,
+    /// Processing has ended and results are available.
+    Ended,
+}
+
+/// A message batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageBatch {
+    /// Unique object identifier.
+    pub id: String,
+
+    /// Object type, always "message_batch".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// Processing status of the batch.
+    pub processing_status: BatchProcessingStatus,
+
+    /// Tallies of requests in the batch by status.
+    pub request_counts: BatchRequestCounts,
+
+    /// When processing ended (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<String>,
+
+    /// When the batch was created (RFC 3339).
+    pub created_at: String,
+
+    /// When the batch will expire (RFC 3339).
+    pub expires_at: String,
+
+    /// When the batch was archived (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+
+    /// When cancellation was initiated (RFC 3339).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_initiated_at: Option<String>,
+
+    /// URL of the `.jsonl` results file, available once processing has ended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results_url: Option<String>,
+}
+
+impl MessageBatch {
+    /// Check if the batch has finished processing.
+    pub fn is_ended(&self) -> bool {
+        self.processing_status == BatchProcessingStatus::Ended
+    }
+}
+
+/// Tallies of requests in a message batch by status.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchRequestCounts {
+    /// Requests still being processed.
+    pub processing: u32,
+
+    /// Requests that completed successfully.
+    pub succeeded: u32,
+
+    /// Requests that encountered an error.
+    pub errored: u32,
+
+    /// Requests that were canceled.
+    pub canceled: u32,
+
+    /// Requests that expired before processing.
+    pub expired: u32,
+}
+
+/// A single line of a message batch results file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchIndividualResponse {
+    /// Developer-provided ID of the request this result belongs to.
+    pub custom_id: String,
+
+    /// The outcome of the request.
+    pub result: BatchResult,
+}
+
+/// The outcome of a single request within a message batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BatchResult {
+    /// The request completed successfully.
+    Succeeded { message: Message },
+
+    /// The request encountered an error.
+    Errored { error: ErrorResponse },
+
+    /// The request was canceled before processing.
+    Canceled,
+
+    /// The request expired before processing.
+    Expired,
+}
+
+impl BatchResult {
+    /// Get the message if the request succeeded.
+    pub fn as_message(&self) -> Option<&Message> {
+        match self {
+            BatchResult::Succeeded { message } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Check if the request succeeded.
+    pub fn is_succeeded(&self) -> bool {
+        matches!(self, BatchResult::Succeeded { .. })
+    }
+}
@@ -0,0 +1,49 @@
+//! Beta feature flags.
+
+// Beta flag constants for convenience
+pub mod betas {
+    /// Files API.
+    pub const FILES_API: &str = "files-api-2025-04-14";
+
+    /// Message Batches API.
+    pub const MESSAGE_BATCHES: &str = "message-batches-2024-09-24";
+
+    /// Prompt caching.
+    pub const PROMPT_CACHING: &str = "prompt-caching-2024-07-31";
+
+    /// PDF document support.
+    pub const PDFS: &str = "pdfs-2024-09-25";
+
+    /// Token counting.
+    pub const TOKEN_COUNTING: &str = "token-counting-2024-11-01";
+
+    /// Interleaved thinking between tool calls.
+    pub const INTERLEAVED_THINKING: &str = "interleaved-thinking-2025-05-14";
+
+    /// Computer use tool (`computer_20250124`).
+    pub const COMPUTER_USE: &str = "computer-use-2025-01-24";
+
+    /// Code execution tool (`code_execution_20250522`).
+    pub const CODE_EXECUTION: &str = "code-execution-2025-05-22";
+
+    /// Context management, including the memory tool (`memory_20250818`).
+    pub const CONTEXT_MANAGEMENT: &str = "context-management-2025-06-27";
+
+    /// MCP connector for remote MCP servers.
+    pub const MCP_CLIENT: &str = "mcp-client-2025-04-04";
+
+    /// Token-efficient tool use for Claude 3.7 Sonnet.
+    pub const TOKEN_EFFICIENT_TOOLS: &str = "token-efficient-tools-2025-02-19";
+
+    /// 1M token context window for Claude Sonnet 4 and 4.5.
+    pub const CONTEXT_1M: &str = "context-1m-2025-08-07";
+
+    /// Up to 128K output tokens for Claude 3.7 Sonnet.
+    pub const OUTPUT_128K: &str = "output-128k-2025-02-19";
+
+    /// Structured outputs with `output_format`.
+    pub const STRUCTURED_OUTPUTS: &str = "structured-outputs-2025-11-13";
+
+    /// Experimental prompt generation, improvement and templatization.
+    pub const PROMPT_TOOLS: &str = "prompt-tools-2025-04-02";
+}
@@ -0,0 +1,174 @@
+//! Computer use tool types.
+
+use serde::{Deserialize, Serialize};
+
+use super::CacheControl;
+
+/// The computer use tool (`computer_20250124`).
+///
+/// The model controls a desktop through screenshots and mouse and keyboard
+/// actions, which the caller executes. Requires the
+/// [`COMPUTER_USE`](super::betas::COMPUTER_USE) beta, which is sent
+/// automatically when this tool is in the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComputerTool {
+    /// The tool name, always `computer`.
+    pub name: String,
+
+    /// Width of the display in pixels.
+    pub display_width_px: u32,
+
+    /// Height of the display in pixels.
+    pub display_height_px: u32,
+
+    /// X11 display number, for environments with several displays.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_number: Option<u32>,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl ComputerTool {
+    /// Create a computer use tool for a display of the given size.
+    pub fn new(display_width_px: u32, display_height_px: u32) -> Self {
+        ComputerTool {
+            name: "computer".into(),
+            display_width_px,
+            display_height_px,
+            display_number: None,
+            cache_control: None,
+        }
+    }
+
+    /// Set the X11 display number.
+    pub fn display_number(mut self, display_number: u32) -> Self {
+        self.display_number = Some(display_number);
+        self
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+/// A pixel position as `[x, y]`.
+pub type Coordinate = [u32; 2];
+
+/// An action requested by the computer use tool.
+///
+/// Parse it from a `tool_use` block with
+/// [`ToolUseBlock::parse_input`](super::ToolUseBlock::parse_input).
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::types::ComputerAction;
+/// use serde_json::json;
+///
+/// let action: ComputerAction =
+///     serde_json::from_value(json!({ "action": "left_click", "coordinate": [640, 400] })).unwrap();
+///
+/// match action {
+///     ComputerAction::LeftClick { coordinate, .. } => assert_eq!(coordinate, Some([640, 400])),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ComputerAction {
+    /// Press a key or key combination, e.g. `ctrl+s`.
+    Key { text: String },
+
+    /// Type a string of text.
+    Type { text: String },
+
+    /// Move the cursor.
+    MouseMove { coordinate: Coordinate },
+
+    /// Click the left mouse button, optionally while holding `text` keys.
+    LeftClick {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coordinate: Option<Coordinate>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Click the right mouse button.
+    RightClick {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coordinate: Option<Coordinate>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Click the middle mouse button.
+    MiddleClick {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coordinate: Option<Coordinate>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Double click the left mouse button.
+    DoubleClick {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coordinate: Option<Coordinate>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Triple click the left mouse button.
+    TripleClick {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        coordinate: Option<Coordinate>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Drag with the left mouse button held down.
+    LeftClickDrag {
+        start_coordinate: Coordinate,
+        coordinate: Coordinate,
+    },
+
+    /// Press the left mouse button without releasing it.
+    LeftMouseDown,
+
+    /// Release the left mouse button.
+    LeftMouseUp,
+
+    /// Scroll at a position.
+    Scroll {
+        coordinate: Coordinate,
+        scroll_direction: ScrollDirection,
+        scroll_amount: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+
+    /// Hold a key down for `duration` seconds.
+    HoldKey { text: String, duration: f64 },
+
+    /// Wait for `duration` seconds.
+    Wait { duration: f64 },
+
+    /// Take a screenshot of the display.
+    Screenshot,
+
+    /// Report the current cursor position.
+    CursorPosition,
+}
+
+/// Direction of a [`ComputerAction::Scroll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
@@ -1,11 +1,24 @@
 //! Content block types.
 
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
+use super::{parse_tool_input, unknown_fallback};
+use crate::error::Result;
+
 /// A content block in a message response.
+///
+/// Blocks of a type added to the API after this SDK are kept as
+/// [`Unknown`](Self::Unknown).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+#[non_exhaustive]
 pub enum ContentBlock {
     /// Text content block.
     Text {
@@ -37,8 +50,83 @@ pub enum ContentBlock {
     /// Web search tool result content block.
     WebSearchToolResult {
         tool_use_id: String,
-        content: Vec<WebSearchResult>,
+        content: WebSearchToolResultContent,
+    },
+
+    /// Code execution tool result content block.
+    CodeExecutionToolResult {
+        tool_use_id: String,
+        content: CodeExecutionToolResultContent,
+    },
+
+    /// Result of a bash command run by the code execution tool.
+    ///
+    /// The content is kept as JSON: a `bash_code_execution_result` with
+    /// `stdout`, `stderr` and `return_code`, or an error.
+    BashCodeExecutionToolResult { tool_use_id: String, content: Value },
+
+    /// Result of a file operation by the code execution tool.
+    ///
+    /// The content is kept as JSON, as it depends on the operation.
+    TextEditorCodeExecutionToolResult { tool_use_id: String, content: Value },
+
+    /// Web fetch tool result content block.
+    ///
+    /// The content is kept as JSON: the fetched document, or an error.
+    WebFetchToolResult { tool_use_id: String, content: Value },
+
+    /// A call to a tool on a remote MCP server.
+    McpToolUse {
+        id: String,
+        name: String,
+        server_name: String,
+        input: Value,
+    },
+
+    /// The result of a call to a tool on a remote MCP server.
+    McpToolResult {
+        tool_use_id: String,
+        #[serde(default)]
+        is_error: bool,
+        content: McpToolResultContent,
     },
+
+    /// A file uploaded to the code execution container.
+    ContainerUpload { file_id: String },
+
+    /// A block of a type this SDK does not know, kept as its raw JSON.
+    #[serde(skip)]
+    Unknown(Value),
+}
+
+unknown_fallback!(
+    ContentBlock,
+    [
+        "text",
+        "thinking",
+        "redacted_thinking",
+        "tool_use",
+        "server_tool_use",
+        "web_search_tool_result",
+        "code_execution_tool_result",
+        "bash_code_execution_tool_result",
+        "text_editor_code_execution_tool_result",
+        "web_fetch_tool_result",
+        "mcp_tool_use",
+        "mcp_tool_result",
+        "container_upload",
+    ]
+);
+
+/// The content of an MCP tool result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum McpToolResultContent {
+    /// Plain text.
+    Text(String),
+
+    /// Content blocks, usually text.
+    Blocks(Vec<ContentBlock>),
 }
 
 impl ContentBlock {
@@ -58,6 +146,16 @@ impl ContentBlock {
         }
     }
 
+    /// Deserialize the input into `T` if this is a tool use block.
+    ///
+    /// See [`ToolUseBlock::parse_input`](super::ToolUseBlock::parse_input).
+    pub fn parse_input<T: DeserializeOwned>(&self) -> Option<Result<T>> {
+        match self {
+            ContentBlock::ToolUse { name, input, .. } => Some(parse_tool_input(name, input)),
+            _ => None,
+        }
+    }
+
     /// Check if this is a text block.
     pub fn is_text(&self) -> bool {
         matches!(self, ContentBlock::Text { .. })
@@ -70,12 +168,18 @@ impl ContentBlock {
 }
 
 /// A content block parameter for request messages.
+///
+/// Blocks without a variant here, such as the server tool blocks of an
+/// assistant turn being replayed, are sent as [`Unknown`](Self::Unknown).
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
 pub enum ContentBlockParam {
     /// Text content block.
     Text {
         text: String,
+        /// Citations of a text block from a response, sent back with it.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        citations: Option<Vec<Citation>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         cache_control: Option<CacheControl>,
     },
@@ -90,6 +194,27 @@ pub enum ContentBlockParam {
     /// Document content block (PDF).
     Document {
         source: DocumentSource,
+        /// Title of the document, returned in citations of it.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        /// Context about the document, such as where it came from, which the
+        /// model reads but does not cite.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        context: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+
+    /// Search result content block, for results retrieved by your own
+    /// search so the model can cite them.
+    SearchResult {
+        /// Where the result came from, such as a URL or document ID.
+        source: String,
+        title: String,
+        /// Text blocks holding the result.
+        content: Vec<ContentBlockParam>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        citations: Option<CitationsConfig>,
         #[serde(skip_serializing_if = "Option::is_none")]
         cache_control: Option<CacheControl>,
     },
@@ -101,6 +226,15 @@ pub enum ContentBlockParam {
         input: Value,
     },
 
+    /// Extended thinking block (for assistant messages in multi-turn).
+    ///
+    /// Thinking blocks must be sent back unchanged, with their signature, when
+    /// an assistant turn that used tools is replayed.
+    Thinking { thinking: String, signature: String },
+
+    /// Redacted thinking block (for assistant messages in multi-turn).
+    RedactedThinking { data: String },
+
     /// Tool result block.
     ToolResult {
         tool_use_id: String,
@@ -111,13 +245,32 @@ pub enum ContentBlockParam {
         #[serde(skip_serializing_if = "Option::is_none")]
         cache_control: Option<CacheControl>,
     },
+
+    /// A block of another type, sent as its raw JSON.
+    #[serde(skip)]
+    Unknown(Value),
 }
 
+unknown_fallback!(
+    ContentBlockParam,
+    [
+        "text",
+        "image",
+        "document",
+        "search_result",
+        "tool_use",
+        "thinking",
+        "redacted_thinking",
+        "tool_result",
+    ]
+);
+
 impl ContentBlockParam {
     /// Create a text content block.
     pub fn text(text: impl Into<String>) -> Self {
         ContentBlockParam::Text {
             text: text.into(),
+            citations: None,
             cache_control: None,
         }
     }
@@ -126,21 +279,39 @@ impl ContentBlockParam {
     pub fn text_with_cache(text: impl Into<String>) -> Self {
         ContentBlockParam::Text {
             text: text.into(),
+            citations: None,
             cache_control: Some(CacheControl::ephemeral()),
         }
     }
 
     /// Create an image content block from base64 data.
-    pub fn image_base64(media_type: impl Into<String>, data: impl Into<String>) -> Self {
+    pub fn image_base64(media_type: ImageMediaType, data: impl Into<String>) -> Self {
         ContentBlockParam::Image {
             source: ImageSource::Base64 {
-                media_type: media_type.into(),
+                media_type,
                 data: data.into(),
             },
             cache_control: None,
         }
     }
 
+    /// Create an image content block from raw image bytes, base64-encoding
+    /// them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anthropic_sdk::types::ImageMediaType;
+    /// use anthropic_sdk::ContentBlockParam;
+    ///
+    /// let bytes = std::fs::read("chart.png")?;
+    /// let image = ContentBlockParam::image_bytes(ImageMediaType::Png, &bytes);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn image_bytes(media_type: ImageMediaType, data: impl AsRef<[u8]>) -> Self {
+        Self::image_base64(media_type, BASE64.encode(data))
+    }
+
     /// Create an image content block from a URL.
     pub fn image_url(url: impl Into<String>) -> Self {
         ContentBlockParam::Image {
@@ -149,6 +320,156 @@ impl ContentBlockParam {
         }
     }
 
+    /// Create an image content block from a Files API upload.
+    pub fn image_file(file_id: impl Into<String>) -> Self {
+        ContentBlockParam::Image {
+            source: ImageSource::File {
+                file_id: file_id.into(),
+            },
+            cache_control: None,
+        }
+    }
+
+    /// Create a document content block from raw document bytes, such as a
+    /// PDF with media type `application/pdf`, base64-encoding them.
+    pub fn document_bytes(media_type: impl Into<String>, data: impl AsRef<[u8]>) -> Self {
+        ContentBlockParam::Document {
+            source: DocumentSource::Base64 {
+                media_type: media_type.into(),
+                data: BASE64.encode(data),
+            },
+            title: None,
+            context: None,
+            cache_control: None,
+        }
+    }
+
+    /// Create a document content block from a Files API upload.
+    pub fn document_file(file_id: impl Into<String>) -> Self {
+        ContentBlockParam::Document {
+            source: DocumentSource::File {
+                file_id: file_id.into(),
+            },
+            title: None,
+            context: None,
+            cache_control: None,
+        }
+    }
+
+    /// Set the title of this document.
+    ///
+    /// Other content blocks are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::ContentBlockParam;
+    ///
+    /// let document = ContentBlockParam::document_file("file_011CNha8iCJcU1wXNR6q4V8w")
+    ///     .with_title("Q3 earnings report")
+    ///     .with_context("Published by the finance team, not audited.");
+    /// ```
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        if let ContentBlockParam::Document { title: slot, .. } = &mut self {
+            *slot = Some(title.into());
+        }
+        self
+    }
+
+    /// Set the context of this document.
+    ///
+    /// Other content blocks are returned unchanged.
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        if let ContentBlockParam::Document { context: slot, .. } = &mut self {
+            *slot = Some(context.into());
+        }
+        self
+    }
+
+    /// Create a thinking block to replay a previous assistant turn.
+    pub fn thinking(thinking: impl Into<String>, signature: impl Into<String>) -> Self {
+        ContentBlockParam::Thinking {
+            thinking: thinking.into(),
+            signature: signature.into(),
+        }
+    }
+
+    /// Create a redacted thinking block to replay a previous assistant turn.
+    pub fn redacted_thinking(data: impl Into<String>) -> Self {
+        ContentBlockParam::RedactedThinking { data: data.into() }
+    }
+
+    /// Create a search result content block from passages of text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::ContentBlockParam;
+    ///
+    /// let result = ContentBlockParam::search_result(
+    ///     "https://docs.example.com/billing",
+    ///     "Billing FAQ",
+    ///     ["Invoices are sent on the 1st of each month."],
+    /// )
+    /// .with_citations(true);
+    /// ```
+    pub fn search_result(
+        source: impl Into<String>,
+        title: impl Into<String>,
+        passages: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        ContentBlockParam::SearchResult {
+            source: source.into(),
+            title: title.into(),
+            content: passages.into_iter().map(Self::text).collect(),
+            citations: None,
+            cache_control: None,
+        }
+    }
+
+    /// Enable or disable citations for this search result.
+    ///
+    /// Other content blocks are returned unchanged.
+    pub fn with_citations(mut self, enabled: bool) -> Self {
+        if let ContentBlockParam::SearchResult { citations, .. } = &mut self {
+            *citations = Some(CitationsConfig { enabled });
+        }
+        self
+    }
+
+    /// Set the cache control of this block, marking a cache breakpoint.
+    ///
+    /// Tool use, thinking, redacted thinking and unknown blocks are left
+    /// unchanged.
+    pub fn set_cache_control(&mut self, cache_control: CacheControl) {
+        match self {
+            ContentBlockParam::Text {
+                cache_control: slot,
+                ..
+            }
+            | ContentBlockParam::Image {
+                cache_control: slot,
+                ..
+            }
+            | ContentBlockParam::Document {
+                cache_control: slot,
+                ..
+            }
+            | ContentBlockParam::SearchResult {
+                cache_control: slot,
+                ..
+            }
+            | ContentBlockParam::ToolResult {
+                cache_control: slot,
+                ..
+            } => *slot = Some(cache_control),
+            ContentBlockParam::ToolUse { .. }
+            | ContentBlockParam::Thinking { .. }
+            | ContentBlockParam::RedactedThinking { .. }
+            | ContentBlockParam::Unknown(_) => {}
+        }
+    }
+
     /// Create a tool result content block.
     pub fn tool_result(tool_use_id: impl Into<String>, content: impl Into<String>) -> Self {
         ContentBlockParam::ToolResult {
@@ -159,6 +480,104 @@ impl ContentBlockParam {
         }
     }
 
+    /// Create a tool result content block made of content blocks.
+    ///
+    /// Tool results may contain text and image blocks, e.g. a screenshot
+    /// along with a caption.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::types::ImageMediaType;
+    /// use anthropic_sdk::ContentBlockParam;
+    ///
+    /// let result = ContentBlockParam::tool_result_with_blocks(
+    ///     "toolu_01",
+    ///     vec![
+    ///         ContentBlockParam::text("Screenshot of the login page"),
+    ///         ContentBlockParam::image_base64(ImageMediaType::Png, "iVBORw0KGgo..."),
+    ///     ],
+    /// );
+    /// ```
+    pub fn tool_result_with_blocks(
+        tool_use_id: impl Into<String>,
+        blocks: Vec<ContentBlockParam>,
+    ) -> Self {
+        ContentBlockParam::ToolResult {
+            tool_use_id: tool_use_id.into(),
+            content: Some(ToolResultContent::Blocks(blocks)),
+            is_error: None,
+            cache_control: None,
+        }
+    }
+
+    /// Create a tool result content block holding a single base64 image.
+    pub fn tool_result_image(
+        tool_use_id: impl Into<String>,
+        media_type: ImageMediaType,
+        data: impl Into<String>,
+    ) -> Self {
+        Self::tool_result_with_blocks(tool_use_id, vec![Self::image_base64(media_type, data)])
+    }
+
+    /// Append a content block to this tool result.
+    ///
+    /// Text content is converted to a text block first. Other content blocks
+    /// are returned unchanged.
+    pub fn with_block(mut self, block: ContentBlockParam) -> Self {
+        if let ContentBlockParam::ToolResult { content, .. } = &mut self {
+            let blocks = match content.take() {
+                None => vec![block],
+                Some(ToolResultContent::Text(text)) => vec![Self::text(text), block],
+                Some(ToolResultContent::Blocks(mut blocks)) => {
+                    blocks.push(block);
+                    blocks
+                }
+            };
+            *content = Some(ToolResultContent::Blocks(blocks));
+        }
+        self
+    }
+
+    /// Append a base64 image to this tool result.
+    ///
+    /// See [`with_block`](Self::with_block).
+    pub fn with_image(self, media_type: ImageMediaType, data: impl Into<String>) -> Self {
+        self.with_block(Self::image_base64(media_type, data))
+    }
+
+    /// Create a tool result content block from a serializable value.
+    ///
+    /// The value is sent to the model as JSON text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::ContentBlockParam;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Weather {
+    ///     temperature: f32,
+    ///     conditions: String,
+    /// }
+    ///
+    /// let weather = Weather {
+    ///     temperature: 21.5,
+    ///     conditions: "sunny".to_string(),
+    /// };
+    /// let result = ContentBlockParam::tool_result_json("toolu_01", &weather).unwrap();
+    /// ```
+    pub fn tool_result_json<T: Serialize + ?Sized>(
+        tool_use_id: impl Into<String>,
+        value: &T,
+    ) -> Result<Self> {
+        Ok(Self::tool_result(
+            tool_use_id,
+            serde_json::to_string(value)?,
+        ))
+    }
+
     /// Create a tool error result content block.
     pub fn tool_error(tool_use_id: impl Into<String>, error: impl Into<String>) -> Self {
         ContentBlockParam::ToolResult {
@@ -175,12 +594,110 @@ impl ContentBlockParam {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ImageSource {
     /// Base64-encoded image data.
-    Base64 { media_type: String, data: String },
+    Base64 {
+        media_type: ImageMediaType,
+        data: String,
+    },
 
     /// URL to an image.
     Url { url: String },
+
+    /// Image uploaded through the Files API.
+    File { file_id: String },
+}
+
+/// The format of a base64 image.
+///
+/// Parse a MIME type with [`FromStr`], which rejects types the API does not
+/// accept. [`Other`](Self::Other) sends any other type as is.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::types::ImageMediaType;
+///
+/// let png: ImageMediaType = "image/png".parse().unwrap();
+/// assert_eq!(png, ImageMediaType::Png);
+/// assert!("image/pgn".parse::<ImageMediaType>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImageMediaType {
+    /// `image/jpeg`.
+    Jpeg,
+    /// `image/png`.
+    Png,
+    /// `image/gif`.
+    Gif,
+    /// `image/webp`.
+    Webp,
+    /// Another media type, sent as is.
+    Other(String),
+}
+
+impl ImageMediaType {
+    /// Get the MIME type, such as `image/png`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Jpeg => "image/jpeg",
+            Self::Png => "image/png",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Other(media_type) => media_type,
+        }
+    }
 }
 
+impl fmt::Display for ImageMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ImageMediaType {
+    type Err = UnsupportedMediaType;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "image/jpeg" => Ok(Self::Jpeg),
+            "image/png" => Ok(Self::Png),
+            "image/gif" => Ok(Self::Gif),
+            "image/webp" => Ok(Self::Webp),
+            _ => Err(UnsupportedMediaType(s.to_string())),
+        }
+    }
+}
+
+impl Serialize for ImageMediaType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ImageMediaType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let media_type = String::deserialize(deserializer)?;
+        Ok(media_type
+            .parse()
+            .unwrap_or(ImageMediaType::Other(media_type)))
+    }
+}
+
+/// A media type that is not a supported image format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedMediaType(pub String);
+
+impl fmt::Display for UnsupportedMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unsupported image media type {:?}, expected image/jpeg, image/png, image/gif or image/webp",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedMediaType {}
+
 /// Document source for document content blocks.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -190,6 +707,15 @@ pub enum DocumentSource {
 
     /// URL to a document.
     Url { url: String },
+
+    /// Document uploaded through the Files API.
+    File { file_id: String },
+}
+
+/// Whether the model may cite a content block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationsConfig {
+    pub enabled: bool,
 }
 
 /// Tool result content.
@@ -204,23 +730,56 @@ pub enum ToolResultContent {
 }
 
 /// Cache control settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CacheControl {
     /// Ephemeral cache control.
-    Ephemeral,
+    Ephemeral {
+        /// How long the cache entry lives, 5 minutes when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ttl: Option<CacheTtl>,
+    },
 }
 
 impl CacheControl {
-    /// Create an ephemeral cache control.
+    /// Create an ephemeral cache control with the default 5 minute lifetime.
     pub fn ephemeral() -> Self {
-        CacheControl::Ephemeral
+        CacheControl::Ephemeral { ttl: None }
+    }
+
+    /// Create an ephemeral cache control that lives for 5 minutes.
+    pub fn ephemeral_5m() -> Self {
+        CacheControl::Ephemeral {
+            ttl: Some(CacheTtl::FiveMinutes),
+        }
+    }
+
+    /// Create an ephemeral cache control that lives for 1 hour.
+    ///
+    /// Writes to a 1 hour cache entry are priced higher than to a 5 minute
+    /// one.
+    pub fn ephemeral_1h() -> Self {
+        CacheControl::Ephemeral {
+            ttl: Some(CacheTtl::OneHour),
+        }
     }
 }
 
+/// Lifetime of a cache entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CacheTtl {
+    /// 5 minutes.
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    /// 1 hour.
+    #[serde(rename = "1h")]
+    OneHour,
+}
+
 /// Citation information for text content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case", remote = "Self")]
+#[non_exhaustive]
 pub enum Citation {
     /// Character location citation.
     CharLocation {
@@ -253,11 +812,41 @@ pub enum Citation {
         cited_text: String,
         url: String,
         title: Option<String>,
+        /// Reference to the cited passage, which must be sent back when the
+        /// turn is replayed.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        encrypted_index: Option<String>,
     },
+
+    /// Search result location citation.
+    SearchResultLocation {
+        cited_text: String,
+        search_result_index: u32,
+        source: String,
+        title: Option<String>,
+        start_block_index: u32,
+        end_block_index: u32,
+    },
+
+    /// A citation of a type this SDK does not know, kept as its raw JSON.
+    #[serde(skip)]
+    Unknown(Value),
 }
 
+unknown_fallback!(
+    Citation,
+    [
+        "char_location",
+        "page_location",
+        "content_block_location",
+        "web_search_result_location",
+        "search_result_location",
+    ]
+);
+
 /// Web search result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "web_search_result")]
 pub struct WebSearchResult {
     /// The URL of the search result.
     pub url: String,
@@ -268,4 +857,141 @@ pub struct WebSearchResult {
     /// Snippet of the search result content.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub snippet: Option<String>,
+
+    /// The page content, encrypted, which must be sent back when the turn is
+    /// replayed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_content: Option<String>,
+
+    /// How long ago the page was last updated, such as `2 days ago`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_age: Option<String>,
+}
+
+/// The outcome of a web search tool call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum WebSearchToolResultContent {
+    /// The search succeeded.
+    Results(Vec<WebSearchResult>),
+
+    /// The search failed.
+    Error(WebSearchToolResultError),
+}
+
+impl WebSearchToolResultContent {
+    /// Get the results if the search succeeded.
+    pub fn results(&self) -> Option<&[WebSearchResult]> {
+        match self {
+            WebSearchToolResultContent::Results(results) => Some(results),
+            WebSearchToolResultContent::Error(_) => None,
+        }
+    }
+
+    /// Get the error code if the search failed.
+    pub fn error_code(&self) -> Option<&WebSearchErrorCode> {
+        match self {
+            WebSearchToolResultContent::Error(error) => Some(&error.error_code),
+            WebSearchToolResultContent::Results(_) => None,
+        }
+    }
+}
+
+/// Why a web search failed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "web_search_tool_result_error")]
+pub struct WebSearchToolResultError {
+    /// The error code.
+    pub error_code: WebSearchErrorCode,
+}
+
+/// The error code of a failed web search.
+///
+/// Codes added to the API after this SDK are kept as
+/// [`Unknown`](Self::Unknown).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WebSearchErrorCode {
+    /// The query or other input was not valid.
+    InvalidToolInput,
+    /// The search service is unavailable.
+    Unavailable,
+    /// The request reached the tool's `max_uses`.
+    MaxUsesExceeded,
+    /// Searches are being rate limited.
+    TooManyRequests,
+    /// The query was too long.
+    QueryTooLong,
+    /// An error code this SDK does not know.
+    Unknown(String),
+}
+
+impl WebSearchErrorCode {
+    /// Get the code as the API names it, such as `max_uses_exceeded`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidToolInput => "invalid_tool_input",
+            Self::Unavailable => "unavailable",
+            Self::MaxUsesExceeded => "max_uses_exceeded",
+            Self::TooManyRequests => "too_many_requests",
+            Self::QueryTooLong => "query_too_long",
+            Self::Unknown(code) => code,
+        }
+    }
+}
+
+impl Serialize for WebSearchErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebSearchErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(match code.as_str() {
+            "invalid_tool_input" => Self::InvalidToolInput,
+            "unavailable" => Self::Unavailable,
+            "max_uses_exceeded" => Self::MaxUsesExceeded,
+            "too_many_requests" => Self::TooManyRequests,
+            "query_too_long" => Self::QueryTooLong,
+            _ => Self::Unknown(code),
+        })
+    }
+}
+
+/// The outcome of a code execution tool call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeExecutionToolResultContent {
+    /// The code ran to completion.
+    CodeExecutionResult {
+        /// Standard output of the code.
+        stdout: String,
+
+        /// Standard error of the code.
+        stderr: String,
+
+        /// Exit code of the code.
+        return_code: i32,
+
+        /// Files written by the code, downloadable through the Files API.
+        #[serde(default)]
+        content: Vec<CodeExecutionOutput>,
+    },
+
+    /// The code could not be executed.
+    #[serde(rename = "code_execution_tool_result_error")]
+    Error {
+        /// Error code, e.g. `unavailable` or `execution_time_exceeded`.
+        error_code: String,
+    },
+}
+
+/// A file produced by code execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "code_execution_output")]
+pub struct CodeExecutionOutput {
+    /// The ID of the file in the Files API.
+    pub file_id: String,
 }
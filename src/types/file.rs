@@ -0,0 +1,75 @@
+//! File types.
+
+use serde::{Deserialize, Serialize};
+
+/// Metadata for a file uploaded to the Files API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileMetadata {
+    /// Unique file identifier.
+    pub id: String,
+
+    /// Object type, always "file".
+    #[serde(rename = "type")]
+    pub object_type: String,
+
+    /// Original name of the uploaded file.
+    pub filename: String,
+
+    /// MIME type of the file.
+    pub mime_type: String,
+
+    /// Size of the file in bytes.
+    pub size_bytes: u64,
+
+    /// When the file was created (RFC 3339).
+    pub created_at: String,
+
+    /// Whether the file can be downloaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloadable: Option<bool>,
+}
+
+/// List of files response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileList {
+    /// The list of files.
+    pub data: Vec<FileMetadata>,
+
+    /// Whether there are more files.
+    pub has_more: bool,
+
+    /// Cursor for the first item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+
+    /// Cursor for the last item.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+}
+
+/// Response from deleting a file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedFile {
+    /// ID of the deleted file.
+    pub id: String,
+
+    /// Object type, always "file_deleted".
+    #[serde(rename = "type")]
+    pub object_type: String,
+}
+
+/// Parameters for listing files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListFilesParams {
+    /// Number of results to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor for pagination (before this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+
+    /// Cursor for pagination (after this ID).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+}
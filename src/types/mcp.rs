@@ -0,0 +1,85 @@
+//! MCP connector types.
+
+use serde::{Deserialize, Serialize};
+
+/// A remote MCP server the API connects to on the model's behalf.
+///
+/// Requires the [`MCP_CLIENT`](super::betas::MCP_CLIENT) beta, which is sent
+/// automatically when a request lists MCP servers.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::types::McpServer;
+///
+/// let server = McpServer::new("https://mcp.example.com/sse", "example")
+///     .authorization_token("secret")
+///     .allowed_tools(["search", "fetch"]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "url")]
+pub struct McpServer {
+    /// The URL of the server.
+    pub url: String,
+
+    /// A unique name for the server.
+    pub name: String,
+
+    /// OAuth bearer token sent to the server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_token: Option<String>,
+
+    /// Which of the server's tools are available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_configuration: Option<McpToolConfiguration>,
+}
+
+impl McpServer {
+    /// Create an MCP server definition.
+    pub fn new(url: impl Into<String>, name: impl Into<String>) -> Self {
+        McpServer {
+            url: url.into(),
+            name: name.into(),
+            authorization_token: None,
+            tool_configuration: None,
+        }
+    }
+
+    /// Set the OAuth bearer token.
+    pub fn authorization_token(mut self, token: impl Into<String>) -> Self {
+        self.authorization_token = Some(token.into());
+        self
+    }
+
+    /// Only allow the named tools.
+    pub fn allowed_tools<I, S>(mut self, tools: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tool_configuration
+            .get_or_insert_with(McpToolConfiguration::default)
+            .allowed_tools = Some(tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Enable or disable all of the server's tools.
+    pub fn tools_enabled(mut self, enabled: bool) -> Self {
+        self.tool_configuration
+            .get_or_insert_with(McpToolConfiguration::default)
+            .enabled = Some(enabled);
+        self
+    }
+}
+
+/// Tool configuration of an [`McpServer`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct McpToolConfiguration {
+    /// Whether the server's tools are enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Only allow these tools; all tools are allowed when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tools: Option<Vec<String>>,
+}
@@ -0,0 +1,84 @@
+//! Memory tool types.
+
+use serde::{Deserialize, Serialize};
+
+use super::CacheControl;
+
+/// The memory tool (`memory_20250818`).
+///
+/// The model stores and recalls information across conversations as files
+/// under `/memories`, which the caller persists. Requires the
+/// [`CONTEXT_MANAGEMENT`](super::betas::CONTEXT_MANAGEMENT) beta, which is
+/// sent automatically when this tool is in the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryTool {
+    /// The tool name, always `memory`.
+    pub name: String,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl MemoryTool {
+    /// Create a memory tool.
+    pub fn new() -> Self {
+        MemoryTool {
+            name: "memory".into(),
+            cache_control: None,
+        }
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+impl Default for MemoryTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A command requested by the memory tool.
+///
+/// Paths are absolute and start with `/memories`. Parse it from a `tool_use`
+/// block with [`ToolUseBlock::parse_input`](super::ToolUseBlock::parse_input),
+/// or let [`FileMemory`](crate::tools::FileMemory) execute it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum MemoryCommand {
+    /// Show a file, optionally only the 1-indexed inclusive line range, or
+    /// list a directory.
+    View {
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view_range: Option<[i64; 2]>,
+    },
+
+    /// Create or overwrite a file.
+    Create { path: String, file_text: String },
+
+    /// Replace the single occurrence of `old_str` with `new_str`.
+    StrReplace {
+        path: String,
+        old_str: String,
+        #[serde(default)]
+        new_str: String,
+    },
+
+    /// Insert `insert_text` after line `insert_line` (0 inserts at the start).
+    Insert {
+        path: String,
+        insert_line: u32,
+        insert_text: String,
+    },
+
+    /// Delete a file or directory.
+    Delete { path: String },
+
+    /// Rename or move a file or directory.
+    Rename { old_path: String, new_path: String },
+}
@@ -1,8 +1,11 @@
 //! Message types.
 
-use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-use super::{ContentBlock, ContentBlockParam, Usage};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{ContentBlock, ContentBlockParam, Usage, STRUCTURED_OUTPUT_TOOL};
+use crate::error::{AnthropicError, Result};
 
 /// The role of a message participant.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,8 +18,11 @@ pub enum Role {
 }
 
 /// The reason the model stopped generating.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+///
+/// Reasons added to the API after this SDK are kept as
+/// [`Unknown`](Self::Unknown).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum StopReason {
     /// The model reached a natural stopping point.
     EndTurn,
@@ -30,6 +36,44 @@ pub enum StopReason {
     PauseTurn,
     /// The model refused to generate content.
     Refusal,
+    /// A reason this SDK does not know.
+    Unknown(String),
+}
+
+impl StopReason {
+    /// Get the reason as the API names it, such as `end_turn`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::EndTurn => "end_turn",
+            Self::MaxTokens => "max_tokens",
+            Self::StopSequence => "stop_sequence",
+            Self::ToolUse => "tool_use",
+            Self::PauseTurn => "pause_turn",
+            Self::Refusal => "refusal",
+            Self::Unknown(reason) => reason,
+        }
+    }
+}
+
+impl Serialize for StopReason {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for StopReason {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let reason = String::deserialize(deserializer)?;
+        Ok(match reason.as_str() {
+            "end_turn" => Self::EndTurn,
+            "max_tokens" => Self::MaxTokens,
+            "stop_sequence" => Self::StopSequence,
+            "tool_use" => Self::ToolUse,
+            "pause_turn" => Self::PauseTurn,
+            "refusal" => Self::Refusal,
+            _ => Self::Unknown(reason),
+        })
+    }
 }
 
 /// A message response from the API.
@@ -61,6 +105,25 @@ pub struct Message {
 
     /// Token usage information.
     pub usage: Usage,
+
+    /// The code execution container used by this message, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<Container>,
+}
+
+/// A code execution container.
+///
+/// Pass its `id` to
+/// [`MessageCreateParamsBuilder::container`](super::MessageCreateParamsBuilder::container)
+/// to run the next request's code in the same container, with the files
+/// written so far.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Container {
+    /// Container identifier.
+    pub id: String,
+
+    /// When the container expires (RFC 3339).
+    pub expires_at: String,
 }
 
 impl Message {
@@ -98,6 +161,36 @@ impl Message {
     pub fn stopped_for_tool_use(&self) -> bool {
         self.stop_reason == Some(StopReason::ToolUse)
     }
+
+    /// Deserialize the model's structured answer.
+    ///
+    /// Reads the input of a call to the [`STRUCTURED_OUTPUT_TOOL`] when the
+    /// request used
+    /// [`output_tool`](super::MessageCreateParamsBuilder::output_tool), and
+    /// the text of the message otherwise, as with
+    /// [`output_schema`](super::MessageCreateParamsBuilder::output_schema).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnthropicError::InvalidResponse`] if the message holds no
+    /// answer, and [`AnthropicError::Json`] if the answer does not match `T`.
+    pub fn parse_structured<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        if let Some((_, _, input)) = self
+            .tool_uses()
+            .into_iter()
+            .find(|(_, name, _)| *name == STRUCTURED_OUTPUT_TOOL)
+        {
+            return serde_json::from_value(input.clone()).map_err(AnthropicError::Json);
+        }
+
+        let text = self.text();
+        if text.trim().is_empty() {
+            return Err(AnthropicError::InvalidResponse {
+                message: "Message has no structured output".into(),
+            });
+        }
+        serde_json::from_str(text.trim()).map_err(AnthropicError::Json)
+    }
 }
 
 /// A message parameter for API requests.
@@ -142,6 +235,75 @@ impl MessageParam {
             content: MessageContent::Blocks(blocks),
         }
     }
+
+    /// Get the text of the message, joining its text blocks.
+    pub fn text(&self) -> String {
+        self.content.text()
+    }
+
+    /// Iterate over the content blocks of the message.
+    ///
+    /// See [`MessageContent::iter`].
+    pub fn blocks(&self) -> impl Iterator<Item = Cow<'_, ContentBlockParam>> {
+        self.content.iter()
+    }
+
+    /// Append a content block to the message.
+    pub fn push(&mut self, block: ContentBlockParam) {
+        self.content.push(block);
+    }
+}
+
+/// Convert a response into the assistant turn to send back with the next
+/// request.
+///
+/// Every block is kept: text with its citations, thinking with its signature,
+/// and server tool blocks and blocks of unknown types as their raw JSON.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # async fn example(client: anthropic_sdk::AsyncAnthropic, mut params: anthropic_sdk::MessageCreateParams) -> anthropic_sdk::Result<()> {
+/// use anthropic_sdk::MessageParam;
+///
+/// let message = client.messages().create(params.clone()).await?;
+/// params.messages.push(MessageParam::from(message));
+/// # Ok(())
+/// # }
+/// ```
+impl From<Message> for MessageParam {
+    fn from(message: Message) -> Self {
+        MessageParam::assistant_with_blocks(
+            message
+                .content
+                .into_iter()
+                .map(|block| match block {
+                    ContentBlock::Text { text, citations } => ContentBlockParam::Text {
+                        text,
+                        citations,
+                        cache_control: None,
+                    },
+                    ContentBlock::Thinking {
+                        thinking,
+                        signature,
+                    } => ContentBlockParam::Thinking {
+                        thinking,
+                        signature,
+                    },
+                    ContentBlock::RedactedThinking { data } => {
+                        ContentBlockParam::RedactedThinking { data }
+                    }
+                    ContentBlock::ToolUse { id, name, input } => {
+                        ContentBlockParam::ToolUse { id, name, input }
+                    }
+                    ContentBlock::Unknown(value) => ContentBlockParam::Unknown(value),
+                    block => {
+                        ContentBlockParam::Unknown(serde_json::to_value(block).unwrap_or_default())
+                    }
+                })
+                .collect(),
+        )
+    }
 }
 
 /// Message content, either text or multiple blocks.
@@ -154,3 +316,242 @@ pub enum MessageContent {
     /// Multiple content blocks.
     Blocks(Vec<ContentBlockParam>),
 }
+
+impl MessageContent {
+    /// Iterate over the content blocks.
+    ///
+    /// Text content is yielded as a single, owned text block.
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'_, ContentBlockParam>> {
+        let (text, blocks) = match self {
+            MessageContent::Text(text) => (Some(ContentBlockParam::text(text.clone())), &[][..]),
+            MessageContent::Blocks(blocks) => (None, blocks.as_slice()),
+        };
+        text.map(Cow::Owned)
+            .into_iter()
+            .chain(blocks.iter().map(Cow::Borrowed))
+    }
+
+    /// Get the text, joining the text blocks.
+    pub fn text(&self) -> String {
+        match self {
+            MessageContent::Text(text) => text.clone(),
+            MessageContent::Blocks(blocks) => blocks
+                .iter()
+                .filter_map(|block| match block {
+                    ContentBlockParam::Text { text, .. } => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Get the text if this is text content.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            MessageContent::Text(text) => Some(text),
+            MessageContent::Blocks(_) => None,
+        }
+    }
+
+    /// Get the blocks if this is block content.
+    pub fn as_blocks(&self) -> Option<&[ContentBlockParam]> {
+        match self {
+            MessageContent::Blocks(blocks) => Some(blocks),
+            MessageContent::Text(_) => None,
+        }
+    }
+
+    /// Get the blocks for editing, turning text content into a text block
+    /// first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::types::CacheControl;
+    /// use anthropic_sdk::MessageParam;
+    ///
+    /// let mut message = MessageParam::user("A long document...");
+    /// if let Some(block) = message.content.blocks_mut().last_mut() {
+    ///     block.set_cache_control(CacheControl::ephemeral());
+    /// }
+    /// ```
+    pub fn blocks_mut(&mut self) -> &mut Vec<ContentBlockParam> {
+        if let MessageContent::Text(text) = self {
+            *self = MessageContent::Blocks(vec![ContentBlockParam::text(std::mem::take(text))]);
+        }
+        match self {
+            MessageContent::Blocks(blocks) => blocks,
+            MessageContent::Text(_) => unreachable!("text content was just converted"),
+        }
+    }
+
+    /// Append a content block.
+    pub fn push(&mut self, block: ContentBlockParam) {
+        self.blocks_mut().push(block);
+    }
+
+    /// Check if there is no content.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            MessageContent::Text(text) => text.is_empty(),
+            MessageContent::Blocks(blocks) => blocks.is_empty(),
+        }
+    }
+}
+
+impl Extend<ContentBlockParam> for MessageContent {
+    fn extend<I: IntoIterator<Item = ContentBlockParam>>(&mut self, blocks: I) {
+        self.blocks_mut().extend(blocks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::McpToolResultContent;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Answer {
+        city: String,
+    }
+
+    fn message(content: serde_json::Value) -> Message {
+        serde_json::from_value(serde_json::json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-5",
+            "content": content,
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 1, "output_tokens": 1}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_structured() {
+        let expected = Answer {
+            city: "Paris".into(),
+        };
+
+        let text =
+            message(serde_json::json!([{"type": "text", "text": " {\"city\": \"Paris\"}\n"}]));
+        assert_eq!(text.parse_structured::<Answer>().unwrap(), expected);
+
+        let tool = message(serde_json::json!([{
+            "type": "tool_use",
+            "id": "toolu_1",
+            "name": STRUCTURED_OUTPUT_TOOL,
+            "input": {"city": "Paris"}
+        }]));
+        assert_eq!(tool.parse_structured::<Answer>().unwrap(), expected);
+
+        let empty = message(serde_json::json!([]));
+        assert!(matches!(
+            empty.parse_structured::<Answer>(),
+            Err(AnthropicError::InvalidResponse { .. })
+        ));
+    }
+    #[test]
+    fn test_message_into_param() {
+        let blocks = serde_json::json!([
+            {"type": "thinking", "thinking": "Look it up.", "signature": "sig"},
+            {"type": "text", "text": "Checking."},
+            {"type": "server_tool_use", "id": "srvtoolu_1", "name": "web_search", "input": {"query": "rust"}},
+            {"type": "web_search_tool_result", "tool_use_id": "srvtoolu_1", "content": [
+                {"type": "web_search_result", "url": "https://www.rust-lang.org", "title": "Rust",
+                    "encrypted_content": "abc", "page_age": "1 day ago"}
+            ]},
+            {"type": "text", "text": "Rust is fast.", "citations": [
+                {"type": "web_search_result_location", "cited_text": "fast", "url": "https://www.rust-lang.org",
+                    "title": "Rust", "encrypted_index": "def"}
+            ]},
+            {"type": "container_upload", "file_id": "file_1"},
+            {"type": "future_block", "data": 1},
+            {"type": "tool_use", "id": "toolu_1", "name": "search", "input": {"q": "rust"}}
+        ]);
+        let param = MessageParam::from(message(blocks.clone()));
+        assert_eq!(param.role, Role::Assistant);
+        assert_eq!(serde_json::to_value(&param.content).unwrap(), blocks);
+
+        let content: MessageContent = serde_json::from_value(blocks.clone()).unwrap();
+        assert!(matches!(
+            content.as_blocks().unwrap()[2],
+            ContentBlockParam::Unknown(_)
+        ));
+        assert_eq!(serde_json::to_value(&content).unwrap(), blocks);
+    }
+
+    #[test]
+    fn test_message_content_helpers() {
+        let mut message = MessageParam::user("Summarize this.");
+        assert_eq!(message.blocks().count(), 1);
+        assert_eq!(message.content.as_text(), Some("Summarize this."));
+
+        message.push(ContentBlockParam::document_file("file_1"));
+        message
+            .content
+            .extend([ContentBlockParam::text(" Briefly.")]);
+        assert_eq!(message.text(), "Summarize this. Briefly.");
+        assert!(matches!(
+            message.blocks().nth(1).as_deref(),
+            Some(ContentBlockParam::Document { .. })
+        ));
+
+        let last = message.content.blocks_mut().last_mut().unwrap();
+        last.set_cache_control(crate::types::CacheControl::ephemeral());
+        assert_eq!(
+            serde_json::to_value(&message.content).unwrap()[2]["cache_control"]["type"],
+            "ephemeral"
+        );
+    }
+
+    #[test]
+    fn test_web_search_errors() {
+        let message = message(serde_json::json!([
+            {"type": "web_search_tool_result", "tool_use_id": "srvtoolu_1", "content": [
+                {"type": "web_search_result", "url": "https://www.rust-lang.org", "title": "Rust"}
+            ]},
+            {"type": "web_search_tool_result", "tool_use_id": "srvtoolu_2", "content": {
+                "type": "web_search_tool_result_error", "error_code": "max_uses_exceeded"
+            }}
+        ]));
+        let content = |index| match &message.content[index] {
+            ContentBlock::WebSearchToolResult { content, .. } => content,
+            block => panic!("unexpected block {:?}", block),
+        };
+        assert_eq!(content(0).results().unwrap()[0].title, "Rust");
+        assert_eq!(
+            content(1).error_code(),
+            Some(&crate::types::WebSearchErrorCode::MaxUsesExceeded)
+        );
+    }
+
+    #[test]
+    fn test_unknown_content_blocks() {
+        let blocks = serde_json::json!([
+            {"type": "mcp_tool_use", "id": "mcptoolu_1", "name": "search", "server_name": "docs", "input": {}},
+            {"type": "mcp_tool_result", "tool_use_id": "mcptoolu_1", "content": [{"type": "text", "text": "Found"}]},
+            {"type": "container_upload", "file_id": "file_1"},
+            {"type": "hologram", "frames": 3}
+        ]);
+        let message = message(blocks.clone());
+        assert!(matches!(
+            &message.content[1],
+            ContentBlock::McpToolResult { is_error: false, content: McpToolResultContent::Blocks(content), .. }
+                if content.len() == 1
+        ));
+        assert!(
+            matches!(&message.content[3], ContentBlock::Unknown(value) if value["frames"] == 3)
+        );
+        assert_eq!(
+            serde_json::from_str::<StopReason>(r#""model_context_window_exceeded""#).unwrap(),
+            StopReason::Unknown("model_context_window_exceeded".into())
+        );
+        assert_eq!(serde_json::to_value(&message.content).unwrap(), {
+            let mut blocks = blocks;
+            blocks[1]["is_error"] = false.into();
+            blocks
+        });
+    }
+}
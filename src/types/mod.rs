@@ -1,15 +1,91 @@
 //! Type definitions for the Anthropic API.
 
+mod admin;
+mod bash;
+mod batch;
+mod beta;
+mod computer_use;
 mod content;
+mod file;
+mod mcp;
+mod memory;
 mod message;
 mod model;
+mod output;
 mod params;
+#[cfg(feature = "beta")]
+mod prompt_tools;
+mod report;
+mod server_tool;
+mod text_editor;
 mod tool;
 mod usage;
 
+pub use admin::*;
+pub use bash::*;
+pub use batch::*;
+pub use beta::*;
+pub use computer_use::*;
 pub use content::*;
+pub use file::*;
+pub use mcp::*;
+pub use memory::*;
 pub use message::*;
 pub use model::*;
+pub use output::*;
 pub use params::*;
+#[cfg(feature = "beta")]
+pub use prompt_tools::*;
+pub use report::*;
+pub use server_tool::*;
+pub use text_editor::*;
 pub use tool::*;
 pub use usage::*;
+
+/// Implement `Serialize` and `Deserialize` for an enum tagged by `type`,
+/// keeping a value whose `type` is not listed in its `Unknown(Value)`
+/// variant.
+///
+/// The enum derives both traits with `#[serde(remote = "Self")]` and marks
+/// `Unknown` with `#[serde(skip)]`. Values of a listed type are deserialized
+/// strictly, so a malformed one is still an error.
+macro_rules! unknown_fallback {
+    ($ty:ident, [$($tag:literal),* $(,)?]) => {
+        impl $ty {
+            /// The `type` of each variant this SDK knows.
+            const KNOWN_TYPES: &'static [&'static str] = &[$($tag),*];
+        }
+
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                match self {
+                    $ty::Unknown(value) => serde::Serialize::serialize(value, serializer),
+                    known => $ty::serialize(known, serializer),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+                let known = value
+                    .get("type")
+                    .and_then(serde_json::Value::as_str)
+                    .is_some_and(|kind| Self::KNOWN_TYPES.contains(&kind));
+                if !known {
+                    return Ok($ty::Unknown(value));
+                }
+                $ty::deserialize(value).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use unknown_fallback;
@@ -1,6 +1,9 @@
 //! Model types.
 
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Information about an available model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,4 +67,177 @@ pub mod models {
 
     /// Claude 3 Haiku
     pub const CLAUDE_3_HAIKU: &str = "claude-3-haiku-20240307";
+
+    /// Alias for the latest Claude Opus 4.5 snapshot
+    pub const CLAUDE_OPUS_4_5_LATEST: &str = "claude-opus-4-5";
+
+    /// Alias for the latest Claude Sonnet 4.5 snapshot
+    pub const CLAUDE_SONNET_4_5_LATEST: &str = "claude-sonnet-4-5";
+
+    /// Alias for the latest Claude Haiku 4.5 snapshot
+    pub const CLAUDE_HAIKU_4_5_LATEST: &str = "claude-haiku-4-5";
+
+    /// Alias for the latest Claude 3.5 Sonnet snapshot
+    pub const CLAUDE_3_5_SONNET_LATEST: &str = "claude-3-5-sonnet-latest";
+
+    /// Alias for the latest Claude 3.5 Haiku snapshot
+    pub const CLAUDE_3_5_HAIKU_LATEST: &str = "claude-3-5-haiku-latest";
+
+    /// Alias for the latest Claude 3 Opus snapshot
+    pub const CLAUDE_3_OPUS_LATEST: &str = "claude-3-opus-latest";
+}
+
+/// A model ID, checked at compile time for the models this SDK knows.
+///
+/// Accepted wherever a model ID string is, such as
+/// [`MessageCreateParamsBuilder::model`](super::MessageCreateParamsBuilder::model).
+/// Parsing a string never fails: IDs this SDK does not know, such as newer
+/// models, become [`Other`](Self::Other).
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{KnownModel, MessageCreateParams};
+///
+/// let params = MessageCreateParams::builder()
+///     .model(KnownModel::ClaudeSonnet4_5)
+///     .build();
+/// assert_eq!(params.model, "claude-sonnet-4-5-20250929");
+///
+/// let model: KnownModel = "claude-3-5-haiku-latest".parse().unwrap();
+/// assert_eq!(model, KnownModel::Claude3_5HaikuLatest);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum KnownModel {
+    /// Claude Opus 4.5 (`claude-opus-4-5-20251101`)
+    ClaudeOpus4_5,
+    /// Claude Sonnet 4.5 (`claude-sonnet-4-5-20250929`)
+    ClaudeSonnet4_5,
+    /// Claude Haiku 4.5 (`claude-haiku-4-5-20251001`)
+    ClaudeHaiku4_5,
+    /// Claude 3.5 Sonnet (`claude-3-5-sonnet-20241022`)
+    Claude3_5Sonnet,
+    /// Claude 3.5 Haiku (`claude-3-5-haiku-20241022`)
+    Claude3_5Haiku,
+    /// Claude 3 Opus (`claude-3-opus-20240229`)
+    Claude3Opus,
+    /// Claude 3 Sonnet (`claude-3-sonnet-20240229`)
+    Claude3Sonnet,
+    /// Claude 3 Haiku (`claude-3-haiku-20240307`)
+    Claude3Haiku,
+    /// The latest Claude Opus 4.5 snapshot (`claude-opus-4-5`)
+    ClaudeOpus4_5Latest,
+    /// The latest Claude Sonnet 4.5 snapshot (`claude-sonnet-4-5`)
+    ClaudeSonnet4_5Latest,
+    /// The latest Claude Haiku 4.5 snapshot (`claude-haiku-4-5`)
+    ClaudeHaiku4_5Latest,
+    /// The latest Claude 3.5 Sonnet snapshot (`claude-3-5-sonnet-latest`)
+    Claude3_5SonnetLatest,
+    /// The latest Claude 3.5 Haiku snapshot (`claude-3-5-haiku-latest`)
+    Claude3_5HaikuLatest,
+    /// The latest Claude 3 Opus snapshot (`claude-3-opus-latest`)
+    Claude3OpusLatest,
+    /// A model ID this SDK does not know.
+    Other(String),
+}
+
+impl KnownModel {
+    /// Every known model, without [`Other`](Self::Other).
+    pub const ALL: [KnownModel; 14] = [
+        Self::ClaudeOpus4_5,
+        Self::ClaudeSonnet4_5,
+        Self::ClaudeHaiku4_5,
+        Self::Claude3_5Sonnet,
+        Self::Claude3_5Haiku,
+        Self::Claude3Opus,
+        Self::Claude3Sonnet,
+        Self::Claude3Haiku,
+        Self::ClaudeOpus4_5Latest,
+        Self::ClaudeSonnet4_5Latest,
+        Self::ClaudeHaiku4_5Latest,
+        Self::Claude3_5SonnetLatest,
+        Self::Claude3_5HaikuLatest,
+        Self::Claude3OpusLatest,
+    ];
+
+    /// The model ID sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ClaudeOpus4_5 => models::CLAUDE_OPUS_4_5,
+            Self::ClaudeSonnet4_5 => models::CLAUDE_SONNET_4_5,
+            Self::ClaudeHaiku4_5 => models::CLAUDE_HAIKU_4_5,
+            Self::Claude3_5Sonnet => models::CLAUDE_3_5_SONNET,
+            Self::Claude3_5Haiku => models::CLAUDE_3_5_HAIKU,
+            Self::Claude3Opus => models::CLAUDE_3_OPUS,
+            Self::Claude3Sonnet => models::CLAUDE_3_SONNET,
+            Self::Claude3Haiku => models::CLAUDE_3_HAIKU,
+            Self::ClaudeOpus4_5Latest => models::CLAUDE_OPUS_4_5_LATEST,
+            Self::ClaudeSonnet4_5Latest => models::CLAUDE_SONNET_4_5_LATEST,
+            Self::ClaudeHaiku4_5Latest => models::CLAUDE_HAIKU_4_5_LATEST,
+            Self::Claude3_5SonnetLatest => models::CLAUDE_3_5_SONNET_LATEST,
+            Self::Claude3_5HaikuLatest => models::CLAUDE_3_5_HAIKU_LATEST,
+            Self::Claude3OpusLatest => models::CLAUDE_3_OPUS_LATEST,
+            Self::Other(id) => id,
+        }
+    }
+}
+
+impl fmt::Display for KnownModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KnownModel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::ALL
+            .into_iter()
+            .find(|model| model.as_str() == s)
+            .unwrap_or_else(|| Self::Other(s.to_string())))
+    }
+}
+
+impl From<KnownModel> for String {
+    fn from(model: KnownModel) -> Self {
+        match model {
+            KnownModel::Other(id) => id,
+            model => model.as_str().to_string(),
+        }
+    }
+}
+
+impl Serialize for KnownModel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for KnownModel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = String::deserialize(deserializer)?;
+        Ok(id.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_model_round_trip() {
+        for model in KnownModel::ALL {
+            assert_eq!(model.to_string().parse::<KnownModel>().unwrap(), model);
+        }
+        assert_eq!(
+            "claude-next".parse::<KnownModel>().unwrap(),
+            KnownModel::Other("claude-next".into())
+        );
+        assert_eq!(
+            serde_json::to_string(&KnownModel::Claude3Haiku).unwrap(),
+            r#""claude-3-haiku-20240307""#
+        );
+    }
 }
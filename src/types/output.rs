@@ -0,0 +1,38 @@
+//! Structured output types.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Name of the tool the model is made to call when structured output is
+/// requested with [`MessageCreateParamsBuilder::output_tool`](super::MessageCreateParamsBuilder::output_tool).
+pub const STRUCTURED_OUTPUT_TOOL: &str = "structured_output";
+
+/// The format of the model's final answer.
+///
+/// Requires a model with native structured output support; the
+/// [`betas::STRUCTURED_OUTPUTS`](super::betas::STRUCTURED_OUTPUTS) flag is
+/// added automatically. For other models, use
+/// [`MessageCreateParamsBuilder::output_tool`](super::MessageCreateParamsBuilder::output_tool).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// JSON matching a schema.
+    JsonSchema {
+        /// The JSON schema the answer must match.
+        schema: Value,
+    },
+}
+
+impl OutputFormat {
+    /// Answer with JSON matching a schema.
+    pub fn json_schema(schema: Value) -> Self {
+        OutputFormat::JsonSchema { schema }
+    }
+
+    /// Answer with JSON matching the schema of a type implementing
+    /// [`schemars::JsonSchema`].
+    #[cfg(feature = "schemars")]
+    pub fn from_type<T: schemars::JsonSchema>() -> Self {
+        Self::json_schema(super::tool::schemars_value::<T>())
+    }
+}
@@ -1,11 +1,16 @@
 //! Request parameter types.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
 
-use super::{ContentBlockParam, MessageParam, Tool, ToolChoice};
+use super::{
+    betas, ContentBlockParam, McpServer, MessageParam, OutputFormat, ServerTool, Tool, ToolChoice,
+    ToolInputSchema, ToolUnion, STRUCTURED_OUTPUT_TOOL,
+};
 
 /// Parameters for creating a message.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct MessageCreateParams {
     /// The model to use.
     pub model: String,
@@ -44,9 +49,9 @@ pub struct MessageCreateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
 
-    /// Tools available to the model.
+    /// Custom and Anthropic-defined tools available to the model.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tools: Option<Vec<Tool>>,
+    pub tools: Option<Vec<ToolUnion>>,
 
     /// Tool choice strategy.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -55,6 +60,35 @@ pub struct MessageCreateParams {
     /// Thinking configuration for extended thinking.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking: Option<ThinkingConfig>,
+
+    /// Remote MCP servers whose tools are available to the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mcp_servers: Option<Vec<McpServer>>,
+
+    /// Format of the model's final answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<OutputFormat>,
+
+    /// ID of a code execution container to reuse, from
+    /// [`Message::container`](super::Message::container).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+
+    /// Beta flags to opt this request into.
+    ///
+    /// Sent in the `anthropic-beta` header along with the client's betas, as
+    /// the official SDKs do, so they also apply through
+    /// [`AsyncAnthropic::messages`](crate::AsyncAnthropic::messages). Requests
+    /// inside a message batch take their betas from the batch call instead.
+    #[serde(skip)]
+    pub betas: Option<Vec<String>>,
+
+    /// Extra fields merged into the request body, for API parameters this
+    /// SDK does not have a field for yet.
+    ///
+    /// A field here replaces a typed field of the same name.
+    #[serde(flatten, skip_serializing)]
+    pub extra_body: Option<Map<String, Value>>,
 }
 
 impl MessageCreateParams {
@@ -64,6 +98,53 @@ impl MessageCreateParams {
     }
 }
 
+impl MessageCreateParams {
+    /// Beta flags requested by this request or required by its tools.
+    pub(crate) fn required_betas<'a>(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        let mcp = self
+            .mcp_servers
+            .as_ref()
+            .filter(|servers| !servers.is_empty())
+            .map(|_| betas::MCP_CLIENT);
+        let output = self
+            .output_format
+            .as_ref()
+            .map(|_| betas::STRUCTURED_OUTPUTS);
+
+        self.tools
+            .iter()
+            .flatten()
+            .filter_map(|tool| tool.as_server()?.required_beta())
+            .chain(mcp)
+            .chain(output)
+            .map(|beta| -> &'a str { beta })
+            .chain(self.betas.iter().flatten().map(String::as_str))
+    }
+}
+
+impl Serialize for MessageCreateParams {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::Error;
+
+        let Some(extra_body) = self.extra_body.as_ref().filter(|extra| !extra.is_empty()) else {
+            return MessageCreateParams::serialize(self, serializer);
+        };
+
+        let mut value = MessageCreateParams::serialize(self, serde_json::value::Serializer)
+            .map_err(S::Error::custom)?;
+        if let Some(object) = value.as_object_mut() {
+            object.extend(extra_body.clone());
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MessageCreateParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MessageCreateParams::deserialize(deserializer)
+    }
+}
+
 /// Builder for MessageCreateParams.
 #[derive(Debug, Default)]
 pub struct MessageCreateParamsBuilder {
@@ -77,9 +158,14 @@ pub struct MessageCreateParamsBuilder {
     temperature: Option<f32>,
     top_k: Option<u32>,
     top_p: Option<f32>,
-    tools: Option<Vec<Tool>>,
+    tools: Option<Vec<ToolUnion>>,
     tool_choice: Option<ToolChoice>,
     thinking: Option<ThinkingConfig>,
+    mcp_servers: Option<Vec<McpServer>>,
+    output_format: Option<OutputFormat>,
+    container: Option<String>,
+    betas: Option<Vec<String>>,
+    extra_body: Option<Map<String, Value>>,
 }
 
 impl MessageCreateParamsBuilder {
@@ -155,12 +241,34 @@ impl MessageCreateParamsBuilder {
         self
     }
 
-    /// Set available tools.
-    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
-        self.tools = Some(tools);
+    /// Set available tools, replacing any added before.
+    ///
+    /// Takes custom [`Tool`]s, Anthropic-defined tools or a mix of both as
+    /// [`ToolUnion`]s.
+    pub fn tools<T: Into<ToolUnion>>(mut self, tools: impl IntoIterator<Item = T>) -> Self {
+        self.tools = Some(tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Add a single custom or Anthropic-defined tool.
+    pub fn tool(mut self, tool: impl Into<ToolUnion>) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(tool.into());
+        self
+    }
+
+    /// Add Anthropic-defined tools, such as web search.
+    pub fn server_tools(mut self, tools: Vec<ServerTool>) -> Self {
+        self.tools
+            .get_or_insert_with(Vec::new)
+            .extend(tools.into_iter().map(ToolUnion::Server));
         self
     }
 
+    /// Add a single Anthropic-defined tool.
+    pub fn server_tool(self, tool: impl Into<ServerTool>) -> Self {
+        self.tool(ToolUnion::Server(tool.into()))
+    }
+
     /// Set the tool choice strategy.
     pub fn tool_choice(mut self, tool_choice: ToolChoice) -> Self {
         self.tool_choice = Some(tool_choice);
@@ -179,6 +287,141 @@ impl MessageCreateParamsBuilder {
         self
     }
 
+    /// Set the remote MCP servers available to the model.
+    pub fn mcp_servers(mut self, servers: Vec<McpServer>) -> Self {
+        self.mcp_servers = Some(servers);
+        self
+    }
+
+    /// Add a single remote MCP server.
+    pub fn mcp_server(mut self, server: McpServer) -> Self {
+        self.mcp_servers.get_or_insert_with(Vec::new).push(server);
+        self
+    }
+
+    /// Set the format of the model's final answer.
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Make the model answer with JSON matching `schema`, read back with
+    /// [`Message::parse_structured`](super::Message::parse_structured).
+    pub fn output_schema(self, schema: serde_json::Value) -> Self {
+        self.output_format(OutputFormat::json_schema(schema))
+    }
+
+    /// Make the model answer with JSON matching `schema` by forcing it to
+    /// call a tool that takes it, for models without native structured
+    /// output.
+    ///
+    /// Adds the [`STRUCTURED_OUTPUT_TOOL`] tool and sets the tool choice to
+    /// it, so it cannot be combined with extended thinking. The schema must
+    /// describe an object. Read the answer back with
+    /// [`Message::parse_structured`](super::Message::parse_structured).
+    pub fn output_tool(mut self, schema: serde_json::Value) -> Self {
+        self.tools
+            .get_or_insert_with(Vec::new)
+            .push(ToolUnion::Custom(Tool::with_description(
+                STRUCTURED_OUTPUT_TOOL,
+                "Respond with the final answer.",
+                ToolInputSchema::from_value(schema),
+            )));
+        self.tool_choice = Some(ToolChoice::tool(STRUCTURED_OUTPUT_TOOL));
+        self
+    }
+
+    /// Run code execution in an existing container, keeping its files.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: anthropic_sdk::AsyncAnthropic, first: anthropic_sdk::Message) -> anthropic_sdk::Result<()> {
+    /// use anthropic_sdk::types::CodeExecutionTool;
+    /// use anthropic_sdk::{MessageCreateParams, MessageParam};
+    ///
+    /// let mut builder = MessageCreateParams::builder()
+    ///     .message(MessageParam::user("Plot the CSV you wrote earlier."))
+    ///     .server_tool(CodeExecutionTool::new());
+    /// if let Some(container) = &first.container {
+    ///     builder = builder.container(&container.id);
+    /// }
+    /// let message = client.messages().create(builder.build()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn container(mut self, container_id: impl Into<String>) -> Self {
+        self.container = Some(container_id.into());
+        self
+    }
+
+    /// Set the beta flags to opt this request into.
+    pub fn betas(mut self, betas: Vec<String>) -> Self {
+        self.betas = Some(betas);
+        self
+    }
+
+    /// Opt this request into a beta, such as one of [`betas`](super::betas).
+    pub fn beta(mut self, beta: impl Into<String>) -> Self {
+        self.betas.get_or_insert_with(Vec::new).push(beta.into());
+        self
+    }
+
+    /// Set extra fields to merge into the request body.
+    pub fn extra_body(mut self, extra_body: Map<String, Value>) -> Self {
+        self.extra_body = Some(extra_body);
+        self
+    }
+
+    /// Add a field to the request body that this SDK does not have a setter
+    /// for yet, replacing a typed field of the same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::{MessageCreateParams, MessageParam};
+    /// use serde_json::json;
+    ///
+    /// let params = MessageCreateParams::builder()
+    ///     .message(MessageParam::user("Hello"))
+    ///     .extra_field("inference_geo", json!("eu"))
+    ///     .build();
+    /// ```
+    pub fn extra_field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra_body
+            .get_or_insert_with(Map::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Have Claude 3.7 Sonnet write tool calls in fewer output tokens by
+    /// opting into the [`TOKEN_EFFICIENT_TOOLS`](super::betas::TOKEN_EFFICIENT_TOOLS)
+    /// beta.
+    ///
+    /// Tool definitions and `tool_use` blocks are sent and parsed the same
+    /// way. Claude 4 models use token-efficient tool calls without the beta.
+    pub fn token_efficient_tools(self) -> Self {
+        self.beta(betas::TOKEN_EFFICIENT_TOOLS)
+    }
+
+    /// Allow prompts of up to 1M tokens, on models that support it, by
+    /// opting into the [`CONTEXT_1M`](super::betas::CONTEXT_1M) beta.
+    ///
+    /// Input beyond 200K tokens is billed at long context rates.
+    pub fn context_1m(self) -> Self {
+        self.beta(betas::CONTEXT_1M)
+    }
+
+    /// Allow Claude 3.7 Sonnet to write up to 128K output tokens by opting
+    /// into the [`OUTPUT_128K`](super::betas::OUTPUT_128K) beta.
+    ///
+    /// Set `max_tokens` as well. Unless a timeout is set, requests with a
+    /// large `max_tokens` are given longer than the default ten minutes.
+    /// Streaming is recommended so that output arrives as it is generated.
+    pub fn output_128k(self) -> Self {
+        self.beta(betas::OUTPUT_128K)
+    }
+
     /// Build the MessageCreateParams.
     pub fn build(self) -> MessageCreateParams {
         MessageCreateParams {
@@ -197,6 +440,11 @@ impl MessageCreateParamsBuilder {
             tools: self.tools,
             tool_choice: self.tool_choice,
             thinking: self.thinking,
+            mcp_servers: self.mcp_servers,
+            output_format: self.output_format,
+            container: self.container,
+            betas: self.betas,
+            extra_body: self.extra_body,
         }
     }
 }
@@ -255,13 +503,125 @@ pub struct CountTokensParams {
 
     /// Tools (optional, affects token count).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub tools: Option<Vec<Tool>>,
+    pub tools: Option<Vec<ToolUnion>>,
+
+    /// Tool choice strategy (optional, affects token count).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
 
     /// Thinking configuration (optional).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thinking: Option<ThinkingConfig>,
 }
 
+impl CountTokensParams {
+    /// Create a new builder for token counting params.
+    pub fn builder() -> CountTokensParamsBuilder {
+        CountTokensParamsBuilder::default()
+    }
+}
+
+/// Count the tokens of the request these params would send.
+impl From<&MessageCreateParams> for CountTokensParams {
+    fn from(params: &MessageCreateParams) -> Self {
+        CountTokensParams {
+            model: params.model.clone(),
+            messages: params.messages.clone(),
+            system: params.system.clone(),
+            tools: params.tools.clone(),
+            tool_choice: params.tool_choice.clone(),
+            thinking: params.thinking.clone(),
+        }
+    }
+}
+
+/// Builder for CountTokensParams.
+#[derive(Debug, Default)]
+pub struct CountTokensParamsBuilder {
+    model: Option<String>,
+    messages: Vec<MessageParam>,
+    system: Option<SystemPrompt>,
+    tools: Option<Vec<ToolUnion>>,
+    tool_choice: Option<ToolChoice>,
+    thinking: Option<ThinkingConfig>,
+}
+
+impl CountTokensParamsBuilder {
+    /// Set the model to count tokens for.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Set the messages in the conversation.
+    pub fn messages(mut self, messages: Vec<MessageParam>) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Add a single message to the conversation.
+    pub fn message(mut self, message: MessageParam) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// Set the system prompt as text.
+    pub fn system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(SystemPrompt::Text(system.into()));
+        self
+    }
+
+    /// Set the system prompt with content blocks.
+    pub fn system_blocks(mut self, blocks: Vec<ContentBlockParam>) -> Self {
+        self.system = Some(SystemPrompt::Blocks(blocks));
+        self
+    }
+
+    /// Set available tools, replacing any added before.
+    pub fn tools<T: Into<ToolUnion>>(mut self, tools: impl IntoIterator<Item = T>) -> Self {
+        self.tools = Some(tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Add a single custom or Anthropic-defined tool.
+    pub fn tool(mut self, tool: impl Into<ToolUnion>) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(tool.into());
+        self
+    }
+
+    /// Set the tool choice strategy.
+    pub fn tool_choice(mut self, tool_choice: ToolChoice) -> Self {
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// Enable extended thinking with a token budget.
+    pub fn thinking(mut self, budget_tokens: u32) -> Self {
+        self.thinking = Some(ThinkingConfig::Enabled { budget_tokens });
+        self
+    }
+
+    /// Disable extended thinking.
+    pub fn no_thinking(mut self) -> Self {
+        self.thinking = Some(ThinkingConfig::Disabled);
+        self
+    }
+
+    /// Build the CountTokensParams.
+    pub fn build(self) -> CountTokensParams {
+        CountTokensParams {
+            model: self
+                .model
+                .unwrap_or_else(|| "claude-sonnet-4-5-20250929".into()),
+            messages: self.messages,
+            system: self.system,
+            tools: self.tools,
+            tool_choice: self.tool_choice,
+            thinking: self.thinking,
+        }
+    }
+}
+
 /// Token count response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenCount {
@@ -284,3 +644,113 @@ pub struct ListModelsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub after_id: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ToolInputSchema, WebSearchTool};
+
+    #[test]
+    fn test_mixed_tools_round_trip() {
+        let params = MessageCreateParams::builder()
+            .message(MessageParam::user("What's new in Rust?"))
+            .tools(vec![Tool::new("lookup", ToolInputSchema::empty())])
+            .server_tool(WebSearchTool::new().max_uses(2))
+            .build();
+
+        let value = serde_json::to_value(&params).unwrap();
+        let tools = value["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0]["name"], "lookup");
+        assert_eq!(tools[1]["type"], "web_search_20250305");
+        assert_eq!(tools[1]["name"], "web_search");
+        assert_eq!(tools[1]["max_uses"], 2);
+
+        let params: MessageCreateParams = serde_json::from_value(value).unwrap();
+        let tools = params.tools.unwrap();
+        assert_eq!(tools[0].as_custom().unwrap().name, "lookup");
+        assert!(matches!(
+            tools[1].as_server(),
+            Some(ServerTool::WebSearch(tool)) if tool.max_uses == Some(2)
+        ));
+    }
+
+    #[test]
+    fn test_extra_body() {
+        let params = MessageCreateParams::builder()
+            .message(MessageParam::user("Hello"))
+            .temperature(0.5)
+            .extra_field("temperature", 0.0)
+            .extra_field("inference_geo", "eu")
+            .build();
+
+        let value = serde_json::to_value(&params).unwrap();
+        assert_eq!(value["temperature"], 0.0);
+        assert_eq!(value["inference_geo"], "eu");
+
+        let params: MessageCreateParams = serde_json::from_value(value).unwrap();
+        assert_eq!(params.extra_body.unwrap()["inference_geo"], "eu");
+    }
+
+    #[test]
+    fn test_count_tokens_from_create_params() {
+        let params = MessageCreateParams::builder()
+            .message(MessageParam::user("Hello"))
+            .max_tokens(2048)
+            .tool(Tool::new("lookup", ToolInputSchema::empty()))
+            .tool_choice(ToolChoice::tool("lookup"))
+            .build();
+
+        let value = serde_json::to_value(CountTokensParams::from(&params)).unwrap();
+        assert_eq!(value["tools"][0]["name"], "lookup");
+        assert_eq!(value["tool_choice"]["name"], "lookup");
+        assert!(value.get("max_tokens").is_none());
+    }
+
+    #[test]
+    fn test_token_efficient_tools() {
+        let builder = || {
+            MessageCreateParams::builder()
+                .message(MessageParam::user("Hello"))
+                .tool(Tool::new("lookup", ToolInputSchema::empty()))
+        };
+        let params = builder().token_efficient_tools().build();
+
+        assert_eq!(
+            params.required_betas().collect::<Vec<_>>(),
+            [betas::TOKEN_EFFICIENT_TOOLS]
+        );
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::to_value(builder().build()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_betas_go_to_the_header() {
+        let params = MessageCreateParams::builder()
+            .message(MessageParam::user("Hello"))
+            .beta(betas::CONTEXT_MANAGEMENT)
+            .build();
+
+        let value = serde_json::to_value(&params).unwrap();
+        assert!(value.get("betas").is_none());
+        assert_eq!(
+            params.required_betas().collect::<Vec<_>>(),
+            [betas::CONTEXT_MANAGEMENT]
+        );
+    }
+
+    #[test]
+    fn test_context_1m() {
+        let params = MessageCreateParams::builder()
+            .message(MessageParam::user("Hello"))
+            .context_1m()
+            .build();
+
+        assert_eq!(
+            params.required_betas().collect::<Vec<_>>(),
+            [betas::CONTEXT_1M]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Experimental prompt tools types.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{MessageParam, Usage};
+
+/// Parameters for generating a prompt from a task description.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratePromptParams {
+    /// Description of the task the prompt should accomplish.
+    pub task: String,
+
+    /// The model the prompt will be used with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_model: Option<String>,
+}
+
+impl GeneratePromptParams {
+    /// Create new generate prompt params.
+    pub fn new(task: impl Into<String>) -> Self {
+        Self {
+            task: task.into(),
+            target_model: None,
+        }
+    }
+
+    /// Set the target model.
+    pub fn target_model(mut self, model: impl Into<String>) -> Self {
+        self.target_model = Some(model.into());
+        self
+    }
+}
+
+/// Parameters for improving an existing prompt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImprovePromptParams {
+    /// The prompt messages to improve.
+    pub messages: Vec<MessageParam>,
+
+    /// The system prompt to improve.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+
+    /// Feedback describing how the prompt should be improved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<String>,
+
+    /// The model the prompt will be used with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_model: Option<String>,
+}
+
+impl ImprovePromptParams {
+    /// Create new improve prompt params.
+    pub fn new(messages: Vec<MessageParam>) -> Self {
+        Self {
+            messages,
+            system: None,
+            feedback: None,
+            target_model: None,
+        }
+    }
+
+    /// Set the system prompt.
+    pub fn system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Set the improvement feedback.
+    pub fn feedback(mut self, feedback: impl Into<String>) -> Self {
+        self.feedback = Some(feedback.into());
+        self
+    }
+
+    /// Set the target model.
+    pub fn target_model(mut self, model: impl Into<String>) -> Self {
+        self.target_model = Some(model.into());
+        self
+    }
+}
+
+/// Parameters for turning a prompt into a reusable template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatizePromptParams {
+    /// The prompt messages to templatize.
+    pub messages: Vec<MessageParam>,
+
+    /// The system prompt to templatize.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+}
+
+impl TemplatizePromptParams {
+    /// Create new templatize prompt params.
+    pub fn new(messages: Vec<MessageParam>) -> Self {
+        Self {
+            messages,
+            system: None,
+        }
+    }
+
+    /// Set the system prompt.
+    pub fn system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+}
+
+/// A prompt returned by the generate and improve endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptResponse {
+    /// The prompt messages.
+    pub messages: Vec<MessageParam>,
+
+    /// The system prompt.
+    #[serde(default)]
+    pub system: String,
+
+    /// Token usage of the underlying requests.
+    #[serde(default)]
+    pub usage: Vec<Usage>,
+}
+
+/// A templatized prompt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplatizePromptResponse {
+    /// The prompt messages with variables replaced by `{{PLACEHOLDERS}}`.
+    pub messages: Vec<MessageParam>,
+
+    /// The templatized system prompt.
+    #[serde(default)]
+    pub system: String,
+
+    /// Token usage of the underlying requests.
+    #[serde(default)]
+    pub usage: Vec<Usage>,
+
+    /// The values extracted for each template variable.
+    #[serde(default)]
+    pub variable_values: HashMap<String, String>,
+}
@@ -0,0 +1,393 @@
+//! Usage and cost report types.
+
+use serde::{Deserialize, Serialize};
+
+/// Width of the time buckets in a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BucketWidth {
+    /// One-minute buckets.
+    #[serde(rename = "1m")]
+    Minute,
+    /// One-hour buckets.
+    #[serde(rename = "1h")]
+    Hour,
+    /// One-day buckets.
+    #[serde(rename = "1d")]
+    Day,
+}
+
+impl BucketWidth {
+    /// Get the wire representation of the bucket width.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BucketWidth::Minute => "1m",
+            BucketWidth::Hour => "1h",
+            BucketWidth::Day => "1d",
+        }
+    }
+}
+
+/// Service tier a request was processed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceTier {
+    /// Standard on-demand tier.
+    Standard,
+    /// Message Batches API.
+    Batch,
+    /// Priority tier.
+    Priority,
+}
+
+impl ServiceTier {
+    /// Get the wire representation of the service tier.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServiceTier::Standard => "standard",
+            ServiceTier::Batch => "batch",
+            ServiceTier::Priority => "priority",
+        }
+    }
+}
+
+/// Dimensions a usage report can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageGroupBy {
+    /// Group by API key.
+    ApiKeyId,
+    /// Group by workspace.
+    WorkspaceId,
+    /// Group by model.
+    Model,
+    /// Group by service tier.
+    ServiceTier,
+    /// Group by context window size.
+    ContextWindow,
+}
+
+impl UsageGroupBy {
+    /// Get the wire representation of the grouping.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageGroupBy::ApiKeyId => "api_key_id",
+            UsageGroupBy::WorkspaceId => "workspace_id",
+            UsageGroupBy::Model => "model",
+            UsageGroupBy::ServiceTier => "service_tier",
+            UsageGroupBy::ContextWindow => "context_window",
+        }
+    }
+}
+
+/// Dimensions a cost report can be grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CostGroupBy {
+    /// Group by workspace.
+    WorkspaceId,
+    /// Group by line item description.
+    Description,
+}
+
+impl CostGroupBy {
+    /// Get the wire representation of the grouping.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CostGroupBy::WorkspaceId => "workspace_id",
+            CostGroupBy::Description => "description",
+        }
+    }
+}
+
+/// Parameters for the Messages usage report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageReportParams {
+    /// Start of the report window (RFC 3339), inclusive.
+    pub starting_at: String,
+
+    /// End of the report window (RFC 3339), exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_at: Option<String>,
+
+    /// Width of each time bucket.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_width: Option<BucketWidth>,
+
+    /// Dimensions to group results by.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub group_by: Vec<UsageGroupBy>,
+
+    /// Only include usage for these models.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub models: Vec<String>,
+
+    /// Only include usage for these workspaces.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub workspace_ids: Vec<String>,
+
+    /// Only include usage for these API keys.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub api_key_ids: Vec<String>,
+
+    /// Only include usage on these service tiers.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub service_tiers: Vec<ServiceTier>,
+
+    /// Maximum number of buckets to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Page cursor from a previous report's `next_page`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl UsageReportParams {
+    /// Create usage report params starting at the given time.
+    pub fn new(starting_at: impl Into<String>) -> Self {
+        UsageReportParams {
+            starting_at: starting_at.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the end of the report window.
+    pub fn ending_at(mut self, ending_at: impl Into<String>) -> Self {
+        self.ending_at = Some(ending_at.into());
+        self
+    }
+
+    /// Set the bucket width.
+    pub fn bucket_width(mut self, bucket_width: BucketWidth) -> Self {
+        self.bucket_width = Some(bucket_width);
+        self
+    }
+
+    /// Add a grouping dimension.
+    pub fn group_by(mut self, group_by: UsageGroupBy) -> Self {
+        self.group_by.push(group_by);
+        self
+    }
+
+    /// Filter to a model.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.models.push(model.into());
+        self
+    }
+
+    /// Filter to a workspace.
+    pub fn workspace_id(mut self, workspace_id: impl Into<String>) -> Self {
+        self.workspace_ids.push(workspace_id.into());
+        self
+    }
+
+    /// Filter to an API key.
+    pub fn api_key_id(mut self, api_key_id: impl Into<String>) -> Self {
+        self.api_key_ids.push(api_key_id.into());
+        self
+    }
+
+    /// Filter to a service tier.
+    pub fn service_tier(mut self, service_tier: ServiceTier) -> Self {
+        self.service_tiers.push(service_tier);
+        self
+    }
+
+    /// Set the maximum number of buckets.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the page cursor.
+    pub fn page(mut self, page: impl Into<String>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// Parameters for the cost report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CostReportParams {
+    /// Start of the report window (RFC 3339), inclusive.
+    pub starting_at: String,
+
+    /// End of the report window (RFC 3339), exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_at: Option<String>,
+
+    /// Dimensions to group results by.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub group_by: Vec<CostGroupBy>,
+
+    /// Maximum number of buckets to return.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Page cursor from a previous report's `next_page`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl CostReportParams {
+    /// Create cost report params starting at the given time.
+    pub fn new(starting_at: impl Into<String>) -> Self {
+        CostReportParams {
+            starting_at: starting_at.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the end of the report window.
+    pub fn ending_at(mut self, ending_at: impl Into<String>) -> Self {
+        self.ending_at = Some(ending_at.into());
+        self
+    }
+
+    /// Add a grouping dimension.
+    pub fn group_by(mut self, group_by: CostGroupBy) -> Self {
+        self.group_by.push(group_by);
+        self
+    }
+
+    /// Set the maximum number of buckets.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set the page cursor.
+    pub fn page(mut self, page: impl Into<String>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A time-bucketed report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report<T> {
+    /// The time buckets in the report.
+    pub data: Vec<ReportBucket<T>>,
+
+    /// Whether there are more buckets.
+    pub has_more: bool,
+
+    /// Cursor for the next page, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+}
+
+/// A single time bucket of a report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportBucket<T> {
+    /// Start of the bucket (RFC 3339), inclusive.
+    pub starting_at: String,
+
+    /// End of the bucket (RFC 3339), exclusive.
+    pub ending_at: String,
+
+    /// The grouped results within this bucket.
+    pub results: Vec<T>,
+}
+
+/// Messages usage report.
+pub type UsageReport = Report<UsageReportResult>;
+
+/// Cost report.
+pub type CostReport = Report<CostReportResult>;
+
+/// Token usage for one group within a usage report bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReportResult {
+    /// Input tokens that were not read from or written to the cache.
+    pub uncached_input_tokens: u64,
+
+    /// Input tokens used to create cache entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_creation: Option<ReportCacheCreation>,
+
+    /// Input tokens read from the cache.
+    pub cache_read_input_tokens: u64,
+
+    /// Output tokens generated.
+    pub output_tokens: u64,
+
+    /// Server tool usage.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_tool_use: Option<ReportServerToolUse>,
+
+    /// API key the usage is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key_id: Option<String>,
+
+    /// Workspace the usage is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+
+    /// Model the usage is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Service tier the usage is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+
+    /// Context window the usage is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_window: Option<String>,
+}
+
+/// Cache creation tokens by cache lifetime.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportCacheCreation {
+    /// Tokens written to the 1-hour cache.
+    pub ephemeral_1h_input_tokens: u64,
+
+    /// Tokens written to the 5-minute cache.
+    pub ephemeral_5m_input_tokens: u64,
+}
+
+/// Server tool usage within a usage report.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportServerToolUse {
+    /// Number of web search requests made.
+    pub web_search_requests: u64,
+}
+
+/// Cost for one group within a cost report bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostReportResult {
+    /// Currency of the amount (e.g. "USD").
+    pub currency: String,
+
+    /// Cost as a decimal string, in the lowest currency unit (cents).
+    pub amount: String,
+
+    /// Workspace the cost is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<String>,
+
+    /// Line item description the cost is grouped by, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Type of cost (e.g. "tokens", "web_search").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_type: Option<String>,
+
+    /// Context window, for token costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_window: Option<String>,
+
+    /// Model, for token costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+
+    /// Service tier, for token costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+
+    /// Token type, for token costs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_type: Option<String>,
+}
@@ -0,0 +1,283 @@
+//! Anthropic-defined tool types.
+
+use serde::{Deserialize, Serialize};
+
+use super::{betas, BashTool, CacheControl, ComputerTool, MemoryTool, TextEditorTool};
+
+/// A tool whose schema is defined by Anthropic.
+///
+/// These are sent alongside custom [`Tool`](super::Tool)s in the `tools`
+/// array, as [`ToolUnion::Server`](super::ToolUnion::Server).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ServerTool {
+    /// Web search, executed by Anthropic.
+    #[serde(rename = "web_search_20250305")]
+    WebSearch(WebSearchTool),
+
+    /// Python code execution in a sandbox, executed by Anthropic.
+    #[serde(rename = "code_execution_20250522")]
+    CodeExecution(CodeExecutionTool),
+
+    /// Computer use, executed by the caller.
+    #[serde(rename = "computer_20250124")]
+    Computer(ComputerTool),
+
+    /// File viewing and editing, executed by the caller.
+    #[serde(rename = "text_editor_20250124")]
+    TextEditor(TextEditorTool),
+
+    /// Shell commands, executed by the caller.
+    #[serde(rename = "bash_20250124")]
+    Bash(BashTool),
+
+    /// Persistent memory files, executed by the caller.
+    #[serde(rename = "memory_20250818")]
+    Memory(MemoryTool),
+}
+
+impl ServerTool {
+    /// The name the model uses to call this tool.
+    pub fn name(&self) -> &str {
+        match self {
+            ServerTool::WebSearch(tool) => &tool.name,
+            ServerTool::CodeExecution(tool) => &tool.name,
+            ServerTool::Computer(tool) => &tool.name,
+            ServerTool::TextEditor(tool) => &tool.name,
+            ServerTool::Bash(tool) => &tool.name,
+            ServerTool::Memory(tool) => &tool.name,
+        }
+    }
+
+    /// The beta flag that must be sent with requests using this tool, if any.
+    pub fn required_beta(&self) -> Option<&'static str> {
+        match self {
+            ServerTool::WebSearch(_) | ServerTool::TextEditor(_) | ServerTool::Bash(_) => None,
+            ServerTool::CodeExecution(_) => Some(betas::CODE_EXECUTION),
+            ServerTool::Computer(_) => Some(betas::COMPUTER_USE),
+            ServerTool::Memory(_) => Some(betas::CONTEXT_MANAGEMENT),
+        }
+    }
+}
+
+impl From<WebSearchTool> for ServerTool {
+    fn from(tool: WebSearchTool) -> Self {
+        ServerTool::WebSearch(tool)
+    }
+}
+
+impl From<CodeExecutionTool> for ServerTool {
+    fn from(tool: CodeExecutionTool) -> Self {
+        ServerTool::CodeExecution(tool)
+    }
+}
+
+impl From<ComputerTool> for ServerTool {
+    fn from(tool: ComputerTool) -> Self {
+        ServerTool::Computer(tool)
+    }
+}
+
+impl From<TextEditorTool> for ServerTool {
+    fn from(tool: TextEditorTool) -> Self {
+        ServerTool::TextEditor(tool)
+    }
+}
+
+impl From<BashTool> for ServerTool {
+    fn from(tool: BashTool) -> Self {
+        ServerTool::Bash(tool)
+    }
+}
+
+impl From<MemoryTool> for ServerTool {
+    fn from(tool: MemoryTool) -> Self {
+        ServerTool::Memory(tool)
+    }
+}
+
+/// The web search server tool.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::types::{UserLocation, WebSearchTool};
+///
+/// let web_search = WebSearchTool::new()
+///     .max_uses(3)
+///     .allowed_domains(["docs.rs", "rust-lang.org"])
+///     .user_location(UserLocation::new().country("US").timezone("America/New_York"));
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebSearchTool {
+    /// The tool name, always `web_search`.
+    pub name: String,
+
+    /// Maximum number of searches per request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+
+    /// Only include results from these domains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_domains: Option<Vec<String>>,
+
+    /// Never include results from these domains.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked_domains: Option<Vec<String>>,
+
+    /// Approximate location of the user, used to localize results.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_location: Option<UserLocation>,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl WebSearchTool {
+    /// Create a web search tool with no limits.
+    pub fn new() -> Self {
+        WebSearchTool {
+            name: "web_search".into(),
+            max_uses: None,
+            allowed_domains: None,
+            blocked_domains: None,
+            user_location: None,
+            cache_control: None,
+        }
+    }
+
+    /// Set the maximum number of searches per request.
+    pub fn max_uses(mut self, max_uses: u32) -> Self {
+        self.max_uses = Some(max_uses);
+        self
+    }
+
+    /// Only include results from these domains.
+    pub fn allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_domains = Some(domains.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Never include results from these domains.
+    pub fn blocked_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.blocked_domains = Some(domains.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the approximate location of the user.
+    pub fn user_location(mut self, location: UserLocation) -> Self {
+        self.user_location = Some(location);
+        self
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+impl Default for WebSearchTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The code execution server tool.
+///
+/// The model runs Python in a sandbox and receives its output as a
+/// [`ContentBlock::CodeExecutionToolResult`](super::ContentBlock::CodeExecutionToolResult).
+/// Requires the [`CODE_EXECUTION`](super::betas::CODE_EXECUTION) beta, which is
+/// sent automatically when this tool is in the request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeExecutionTool {
+    /// The tool name, always `code_execution`.
+    pub name: String,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl CodeExecutionTool {
+    /// Create a code execution tool.
+    pub fn new() -> Self {
+        CodeExecutionTool {
+            name: "code_execution".into(),
+            cache_control: None,
+        }
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+impl Default for CodeExecutionTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Approximate user location for localizing web search results.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "approximate")]
+pub struct UserLocation {
+    /// City name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// Region or state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Two letter ISO country code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// IANA time zone, e.g. `America/New_York`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+impl UserLocation {
+    /// Create an empty location.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the city.
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Set the region.
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the country code.
+    pub fn country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the time zone.
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+}
@@ -0,0 +1,108 @@
+//! Text editor tool types.
+
+use serde::{Deserialize, Serialize};
+
+use super::CacheControl;
+
+/// The text editor tool (`text_editor_20250124`).
+///
+/// The model views and edits files through commands, which the caller
+/// executes against its own file system.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextEditorTool {
+    /// The tool name, always `str_replace_editor`.
+    pub name: String,
+
+    /// Cache control settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl TextEditorTool {
+    /// Create a text editor tool.
+    pub fn new() -> Self {
+        TextEditorTool {
+            name: "str_replace_editor".into(),
+            cache_control: None,
+        }
+    }
+
+    /// Enable cache control for this tool.
+    pub fn with_cache_control(mut self) -> Self {
+        self.cache_control = Some(CacheControl::ephemeral());
+        self
+    }
+}
+
+impl Default for TextEditorTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A command requested by the text editor tool.
+///
+/// Parse it from a `tool_use` block with
+/// [`ToolUseBlock::parse_input`](super::ToolUseBlock::parse_input).
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::types::TextEditorCommand;
+/// use serde_json::json;
+///
+/// let command: TextEditorCommand = serde_json::from_value(json!({
+///     "command": "str_replace",
+///     "path": "src/main.rs",
+///     "old_str": "Hello",
+///     "new_str": "Goodbye",
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(command.path(), "src/main.rs");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum TextEditorCommand {
+    /// Show a file, optionally only the 1-indexed inclusive line range
+    /// (`-1` as the end means the end of the file), or list a directory.
+    View {
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        view_range: Option<[i64; 2]>,
+    },
+
+    /// Create a file with the given contents.
+    Create { path: String, file_text: String },
+
+    /// Replace the single occurrence of `old_str` with `new_str`.
+    StrReplace {
+        path: String,
+        old_str: String,
+        #[serde(default)]
+        new_str: String,
+    },
+
+    /// Insert `new_str` after line `insert_line` (0 inserts at the start).
+    Insert {
+        path: String,
+        insert_line: u32,
+        new_str: String,
+    },
+
+    /// Revert the last edit made to a file.
+    UndoEdit { path: String },
+}
+
+impl TextEditorCommand {
+    /// The file or directory the command operates on.
+    pub fn path(&self) -> &str {
+        match self {
+            TextEditorCommand::View { path, .. }
+            | TextEditorCommand::Create { path, .. }
+            | TextEditorCommand::StrReplace { path, .. }
+            | TextEditorCommand::Insert { path, .. }
+            | TextEditorCommand::UndoEdit { path } => path,
+        }
+    }
+}
@@ -1,8 +1,15 @@
 //! Tool use types.
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use super::ServerTool;
+use crate::error::{AnthropicError, Result};
+
+/// Maximum length of the raw input quoted in [`AnthropicError::ToolInput`].
+const INPUT_SNIPPET_LEN: usize = 200;
+
 /// A tool definition for the API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tool {
@@ -54,9 +61,121 @@ impl Tool {
 
     /// Enable cache control for this tool.
     pub fn with_cache_control(mut self) -> Self {
-        self.cache_control = Some(super::CacheControl::Ephemeral);
+        self.cache_control = Some(super::CacheControl::ephemeral());
         self
     }
+
+    /// Create a tool from a type implementing [`schemars::JsonSchema`].
+    ///
+    /// The name is the schema name in snake_case and the description is taken
+    /// from the type's doc comment. Use [`Tool::new`] with
+    /// [`ToolInputSchema::from_type`] to choose a different name.
+    #[cfg(feature = "schemars")]
+    pub fn from_type<T: schemars::JsonSchema>() -> Self {
+        let schema = schemars_value::<T>();
+        let mut tool = Tool::new(
+            to_snake_case(&T::schema_name()),
+            ToolInputSchema::from_value(schema.clone()),
+        );
+        tool.description = schema
+            .get("description")
+            .and_then(|v| v.as_str())
+            .map(String::from);
+        tool
+    }
+}
+
+/// A tool in the `tools` array of a request.
+///
+/// Either a custom tool with its own input schema, or an Anthropic-defined
+/// tool such as web search, which is identified by its `type`.
+///
+/// # Example
+///
+/// ```rust
+/// use anthropic_sdk::{Tool, ToolInputSchema, ToolUnion};
+/// use anthropic_sdk::types::WebSearchTool;
+///
+/// let tools: Vec<ToolUnion> = vec![
+///     Tool::new("lookup", ToolInputSchema::empty()).into(),
+///     WebSearchTool::new().max_uses(3).into(),
+/// ];
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ToolUnion {
+    /// An Anthropic-defined tool.
+    Server(ServerTool),
+
+    /// A custom tool.
+    Custom(Tool),
+}
+
+impl ToolUnion {
+    /// Get the tool name.
+    pub fn name(&self) -> &str {
+        match self {
+            ToolUnion::Custom(tool) => &tool.name,
+            ToolUnion::Server(tool) => tool.name(),
+        }
+    }
+
+    /// Get the custom tool, if this is one.
+    pub fn as_custom(&self) -> Option<&Tool> {
+        match self {
+            ToolUnion::Custom(tool) => Some(tool),
+            ToolUnion::Server(_) => None,
+        }
+    }
+
+    /// Get the Anthropic-defined tool, if this is one.
+    pub fn as_server(&self) -> Option<&ServerTool> {
+        match self {
+            ToolUnion::Server(tool) => Some(tool),
+            ToolUnion::Custom(_) => None,
+        }
+    }
+}
+
+impl From<Tool> for ToolUnion {
+    fn from(tool: Tool) -> Self {
+        ToolUnion::Custom(tool)
+    }
+}
+
+impl<T: Into<ServerTool>> From<T> for ToolUnion {
+    fn from(tool: T) -> Self {
+        ToolUnion::Server(tool.into())
+    }
+}
+
+/// Generate a JSON schema for a type with all subschemas inlined.
+///
+/// Tool input schemas are sent without `$defs`, so nested types must be
+/// inlined. Recursive types still produce `$ref`s and are not supported.
+#[cfg(feature = "schemars")]
+pub(super) fn schemars_value<T: schemars::JsonSchema>() -> Value {
+    schemars::generate::SchemaSettings::draft2020_12()
+        .with(|settings| settings.inline_subschemas = true)
+        .into_generator()
+        .into_root_schema_for::<T>()
+        .to_value()
+}
+
+#[cfg(feature = "schemars")]
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in name.char_indices() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(ch);
+        }
+    }
+    snake
 }
 
 /// JSON schema for tool input parameters.
@@ -103,6 +222,14 @@ impl ToolInputSchema {
         }
     }
 
+    /// Create a schema from a type implementing [`schemars::JsonSchema`].
+    ///
+    /// Nested objects and enums are inlined into the properties.
+    #[cfg(feature = "schemars")]
+    pub fn from_type<T: schemars::JsonSchema>() -> Self {
+        Self::from_value(schemars_value::<T>())
+    }
+
     /// Create a schema with properties.
     pub fn with_properties(properties: Value, required: Vec<String>) -> Self {
         ToolInputSchema {
@@ -114,6 +241,119 @@ impl ToolInputSchema {
     }
 }
 
+/// A type that can describe itself as a JSON schema.
+///
+/// Used to build tool input schemas; implemented for common primitive and
+/// collection types, and derived with `#[derive(AnthropicTool)]`.
+pub trait ToolSchema {
+    /// The JSON schema for this type.
+    fn schema() -> Value;
+}
+
+/// A Rust type that describes a tool's input.
+///
+/// Usually derived with `#[derive(AnthropicTool)]` (requires the `macros`
+/// feature), which takes the name from the struct, the description from its
+/// doc comment, and the input schema from its fields.
+pub trait AnthropicTool: ToolSchema {
+    /// The name of the tool.
+    const NAME: &'static str;
+
+    /// A description of what the tool does.
+    const DESCRIPTION: Option<&'static str> = None;
+
+    /// The JSON schema for the tool's input.
+    fn input_schema() -> ToolInputSchema {
+        ToolInputSchema::from_value(Self::schema())
+    }
+
+    /// The tool definition to send to the API.
+    fn tool() -> Tool {
+        let tool = Tool::new(Self::NAME, Self::input_schema());
+        match Self::DESCRIPTION {
+            Some(description) => tool.description(description),
+            None => tool,
+        }
+    }
+}
+
+macro_rules! impl_tool_schema {
+    ($schema_type:literal: $($ty:ty),*) => {
+        $(
+            impl ToolSchema for $ty {
+                fn schema() -> Value {
+                    serde_json::json!({ "type": $schema_type })
+                }
+            }
+        )*
+    };
+}
+
+impl_tool_schema!("string": String, str, char);
+impl_tool_schema!("boolean": bool);
+impl_tool_schema!("integer": i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_tool_schema!("number": f32, f64);
+
+impl ToolSchema for Value {
+    fn schema() -> Value {
+        serde_json::json!({})
+    }
+}
+
+impl<T: ToolSchema + ?Sized> ToolSchema for &T {
+    fn schema() -> Value {
+        T::schema()
+    }
+}
+
+impl<T: ToolSchema + ?Sized> ToolSchema for Box<T> {
+    fn schema() -> Value {
+        T::schema()
+    }
+}
+
+impl<T: ToolSchema> ToolSchema for Option<T> {
+    fn schema() -> Value {
+        T::schema()
+    }
+}
+
+impl<T: ToolSchema> ToolSchema for Vec<T> {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "array", "items": T::schema() })
+    }
+}
+
+impl<T: ToolSchema> ToolSchema for [T] {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "array", "items": T::schema() })
+    }
+}
+
+impl<T: ToolSchema, S> ToolSchema for std::collections::HashSet<T, S> {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "array", "items": T::schema(), "uniqueItems": true })
+    }
+}
+
+impl<T: ToolSchema> ToolSchema for std::collections::BTreeSet<T> {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "array", "items": T::schema(), "uniqueItems": true })
+    }
+}
+
+impl<V: ToolSchema, S> ToolSchema for std::collections::HashMap<String, V, S> {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "object", "additionalProperties": V::schema() })
+    }
+}
+
+impl<V: ToolSchema> ToolSchema for std::collections::BTreeMap<String, V> {
+    fn schema() -> Value {
+        serde_json::json!({ "type": "object", "additionalProperties": V::schema() })
+    }
+}
+
 /// Tool choice parameter for controlling tool usage.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -203,6 +443,61 @@ pub struct ToolUseBlock {
     pub input: Value,
 }
 
+impl ToolUseBlock {
+    /// Deserialize the tool input into `T`.
+    ///
+    /// On failure the error names the tool, the path of the offending field
+    /// and quotes the raw input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use anthropic_sdk::ToolUseBlock;
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct GetWeather {
+    ///     location: String,
+    /// }
+    ///
+    /// let block = ToolUseBlock {
+    ///     id: "toolu_01".to_string(),
+    ///     name: "get_weather".to_string(),
+    ///     input: json!({ "location": "Paris" }),
+    /// };
+    ///
+    /// let input: GetWeather = block.parse_input().unwrap();
+    /// assert_eq!(input.location, "Paris");
+    /// ```
+    pub fn parse_input<T: DeserializeOwned>(&self) -> Result<T> {
+        parse_tool_input(&self.name, &self.input)
+    }
+}
+
+/// Deserialize a tool input, reporting the failing field path on error.
+pub(crate) fn parse_tool_input<T: DeserializeOwned>(name: &str, input: &Value) -> Result<T> {
+    serde_path_to_error::deserialize(input).map_err(|error| {
+        let path = error.path().to_string();
+        let mut snippet = input.to_string();
+        if snippet.len() > INPUT_SNIPPET_LEN {
+            let mut end = INPUT_SNIPPET_LEN;
+            while !snippet.is_char_boundary(end) {
+                end -= 1;
+            }
+            snippet.truncate(end);
+            snippet.push_str("...");
+        }
+
+        AnthropicError::ToolInput {
+            name: name.to_string(),
+            path,
+            message: error.into_inner().to_string(),
+            input: snippet,
+        }
+    })
+}
+
 /// Tool result block for sending tool execution results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResultBlockParam {
@@ -228,6 +523,16 @@ impl ToolResultBlockParam {
         }
     }
 
+    /// Create a successful tool result from a serializable value.
+    ///
+    /// The value is sent to the model as JSON text.
+    pub fn from_value<T: Serialize + ?Sized>(
+        tool_use_id: impl Into<String>,
+        value: &T,
+    ) -> Result<Self> {
+        Ok(Self::success(tool_use_id, serde_json::to_string(value)?))
+    }
+
     /// Create an error tool result.
     pub fn error(tool_use_id: impl Into<String>, error: impl Into<String>) -> Self {
         ToolResultBlockParam {
@@ -237,3 +542,135 @@ impl ToolResultBlockParam {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anthropic_sdk_macros::AnthropicTool;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_input_reports_field_path() {
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Route {
+            stops: Vec<Stop>,
+        }
+
+        #[allow(dead_code)]
+        #[derive(Debug, Deserialize)]
+        struct Stop {
+            city: String,
+        }
+
+        let block = ToolUseBlock {
+            id: "toolu_01".to_string(),
+            name: "plan_route".to_string(),
+            input: json!({ "stops": [{ "city": "Paris" }, { "city": 7 }] }),
+        };
+
+        match block.parse_input::<Route>().unwrap_err() {
+            AnthropicError::ToolInput {
+                name, path, input, ..
+            } => {
+                assert_eq!(name, "plan_route");
+                assert_eq!(path, "stops[1].city");
+                assert!(input.contains(r#""city":7"#));
+            }
+            error => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_tool_from_schemars_type() {
+        /// Book a flight.
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        struct BookFlight {
+            /// Departure and arrival airports.
+            route: Route,
+            class: Class,
+            passengers: Option<u32>,
+        }
+
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        struct Route {
+            from: String,
+            to: String,
+        }
+
+        #[allow(dead_code)]
+        #[derive(schemars::JsonSchema)]
+        enum Class {
+            Economy,
+            Business,
+        }
+
+        let tool = Tool::from_type::<BookFlight>();
+        assert_eq!(tool.name, "book_flight");
+        assert_eq!(tool.description.as_deref(), Some("Book a flight."));
+
+        let schema = tool.input_schema;
+        assert_eq!(
+            schema.required,
+            Some(vec!["route".to_string(), "class".to_string()])
+        );
+
+        let properties = schema.properties.unwrap();
+        assert_eq!(properties["route"]["type"], "object");
+        assert_eq!(properties["route"]["required"], json!(["from", "to"]));
+        assert_eq!(properties["class"]["enum"], json!(["Economy", "Business"]));
+    }
+
+    /// The unit for temperature.
+    #[allow(dead_code)]
+    #[derive(Deserialize, AnthropicTool)]
+    #[serde(rename_all = "lowercase")]
+    enum Unit {
+        Celsius,
+        Fahrenheit,
+    }
+
+    /// Get the current weather in a given location.
+    #[allow(dead_code)]
+    #[derive(Deserialize, AnthropicTool)]
+    struct GetWeather {
+        /// The city and state, e.g. San Francisco, CA.
+        location: String,
+        unit: Option<Unit>,
+        #[serde(rename = "days")]
+        forecast_days: Vec<u8>,
+    }
+
+    #[test]
+    fn test_derive_tool() {
+        let tool = GetWeather::tool();
+
+        assert_eq!(tool.name, "get_weather");
+        assert_eq!(
+            tool.description.as_deref(),
+            Some("Get the current weather in a given location.")
+        );
+        assert_eq!(
+            tool.input_schema.properties,
+            Some(json!({
+                "location": {
+                    "type": "string",
+                    "description": "The city and state, e.g. San Francisco, CA."
+                },
+                "unit": {
+                    "type": "string",
+                    "enum": ["celsius", "fahrenheit"],
+                    "description": "The unit for temperature."
+                },
+                "days": { "type": "array", "items": { "type": "integer" } }
+            }))
+        );
+        assert_eq!(
+            tool.input_schema.required,
+            Some(vec!["location".to_string(), "days".to_string()])
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::ServiceTier;
+
 /// Token usage information for a request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Usage {
@@ -18,18 +20,34 @@ pub struct Usage {
     /// The number of tokens read from the cache.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cache_read_input_tokens: Option<u32>,
+
+    /// The tokens used to create cache entries, by cache lifetime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_creation: Option<CacheCreation>,
+
+    /// Server tool usage, such as web search requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_tool_use: Option<ServerToolUsage>,
+
+    /// The service tier the request was served with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
 }
 
-/// Cache creation information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Cache creation tokens by cache lifetime, which are priced differently.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CacheCreation {
-    /// Type identifier.
-    #[serde(rename = "type")]
-    pub cache_type: String,
+    /// Tokens written to 5 minute cache entries.
+    #[serde(default)]
+    pub ephemeral_5m_input_tokens: u32,
+
+    /// Tokens written to 1 hour cache entries.
+    #[serde(default)]
+    pub ephemeral_1h_input_tokens: u32,
 }
 
 /// Server tool usage information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ServerToolUsage {
     /// Number of web search requests made.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -37,8 +55,26 @@ pub struct ServerToolUsage {
 }
 
 /// Message delta usage (for streaming).
+///
+/// The counts are cumulative for the whole message, not increments.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageDeltaUsage {
     /// The number of output tokens generated so far.
     pub output_tokens: u32,
+
+    /// The number of input tokens used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_tokens: Option<u32>,
+
+    /// The number of tokens used to create the cache entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_creation_input_tokens: Option<u32>,
+
+    /// The number of tokens read from the cache.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_read_input_tokens: Option<u32>,
+
+    /// Server tool usage so far.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_tool_use: Option<ServerToolUsage>,
 }